@@ -0,0 +1,144 @@
+//! On-disk cache of fetched Amazon pages, keyed by URL.
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Stores response bodies as one file per URL, optionally gzip-compressed.
+///
+/// Reads accept both compressed and plain entries, so toggling compression
+/// doesn't invalidate an existing cache. Entries older than the TTL are
+/// treated as missing.
+pub struct ResponseCache {
+    dir: PathBuf,
+    compress: bool,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    /// Creates a cache rooted at `dir` (created on first write) whose entries
+    /// are reused for `ttl`.
+    pub fn new(dir: impl Into<PathBuf>, compress: bool, ttl: Duration) -> Self {
+        Self { dir: dir.into(), compress, ttl }
+    }
+
+    /// Stable file stem for a URL (FNV-1a, so names survive Rust upgrades).
+    fn key(url: &str) -> String {
+        let hash = url.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        format!("{:016x}", hash)
+    }
+
+    fn path(&self, url: &str, compressed: bool) -> PathBuf {
+        let ext = if compressed { "html.gz" } else { "html" };
+        self.dir.join(format!("{}.{}", Self::key(url), ext))
+    }
+
+    /// Returns the cached body for `url`, if any and not expired.
+    pub fn get(&self, url: &str) -> Result<Option<String>> {
+        // Prefer the entry matching the current mode, but read either
+        for compressed in [self.compress, !self.compress] {
+            let path = self.path(url, compressed);
+            let Ok(metadata) = std::fs::metadata(&path) else {
+                continue;
+            };
+            let age =
+                SystemTime::now().duration_since(metadata.modified()?).unwrap_or(Duration::ZERO);
+            if age > self.ttl {
+                return Ok(None);
+            }
+            return read_entry(&path, compressed).map(Some);
+        }
+        Ok(None)
+    }
+
+    /// Writes the body for `url`, replacing any previous entry.
+    pub fn put(&self, url: &str, body: &str) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create cache directory: {}", self.dir.display()))?;
+
+        let path = self.path(url, self.compress);
+        let bytes = if self.compress {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(body.as_bytes())?;
+            encoder.finish()?
+        } else {
+            body.as_bytes().to_vec()
+        };
+        std::fs::write(&path, bytes)
+            .with_context(|| format!("Failed to write cache entry: {}", path.display()))?;
+
+        // Drop a stale entry in the other format so `get` can't return it
+        let _ = std::fs::remove_file(self.path(url, !self.compress));
+        Ok(())
+    }
+}
+
+fn read_entry(path: &Path, compressed: bool) -> Result<String> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read cache entry: {}", path.display()))?;
+    if !compressed {
+        return String::from_utf8(bytes).context("Cache entry is not valid UTF-8");
+    }
+
+    let mut body = String::new();
+    GzDecoder::new(bytes.as_slice())
+        .read_to_string(&mut body)
+        .with_context(|| format!("Failed to decompress cache entry: {}", path.display()))?;
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://www.amazon.com/dp/B0TEST1234";
+    const DAY: Duration = Duration::from_secs(24 * 3600);
+
+    fn sample_html() -> String {
+        let card = r#"<div data-component-type="s-search-result"><h2>Widget</h2></div>"#;
+        format!("<html><body>{}</body></html>", card.repeat(200))
+    }
+
+    #[test]
+    fn test_compressed_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path(), true, DAY);
+        let html = sample_html();
+
+        cache.put(URL, &html).unwrap();
+
+        assert_eq!(cache.get(URL).unwrap().as_deref(), Some(html.as_str()));
+        let on_disk = std::fs::metadata(cache.path(URL, true)).unwrap().len();
+        assert!(on_disk < html.len() as u64);
+    }
+
+    #[test]
+    fn test_uncompressed_roundtrip_and_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path(), false, DAY);
+
+        assert!(cache.get(URL).unwrap().is_none());
+        cache.put(URL, "<html></html>").unwrap();
+        assert_eq!(std::fs::read_to_string(cache.path(URL, false)).unwrap(), "<html></html>");
+
+        // A compressing cache still reads the plain entry
+        let compressed = ResponseCache::new(dir.path(), true, DAY);
+        assert_eq!(compressed.get(URL).unwrap().as_deref(), Some("<html></html>"));
+    }
+
+    #[test]
+    fn test_expired_entry_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        ResponseCache::new(dir.path(), true, DAY).put(URL, "<html></html>").unwrap();
+
+        let expired = ResponseCache::new(dir.path(), true, Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(expired.get(URL).unwrap().is_none());
+    }
+}
@@ -0,0 +1,49 @@
+//! Typed errors for responses where Amazon is blocking requests.
+
+use thiserror::Error;
+
+/// Amazon refused to serve the page (CAPTCHA, error page, rate limit or a
+/// page matching a block indicator).
+///
+/// Returned inside `anyhow::Error`; use [`BlockedError::find`] to tell a block
+/// apart from network or parse failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum BlockedError {
+    #[error(
+        "CAPTCHA detected. Amazon is blocking requests. \
+         Try using a proxy or waiting before retrying."
+    )]
+    Captcha,
+    #[error("Amazon error page detected (503). The service may be temporarily unavailable.")]
+    ErrorPage,
+    #[error("Rate limited by Amazon ({0}). Try increasing --delay or using a proxy.")]
+    RateLimited(u16),
+    #[error(
+        "Amazon served a block page instead of results. \
+         Try using a proxy or waiting before retrying."
+    )]
+    SoftBlock,
+}
+
+impl BlockedError {
+    /// Returns the block behind `err`, looking through any added context.
+    pub fn find(err: &anyhow::Error) -> Option<Self> {
+        err.downcast_ref::<Self>().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_find_through_context() {
+        let err: anyhow::Result<()> = Err(BlockedError::Captcha.into());
+        let err = err.context("Failed to fetch B000000001").unwrap_err();
+        assert_eq!(BlockedError::find(&err), Some(BlockedError::Captcha));
+
+        let other = anyhow::anyhow!("Could not find product title");
+        assert_eq!(BlockedError::find(&other), None);
+    }
+}
@@ -1,12 +1,15 @@
 //! Amazon-specific modules for HTTP client, parsing, and data models.
 
+pub mod cache;
 pub mod client;
+pub mod error;
 pub mod models;
 pub mod parser;
 pub mod regions;
 pub mod selectors;
 
 pub use client::{AmazonClient, AmazonSearch};
-pub use models::{Price, PriceRange, Product, Rating};
+pub use error::BlockedError;
+pub use models::{Price, PriceRange, Product, Rating, RawFields, UnitPrice};
 pub use parser::Parser;
 pub use regions::Region;
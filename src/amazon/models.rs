@@ -1,9 +1,10 @@
 //! Data models for Amazon products, prices, and ratings.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Represents an Amazon product with all available metadata.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Product {
     /// Amazon Standard Identification Number
     pub asin: String,
@@ -27,6 +28,65 @@ pub struct Product {
     pub in_stock: bool,
     /// Product brand if available
     pub brand: Option<String>,
+    /// ASINs from the "Customers who viewed this also viewed" carousel
+    #[serde(default)]
+    pub related_asins: Vec<String>,
+    /// True if the page's price symbol doesn't match the region's currency
+    #[serde(default)]
+    pub currency_mismatch: bool,
+    /// Heuristic: ebook, audiobook, gift card, or other digital item
+    #[serde(default)]
+    pub is_digital: bool,
+    /// Product page shows Amazon's "Frequently returned item" warning
+    #[serde(default)]
+    pub is_frequently_returned: bool,
+    /// Raw promotion text, e.g. "Save 5% when you buy 2"
+    #[serde(default)]
+    pub promotion: Option<String>,
+    /// EU energy efficiency class, 'A' (best) to 'G'
+    #[serde(default)]
+    pub energy_class: Option<char>,
+    /// Dimensions as listed in the product details, e.g. "20 x 10 x 5 cm"
+    #[serde(default)]
+    pub dimensions: Option<String>,
+    /// Weight as listed in the product details, e.g. "450 g"
+    #[serde(default)]
+    pub weight: Option<String>,
+    /// Date of the newest review shown on the product page, as displayed
+    /// (e.g. "March 3, 2024"); a staleness signal
+    #[serde(default)]
+    pub latest_review_date: Option<String>,
+    /// Lowest price from other sellers ("New & Used from $18.99"), in the
+    /// same currency as `price`
+    #[serde(default)]
+    pub other_sellers_from: Option<f64>,
+    /// Position on a best seller list (1 = top seller)
+    #[serde(default)]
+    pub rank: Option<u32>,
+    /// Promo code to enter at checkout (e.g. "SAVE15"); None for clip-only
+    /// coupons and listings without a code
+    #[serde(default)]
+    pub coupon_code: Option<String>,
+    /// Subscribe & Save price, when the product offers one
+    #[serde(default)]
+    pub subscribe_price: Option<f64>,
+    /// Subscribe & Save saving relative to the one-time price, in percent
+    #[serde(default)]
+    pub subscribe_discount_percent: Option<u8>,
+    /// Price per base unit (kg, l, count, ...) from "(€2.50/100 g)" labels
+    #[serde(default)]
+    pub unit_price: Option<UnitPrice>,
+    /// Whether the listing ships to the buyer's location, from the product
+    /// page delivery message (best effort; `None` when no message matched)
+    #[serde(default)]
+    pub ships_internationally: Option<bool>,
+    /// Units left when the listing shows low-stock text such as "Only 3 left
+    /// in stock" (`None` when no count is shown)
+    #[serde(default)]
+    pub stock_remaining: Option<u32>,
+    /// Raw strings the fields were parsed from (only with `--debug-raw`)
+    #[serde(rename = "_raw", default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<RawFields>,
 }
 
 impl Product {
@@ -40,6 +100,118 @@ impl Product {
         self.rating.as_ref().map(|r| r.stars)
     }
 
+    /// Reduces the product URL to `https://www.<domain>/dp/<asin>`, dropping
+    /// `/ref=...` segments and query strings. Non-Amazon URLs are returned as-is.
+    pub fn canonical_url(&self) -> String {
+        let rest = self.url.split_once("://").map_or(self.url.as_str(), |(_, rest)| rest);
+        let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        let host = host.split(':').next().unwrap_or_default().to_lowercase();
+        let domain = host.trim_start_matches("www.");
+
+        if domain.starts_with("amazon.") && !self.asin.is_empty() {
+            format!("https://www.{}/dp/{}", domain, self.asin)
+        } else {
+            self.url.clone()
+        }
+    }
+
+    /// Scores how well the title matches `query`, from 0.0 to 1.0: mostly the
+    /// share of query words found in the title, plus a bonus for the exact phrase.
+    pub fn relevance(&self, query: &str) -> f32 {
+        let tokenize = |s: &str| -> Vec<String> {
+            s.split(|c: char| !c.is_alphanumeric())
+                .filter(|t| !t.is_empty())
+                .map(str::to_lowercase)
+                .collect()
+        };
+
+        let query_tokens = tokenize(query);
+        if query_tokens.is_empty() {
+            return 0.0;
+        }
+
+        let title_tokens = tokenize(&self.title);
+        let hits = query_tokens.iter().filter(|q| title_tokens.contains(q)).count();
+        let overlap = hits as f32 / query_tokens.len() as f32;
+
+        let phrase = query_tokens.join(" ");
+        let phrase_bonus = if title_tokens.join(" ").contains(&phrase) { 1.0 } else { 0.0 };
+
+        0.8 * overlap + 0.2 * phrase_bonus
+    }
+
+    /// Blends trust signals into a 0-100 heuristic, or None without a rating.
+    ///
+    /// Weights:
+    /// - stars: up to 60 points (linear over 0-5 stars)
+    /// - review count: up to 30 points (log scale, full at 10,000 reviews)
+    /// - Amazon's Choice badge: 10 points
+    /// - "Frequently returned item" warning: minus 20 points
+    ///
+    /// Badges and warnings that weren't scraped simply add nothing.
+    pub fn quality_score(&self) -> Option<f32> {
+        let rating = self.rating.as_ref()?;
+
+        let stars = 60.0 * (rating.stars / 5.0).clamp(0.0, 1.0);
+        let reviews = 30.0 * ((rating.review_count as f32 + 1.0).log10() / 4.0).min(1.0);
+        let choice = if self.is_amazon_choice { 10.0 } else { 0.0 };
+        let returns = if self.is_frequently_returned { 20.0 } else { 0.0 };
+
+        Some((stars + reviews + choice - returns).clamp(0.0, 100.0))
+    }
+
+    /// Combines a search-result product (`self`) with a richer record for the
+    /// same item, usually parsed from its product page (`other`).
+    ///
+    /// Precedence:
+    /// - Optional fields, text and lists come from `other` when it has them,
+    ///   falling back to `self` (a hidden price never replaces a visible one).
+    /// - `is_sponsored` and `rank` are kept from `self`, since only listings know them.
+    /// - `in_stock` comes from `other`; badges and warnings are set if either has them.
+    pub fn merge(self, other: Product) -> Product {
+        let pick = |ours: String, theirs: String| if theirs.is_empty() { ours } else { theirs };
+        let price = match other.price {
+            Some(price) if !price.is_hidden => Some(price),
+            price => self.price.or(price),
+        };
+        let related_asins =
+            if other.related_asins.is_empty() { self.related_asins } else { other.related_asins };
+
+        Product {
+            asin: pick(self.asin, other.asin),
+            title: pick(self.title, other.title),
+            url: pick(self.url, other.url),
+            image_url: other.image_url.or(self.image_url),
+            price,
+            rating: other.rating.or(self.rating),
+            is_sponsored: self.is_sponsored,
+            is_prime: self.is_prime || other.is_prime,
+            is_amazon_choice: self.is_amazon_choice || other.is_amazon_choice,
+            in_stock: other.in_stock,
+            brand: other.brand.or(self.brand),
+            related_asins,
+            currency_mismatch: self.currency_mismatch || other.currency_mismatch,
+            is_digital: self.is_digital || other.is_digital,
+            is_frequently_returned: self.is_frequently_returned || other.is_frequently_returned,
+            promotion: other.promotion.or(self.promotion),
+            energy_class: other.energy_class.or(self.energy_class),
+            dimensions: other.dimensions.or(self.dimensions),
+            weight: other.weight.or(self.weight),
+            latest_review_date: other.latest_review_date.or(self.latest_review_date),
+            other_sellers_from: other.other_sellers_from.or(self.other_sellers_from),
+            rank: self.rank.or(other.rank),
+            coupon_code: other.coupon_code.or(self.coupon_code),
+            subscribe_price: other.subscribe_price.or(self.subscribe_price),
+            subscribe_discount_percent: other
+                .subscribe_discount_percent
+                .or(self.subscribe_discount_percent),
+            unit_price: other.unit_price.or(self.unit_price),
+            ships_internationally: other.ships_internationally.or(self.ships_internationally),
+            stock_remaining: other.stock_remaining.or(self.stock_remaining),
+            raw: other.raw.or(self.raw),
+        }
+    }
+
     /// Returns discount percentage if on sale.
     pub fn discount_percent(&self) -> Option<u8> {
         self.price.as_ref().and_then(|p| {
@@ -49,10 +221,21 @@ impl Product {
             })
         })
     }
+
+    /// Percent saved by the Subscribe & Save price against the one-time
+    /// price, rounded and capped at 99 like [`Product::discount_percent`].
+    /// None when S&S isn't cheaper.
+    pub fn subscribe_discount(one_time: f64, subscribe: f64) -> Option<u8> {
+        if one_time <= 0.0 || subscribe >= one_time {
+            return None;
+        }
+        let discount = ((one_time - subscribe) / one_time * 100.0).round() as u8;
+        Some(discount.min(99))
+    }
 }
 
 /// Price information including current, original, and range prices.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Price {
     /// Current/sale price
     pub current: f64,
@@ -64,12 +247,26 @@ pub struct Price {
     pub range: Option<PriceRange>,
     /// True if price is "See price in cart"
     pub is_hidden: bool,
+    /// Currency symbol as it appeared on the page (e.g. "$", "€")
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Labeled "List Price" (MSRP), distinct from the previous/strikethrough price
+    #[serde(default)]
+    pub list_price: Option<f64>,
 }
 
 impl Price {
     /// Creates a simple price with just current value.
     pub fn simple(current: f64, currency: impl Into<String>) -> Self {
-        Self { current, original: None, currency: currency.into(), range: None, is_hidden: false }
+        Self {
+            current,
+            original: None,
+            currency: currency.into(),
+            range: None,
+            is_hidden: false,
+            symbol: None,
+            list_price: None,
+        }
     }
 
     /// Creates a price with original/sale price.
@@ -80,6 +277,8 @@ impl Price {
             currency: currency.into(),
             range: None,
             is_hidden: false,
+            symbol: None,
+            list_price: None,
         }
     }
 
@@ -91,6 +290,8 @@ impl Price {
             currency: currency.into(),
             range: None,
             is_hidden: true,
+            symbol: None,
+            list_price: None,
         }
     }
 
@@ -102,12 +303,14 @@ impl Price {
             currency: currency.into(),
             range: Some(PriceRange { min, max }),
             is_hidden: false,
+            symbol: None,
+            list_price: None,
         }
     }
 }
 
 /// Price range for items with variable pricing ("from $X" or "$X - $Y").
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PriceRange {
     /// Minimum price
     pub min: f64,
@@ -115,8 +318,18 @@ pub struct PriceRange {
     pub max: Option<f64>,
 }
 
+/// Price per base unit, normalized from labels like "(€2.50/100 g)" to
+/// 25.00 per kg.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct UnitPrice {
+    /// Price for one base unit, in the product's currency
+    pub amount: f64,
+    /// Base unit: "kg", "l", "count", or the label's own unit if unknown
+    pub unit: String,
+}
+
 /// Product rating and review count.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Rating {
     /// Star rating (0.0 - 5.0)
     pub stars: f32,
@@ -131,6 +344,21 @@ impl Rating {
     }
 }
 
+/// Text extracted for a product before normalization, for debugging
+/// selectors and parsing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RawFields {
+    /// Title text as found in the page
+    pub title: Option<String>,
+    /// Current price text (e.g. "$1,299.99")
+    pub price: Option<String>,
+    /// Star rating text (e.g. "4,5 von 5 Sternen")
+    pub rating: Option<String>,
+}
+
+/// Organic results Amazon shows per search page in the default list layout.
+pub const RESULTS_PER_PAGE: u32 = 48;
+
 /// Search results container with metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResults {
@@ -146,6 +374,17 @@ pub struct SearchResults {
     pub page: u32,
     /// Whether there are more pages
     pub has_more: bool,
+    /// Href of the "Next" pagination link, carrying Amazon's paging state
+    #[serde(default)]
+    pub next_url: Option<String>,
+    /// Outer HTML of each product's card, parallel to `products` (only with
+    /// `Parser::with_card_html`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub card_html: Vec<String>,
+    /// Outer HTML of each card that failed to parse and was skipped (only
+    /// with `Parser::with_card_html`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_card_html: Vec<String>,
 }
 
 impl SearchResults {
@@ -158,9 +397,19 @@ impl SearchResults {
             products: Vec::new(),
             page: 1,
             has_more: false,
+            next_url: None,
+            card_html: Vec::new(),
+            failed_card_html: Vec::new(),
         }
     }
 
+    /// Estimates how many result pages exist from `total_results`, given
+    /// `per_page` organic results per page. None when the total is unknown.
+    pub fn estimated_pages(&self, per_page: u32) -> Option<u32> {
+        let total = self.total_results?;
+        Some(total.div_ceil(per_page.max(1)).max(1))
+    }
+
     /// Returns number of products.
     pub fn count(&self) -> usize {
         self.products.len()
@@ -189,9 +438,144 @@ mod tests {
             is_amazon_choice: false,
             in_stock: true,
             brand: Some("TestBrand".to_string()),
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
+    #[test]
+    fn test_relevance_full_match_beats_partial() {
+        let mut full = make_test_product();
+        full.title = "Logitech MX Master 3S Wireless Mouse".to_string();
+        let mut partial = make_test_product();
+        partial.title = "Razer Wired Gaming Mouse".to_string();
+
+        let query = "wireless mouse";
+        assert!(full.relevance(query) > partial.relevance(query));
+        assert_eq!(full.relevance(query), 1.0);
+        assert_eq!(partial.relevance(query), 0.4);
+    }
+
+    #[test]
+    fn test_quality_score_high_vs_low_signal() {
+        let mut high = make_test_product();
+        high.rating = Some(Rating::new(4.8, 12_000));
+        high.is_amazon_choice = true;
+
+        let mut low = make_test_product();
+        low.rating = Some(Rating::new(3.9, 8));
+        low.is_frequently_returned = true;
+
+        let high_score = high.quality_score().unwrap();
+        let low_score = low.quality_score().unwrap();
+        assert!((high_score - 97.6).abs() < 0.01, "{}", high_score);
+        assert!(low_score < 40.0, "{}", low_score);
+        assert!(high_score > low_score);
+
+        // Same rating without the badge still ranks between them
+        high.is_amazon_choice = false;
+        let plain = high.quality_score().unwrap();
+        assert!(plain < high_score && plain > low_score);
+
+        // A best seller list position is not a badge and doesn't count
+        high.rank = Some(1);
+        assert_eq!(high.quality_score().unwrap(), plain);
+    }
+
+    #[test]
+    fn test_quality_score_needs_rating() {
+        let mut product = make_test_product();
+        product.rating = None;
+        product.is_amazon_choice = true;
+        assert_eq!(product.quality_score(), None);
+
+        product.rating = Some(Rating::new(0.0, 0));
+        product.is_frequently_returned = true;
+        assert_eq!(product.quality_score(), Some(0.0));
+    }
+
+    #[test]
+    fn test_relevance_ignores_case_and_punctuation() {
+        let mut product = make_test_product();
+        product.title = "USB-C Charger, 65W (GaN)".to_string();
+
+        assert_eq!(product.relevance("usb c charger"), 1.0);
+        assert_eq!(product.relevance("laptop stand"), 0.0);
+        assert_eq!(product.relevance("  "), 0.0);
+    }
+
+    #[test]
+    fn test_canonical_url_strips_tracking() {
+        let mut product = make_test_product();
+        product.asin = "B09HM94VDS".to_string();
+        product.url = "https://www.amazon.co.uk/Logitech-Master-Performance-Ultra-fast-Scrolling/dp/B09HM94VDS/ref=sr_1_3?crid=2Q&keywords=mouse&qid=1700000000&sr=8-3".to_string();
+        assert_eq!(product.canonical_url(), "https://www.amazon.co.uk/dp/B09HM94VDS");
+
+        product.url = "http://amazon.de/gp/product/B09HM94VDS?th=1".to_string();
+        assert_eq!(product.canonical_url(), "https://www.amazon.de/dp/B09HM94VDS");
+
+        product.url = "http://127.0.0.1:8080/dp/B09HM94VDS/ref=sr_1_1".to_string();
+        assert_eq!(product.canonical_url(), product.url);
+    }
+
+    #[test]
+    fn test_merge_takes_fields_from_both() {
+        let mut search = make_test_product();
+        search.is_sponsored = true;
+        search.brand = None;
+        search.url = "https://amazon.com/dp/TEST123/ref=sr_1_1".to_string();
+
+        let mut detail = make_test_product();
+        detail.title = String::new();
+        detail.url = "https://amazon.com/dp/TEST123".to_string();
+        detail.price = Some(Price::hidden("USD"));
+        detail.rating = None;
+        detail.brand = Some("Acme".to_string());
+        detail.is_frequently_returned = true;
+        detail.dimensions = Some("20 x 10 x 5 cm".to_string());
+
+        let merged = search.merge(detail);
+
+        // Kept from the search result
+        assert!(merged.is_sponsored);
+        assert_eq!(merged.title, "Test Product");
+        assert_eq!(merged.current_price(), Some(20.0));
+        assert_eq!(merged.stars(), Some(4.5));
+        // Taken from the product page
+        assert_eq!(merged.url, "https://amazon.com/dp/TEST123");
+        assert_eq!(merged.brand.as_deref(), Some("Acme"));
+        assert!(merged.is_frequently_returned);
+        assert_eq!(merged.dimensions.as_deref(), Some("20 x 10 x 5 cm"));
+    }
+
+    #[test]
+    fn test_merge_prefers_detail_price() {
+        let search = make_test_product();
+        let mut detail = make_test_product();
+        detail.price = Some(Price::simple(18.5, "USD"));
+        detail.in_stock = false;
+
+        let merged = search.merge(detail);
+        assert_eq!(merged.current_price(), Some(18.5));
+        assert!(!merged.in_stock);
+    }
+
     #[test]
     fn test_price_simple() {
         let price = Price::simple(29.99, "USD");
@@ -262,6 +646,18 @@ mod tests {
         assert!(product.stars().is_none());
     }
 
+    #[test]
+    fn test_subscribe_discount() {
+        assert_eq!(Product::subscribe_discount(20.0, 17.0), Some(15));
+        assert_eq!(Product::subscribe_discount(9.99, 9.49), Some(5));
+        assert_eq!(Product::subscribe_discount(10.0, 0.01), Some(99));
+
+        // S&S no cheaper than one-time, or no usable one-time price
+        assert!(Product::subscribe_discount(10.0, 10.0).is_none());
+        assert!(Product::subscribe_discount(10.0, 12.0).is_none());
+        assert!(Product::subscribe_discount(0.0, 5.0).is_none());
+    }
+
     #[test]
     fn test_discount_percent() {
         let product = make_test_product();
@@ -314,6 +710,20 @@ mod tests {
         assert_eq!(results.count(), 1);
     }
 
+    #[test]
+    fn test_estimated_pages() {
+        let mut results = SearchResults::new("test", "us");
+        assert_eq!(results.estimated_pages(RESULTS_PER_PAGE), None);
+
+        results.total_results = Some(100);
+        assert_eq!(results.estimated_pages(RESULTS_PER_PAGE), Some(3));
+        assert_eq!(results.estimated_pages(50), Some(2));
+        assert_eq!(results.estimated_pages(0), Some(100));
+
+        results.total_results = Some(0);
+        assert_eq!(results.estimated_pages(RESULTS_PER_PAGE), Some(1));
+    }
+
     #[test]
     fn test_product_serde() {
         let product = make_test_product();
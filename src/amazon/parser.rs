@@ -1,12 +1,311 @@
 //! HTML parser for Amazon search results and product pages.
 
-use crate::amazon::models::{Price, PriceRange, Product, Rating, SearchResults};
+use crate::amazon::error::BlockedError;
+use crate::amazon::models::{
+    Price, PriceRange, Product, Rating, RawFields, SearchResults, UnitPrice,
+};
 use crate::amazon::regions::Region;
-use crate::amazon::selectors::{errors, product, search};
+use crate::amazon::selectors::{bestsellers, errors, product, search};
+use crate::config::{Config, DecimalSeparator};
 use anyhow::{Context, Result};
-use scraper::{ElementRef, Html};
+use scraper::{ElementRef, Html, Selector};
 use tracing::{debug, trace, warn};
 
+/// Phrases (matched case-insensitively) on pages Amazon serves with a 200
+/// status to block scrapers without showing a proper CAPTCHA form.
+const DEFAULT_BLOCK_PHRASES: &[&str] = &[
+    "enter the characters you see below",
+    "type the characters you see in this image",
+    "to discuss automated access to amazon data",
+    "sorry, we just need to make sure you're not a robot",
+];
+
+/// Collapses runs of whitespace (including non-breaking spaces and newlines)
+/// to single spaces and trims the ends.
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Text of the first matched element, exactly as it appears in the page.
+fn first_text<'a>(mut elements: impl Iterator<Item = ElementRef<'a>>) -> Option<String> {
+    elements.next().map(|e| e.text().collect())
+}
+
+/// Returns an element's whitespace-normalized text, or None if it's empty.
+fn promotion_text(element: ElementRef) -> Option<String> {
+    let text = normalize_text(&element.text().collect::<Vec<_>>().join(" "));
+    (!text.is_empty()).then_some(text)
+}
+
+/// Picks the promo code out of coupon text such as "Save 15% with code
+/// SPRING15" or "Promo code: TAKE10". Codes are upper-case letters and digits.
+fn parse_coupon_code(text: &str) -> Option<String> {
+    let mut tokens = text.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if !token.trim_end_matches(':').eq_ignore_ascii_case("code") {
+            continue;
+        }
+        let code = tokens.next()?.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+        let is_code = code.len() >= 3
+            && code.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && code.chars().any(|c| c.is_ascii_uppercase());
+        if is_code {
+            return Some(code.to_string());
+        }
+    }
+    None
+}
+
+/// Picks the energy class letter (A-G) out of badge text such as
+/// "Energy efficiency class: D" or "Energieeffizienzklasse A".
+fn parse_energy_class(text: &str) -> Option<char> {
+    text.split(|c: char| !c.is_alphanumeric()).find_map(|token| {
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c @ 'A'..='G'), None) => Some(c),
+            _ => None,
+        }
+    })
+}
+
+/// Reads the energy class from a badge's text, or its `alt`/`aria-label`
+/// when the badge is an image.
+fn energy_class(element: ElementRef) -> Option<char> {
+    parse_energy_class(&element.text().collect::<Vec<_>>().join(" ")).or_else(|| {
+        element
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .flat_map(|e| [e.value().attr("alt"), e.value().attr("aria-label")])
+            .flatten()
+            .find_map(parse_energy_class)
+    })
+}
+
+/// Extracts the units left from low-stock availability text, e.g. 3 from
+/// "Only 3 left in stock" or "Noch 3 auf Lager". Plain "In Stock" gives `None`.
+fn parse_stock_remaining(text: &str) -> Option<u32> {
+    let lower = normalize_text(text).to_lowercase();
+    LOW_STOCK_PHRASES.iter().find_map(|phrase| {
+        lower.match_indices(phrase).find_map(|(start, _)| {
+            let rest = lower[start + phrase.len()..].trim_start();
+            let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok().filter(|n| *n > 0)
+        })
+    })
+}
+
+/// Classifies a delivery message: `Some(false)` when it says the item can't
+/// ship to the buyer, `Some(true)` when it mentions shipping abroad.
+fn parse_delivery_message(text: &str) -> Option<bool> {
+    let lower = normalize_text(text).to_lowercase();
+    if UNDELIVERABLE_PHRASES.iter().any(|phrase| lower.contains(phrase)) {
+        Some(false)
+    } else if SHIPS_PHRASES.iter().any(|phrase| lower.contains(phrase)) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Reads the delivery/export messages of a product page. A "does not
+/// deliver" message anywhere wins over a "ships to" one.
+fn ships_internationally(document: &Html) -> Option<bool> {
+    let verdicts: Vec<bool> = document
+        .select(&product::DELIVERY_MESSAGE)
+        .filter_map(|e| parse_delivery_message(&e.text().collect::<Vec<_>>().join(" ")))
+        .collect();
+    if verdicts.contains(&false) {
+        Some(false)
+    } else {
+        verdicts.first().copied()
+    }
+}
+
+/// Collects (label, value) pairs from the product details table and bullet list.
+fn product_details(document: &Html) -> Vec<(String, String)> {
+    // Bullets are wrapped in invisible direction marks, e.g. "Item Weight \u{200f} : \u{200e} 450 g"
+    let clean = |text: String| {
+        let text: String = text.chars().filter(|c| !matches!(c, '\u{200e}' | '\u{200f}')).collect();
+        normalize_text(&text)
+    };
+
+    let rows = document.select(&product::DETAIL_ROWS).filter_map(|row| {
+        let label = row.select(&product::DETAIL_LABEL).next()?.text().collect::<String>();
+        let value = row.select(&product::DETAIL_VALUE).next()?.text().collect::<String>();
+        Some((clean(label), clean(value)))
+    });
+
+    let bullets = document.select(&product::DETAIL_BULLETS).filter_map(|item| {
+        let text = clean(item.text().collect());
+        let (label, value) = text.split_once(':')?;
+        Some((label.trim().to_string(), value.trim().to_string()))
+    });
+
+    rows.chain(bullets).filter(|(label, value)| !label.is_empty() && !value.is_empty()).collect()
+}
+
+/// Finds the dimensions and weight in the product details by their labels.
+///
+/// Some pages combine both in one row ("20 x 10 x 5 cm; 450 g"); the part
+/// after the semicolon is used as the weight if there's no weight row.
+fn parse_dimensions_and_weight(document: &Html) -> (Option<String>, Option<String>) {
+    let details = product_details(document);
+    let find = |labels: &[&str]| {
+        details.iter().find_map(|(label, value)| {
+            let label = label.to_lowercase();
+            labels.iter().any(|l| label.contains(l)).then(|| value.clone())
+        })
+    };
+
+    let mut weight = find(WEIGHT_LABELS);
+    let dimensions = find(DIMENSION_LABELS).map(|value| match value.split_once(';') {
+        Some((dims, combined_weight)) => {
+            weight.get_or_insert_with(|| combined_weight.trim().to_string());
+            dims.trim().to_string()
+        }
+        None => value,
+    });
+
+    (dimensions, weight)
+}
+
+/// Words that precede the date in review headers, e.g. "Reviewed in the
+/// United States on March 3, 2024" or "Rezension aus Deutschland vom 3. März 2024".
+const REVIEW_DATE_MARKERS: &[&str] = &[" on ", " vom ", " le ", " el ", " il ", " op ", " den "];
+
+/// Lowercased month-name prefixes (en/de/fr/es/it/nl), indexed by month - 1.
+const MONTH_PREFIXES: [&[&str]; 12] = [
+    &["jan", "ene", "gen"],
+    &["feb", "fév", "fev"],
+    &["mar", "mär", "maa"],
+    &["apr", "avr", "abr"],
+    &["may", "mai", "mag", "mei"],
+    &["jun", "juin", "giu"],
+    &["jul", "juil", "lug"],
+    &["aug", "aoû", "aou", "ago"],
+    &["sep", "set"],
+    &["oct", "okt", "ott"],
+    &["nov"],
+    &["dec", "dez", "déc", "dic"],
+];
+
+/// Strips the "Reviewed in ... on" prefix from a review header, keeping the raw date.
+fn review_date_text(header: &str) -> String {
+    let header = normalize_text(header);
+    REVIEW_DATE_MARKERS
+        .iter()
+        .filter_map(|marker| header.rfind(marker).map(|i| i + marker.len()))
+        .max()
+        .map_or(header.clone(), |start| header[start..].to_string())
+}
+
+/// Sortable (year, month, day) for dates like "March 3, 2024" or "3. März 2024".
+fn review_date_key(date: &str) -> Option<(u32, u32, u32)> {
+    let tokens: Vec<String> = date
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    // Numeric tokens only: "june"/"juli" are four letters, "de" two
+    let numeric = |t: &&String| t.bytes().all(|b| b.is_ascii_digit());
+    let year = tokens.iter().filter(numeric).find(|t| t.len() == 4).and_then(|t| t.parse().ok())?;
+    let day = tokens.iter().filter(numeric).find(|t| t.len() <= 2).and_then(|t| t.parse().ok())?;
+    let month = tokens.iter().find_map(|t| {
+        MONTH_PREFIXES.iter().position(|prefixes| prefixes.iter().any(|p| t.starts_with(p)))
+    })?;
+
+    Some((year, month as u32 + 1, day))
+}
+
+/// Best-effort date of the newest review on the page, as displayed.
+///
+/// Reviews are usually sorted by helpfulness, so the dates are compared when
+/// they can be read; otherwise the first one is returned.
+fn latest_review_date(document: &Html) -> Option<String> {
+    let dates: Vec<String> = document
+        .select(&product::REVIEW_DATE)
+        .map(|e| review_date_text(&e.text().collect::<String>()))
+        .filter(|date| !date.is_empty())
+        .collect();
+
+    let keys: Option<Vec<_>> = dates.iter().map(|date| review_date_key(date)).collect();
+    match keys {
+        Some(keys) => keys.iter().zip(&dates).max_by_key(|(key, _)| **key).map(|(_, d)| d.clone()),
+        None => dates.into_iter().next(),
+    }
+}
+
+/// Returns true if `s` looks like an ASIN (10 ASCII alphanumerics).
+fn is_asin(s: &str) -> bool {
+    s.len() == 10 && s.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Extracts the ASIN from a product URL such as `/dp/B08N5WRWNW` or
+/// `/gp/product/B08N5WRWNW?ref=...`.
+fn asin_from_url(url: &str) -> Option<&str> {
+    ["/dp/", "/gp/product/"].iter().find_map(|marker| {
+        let rest = &url[url.find(marker)? + marker.len()..];
+        let asin = rest.split(['/', '?', '#']).next()?;
+        is_asin(asin).then_some(asin)
+    })
+}
+
+/// Reads a product page's own ASIN from the hidden `ASIN` input, the detail
+/// table, or the canonical link.
+fn page_asin(document: &Html) -> Option<String> {
+    document
+        .select(&product::ASIN)
+        .map(|e| e.value().attr("value").map_or_else(|| e.text().collect(), String::from))
+        .map(|asin| asin.trim().to_string())
+        .find(|asin| is_asin(asin))
+        .or_else(|| {
+            document
+                .select(&product::CANONICAL_LINK)
+                .filter_map(|e| e.value().attr("href"))
+                .find_map(asin_from_url)
+                .map(String::from)
+        })
+}
+
+/// Currency symbols recognized around prices, as (as written, canonical).
+/// "R$" comes before "$" so Brazilian prices aren't read as dollars.
+const CURRENCY_SYMBOLS: &[(&str, &str)] = &[
+    ("R$", "R$"),
+    ("$", "$"),
+    ("€", "€"),
+    ("£", "£"),
+    ("¥", "¥"),
+    // Amazon Japan renders the full-width yen sign
+    ("\u{FFE5}", "¥"),
+    ("₹", "₹"),
+    ("zł", "zł"),
+    ("kr", "kr"),
+    ("₺", "₺"),
+    // Amazon Turkey often writes the lira as "TL"
+    ("TL", "₺"),
+];
+
+/// Extracts the currency symbol surrounding a price, e.g. "$" from "$29.99"
+/// or "€" from "29,99 €". Known symbols are returned in canonical form (see
+/// `Region::currency_for_symbol`), others as written. Returns `None` if the
+/// text is just a number.
+fn detect_currency_symbol(text: &str) -> Option<&str> {
+    let is_amount = |c: char| c.is_ascii_digit() || c == '.' || c == ',';
+    let text = text.trim();
+
+    let prefix = text.split(is_amount).next().unwrap_or("").trim();
+    let symbol =
+        if prefix.is_empty() { text.rsplit(is_amount).next().unwrap_or("").trim() } else { prefix };
+
+    if symbol.is_empty() {
+        return None;
+    }
+
+    let known = CURRENCY_SYMBOLS.iter().find(|(written, _)| symbol.contains(written));
+    Some(known.map_or(symbol, |(_, canonical)| canonical))
+}
+
 /// Heuristic to discard non-brand text matched by the broad search BRAND
 /// selectors (badges, delivery dates, "no offers" placeholders).
 fn looks_like_brand(s: &str) -> bool {
@@ -55,15 +354,258 @@ fn looks_like_brand(s: &str) -> bool {
     true
 }
 
+/// Localized "Currently unavailable" / "Out of stock" phrases shown on
+/// out-of-stock listings.
+const UNAVAILABLE_PHRASES: &[&str] = &[
+    "currently unavailable",
+    "out of stock",
+    "nicht auf lager",
+    "rupture de stock",
+    "agotado",
+    "esaurito",
+    "derzeit nicht verfügbar",
+    "actuellement indisponible",
+    "no disponible",
+    "non disponibile",
+    "momenteel niet verkrijgbaar",
+    "現在在庫切れです",
+];
+
+/// Localized low-stock phrases, each followed by the number of units left
+/// ("Only 3 left in stock", "Nur noch 3 auf Lager", "Il ne reste plus que 3").
+const LOW_STOCK_PHRASES: &[&str] =
+    &["only", "noch", "plus que", "quedan", "solo", "nog maar", "残り"];
+
+/// Localized delivery messages saying the item can't be shipped to the
+/// buyer's location. Checked before `SHIPS_PHRASES`.
+const UNDELIVERABLE_PHRASES: &[&str] = &[
+    "does not deliver to your location",
+    "cannot be shipped to your selected delivery location",
+    "does not ship to",
+    "kann nicht an ihren ausgewählten lieferort versendet werden",
+    "liefert nicht an ihren standort",
+    "ne peut pas être expédié à l'adresse de livraison sélectionnée",
+    "ne livre pas à votre emplacement",
+    "no se puede enviar a la dirección de entrega seleccionada",
+    "non può essere spedito all'indirizzo di consegna selezionato",
+    "kan niet naar de geselecteerde bezorglocatie worden verzonden",
+    "お届け先に発送できません",
+];
+
+/// Localized delivery messages saying the item ships abroad.
+const SHIPS_PHRASES: &[&str] = &[
+    "ships to",
+    "international shipping",
+    "eligible for amazonglobal",
+    "versand nach",
+    "internationaler versand",
+    "expédié vers",
+    "livraison internationale",
+    "envío internacional",
+    "spedizione internazionale",
+    "internationale verzending",
+];
+
+/// Lowercased labels marking a "List Price" (MSRP), as opposed to a
+/// "Typical price" or previous price.
+const LIST_PRICE_LABELS: &[&str] = &[
+    "list price",
+    "uvp",
+    "prix conseillé",
+    "precio recomendado",
+    "prezzo consigliato",
+    "adviesprijs",
+    "m.r.p",
+    "参考価格",
+];
+
+/// Lowercased inline prefixes for an original price rendered as plain text
+/// ("List: $39.99", "UVP: 49,99 €") instead of a struck-through price.
+const INLINE_ORIGINAL_PREFIXES: &[&str] = &[
+    "list:",
+    "was:",
+    "uvp:",
+    "statt:",
+    "prix conseillé:",
+    "ancien prix:",
+    "precio anterior:",
+    "prezzo precedente:",
+    "m.r.p.:",
+];
+
+/// Lowercased product-details labels for the item's dimensions, across regions.
+const DIMENSION_LABELS: &[&str] = &[
+    "dimensions",
+    "abmessungen",
+    "dimensiones",
+    "dimensioni",
+    "afmetingen",
+    "mått",
+    "wymiary",
+    "boyutlar",
+    "寸法",
+    "サイズ",
+];
+
+/// Lowercased product-details labels for the item's weight, across regions.
+const WEIGHT_LABELS: &[&str] =
+    &["weight", "gewicht", "poids", "peso", "vikt", "waga", "ağırlı", "重量"];
+
+/// Lowercased phrases on digital listings (ebooks, audiobooks, gift cards, codes).
+const DIGITAL_PHRASES: &[&str] = &[
+    "kindle edition",
+    "kindle ebook",
+    "audible audiobook",
+    "gift card",
+    "egift",
+    "digital code",
+    "digital download",
+    "prime video",
+];
+
+/// Lowercased link fragments used by Amazon for digital item pages.
+const DIGITAL_URL_PATTERNS: &[&str] = &["-ebook/dp/", "/digital/", "/gc/"];
+
+/// Heuristic for digital items from title/badge text and the listing URL.
+fn looks_digital(text: &str, url: &str) -> bool {
+    let text = text.to_lowercase();
+    let url = url.to_lowercase();
+    DIGITAL_PHRASES.iter().any(|p| text.contains(p))
+        || DIGITAL_URL_PATTERNS.iter().any(|p| url.contains(p))
+}
+
+/// Prices closer than this are considered the same amount.
+const PRICE_EPSILON: f64 = 0.005;
+
+/// Maximum number of related ASINs kept from a product page carousel.
+const MAX_RELATED_ASINS: usize = 20;
+
 /// Parser for Amazon HTML pages.
 pub struct Parser {
     region: Region,
+    /// Keep the shown price on listings that are out of stock
+    out_of_stock_price: bool,
+    /// Only count Prime badges in the product page buy box
+    strict_prime: bool,
+    /// Fail instead of falling back when a title or expected price is missing
+    strict_parse: bool,
+    /// Decimal separator override (None = the region's convention)
+    decimal: Option<DecimalSeparator>,
+    /// Currency code override (None = the region's currency)
+    currency: Option<String>,
+    /// Keep the raw title/price/rating text on each product
+    capture_raw: bool,
+    /// Keep each search card's outer HTML in `SearchResults::card_html`
+    capture_card_html: bool,
+    /// Lowercased phrases that mark a page as a soft block
+    block_phrases: Vec<String>,
+    /// Elements that mark a page as a soft block
+    block_selectors: Vec<Selector>,
 }
 
 impl Parser {
     /// Creates a new parser for the given region.
     pub fn new(region: Region) -> Self {
-        Self { region }
+        Self {
+            region,
+            out_of_stock_price: false,
+            strict_prime: false,
+            strict_parse: false,
+            decimal: None,
+            currency: None,
+            capture_raw: false,
+            capture_card_html: false,
+            block_phrases: DEFAULT_BLOCK_PHRASES.iter().map(|p| p.to_string()).collect(),
+            block_selectors: Vec::new(),
+        }
+    }
+
+    /// Applies the parsing options in `config` (stock and price conventions).
+    pub fn with_config(self, config: &Config) -> Self {
+        let mut parser = self
+            .with_out_of_stock_price(config.include_out_of_stock_price)
+            .with_strict_prime(config.strict_prime)
+            .with_strict_parse(config.strict_parse)
+            .with_raw_capture(config.debug_raw);
+        for phrase in &config.block_phrases {
+            parser = parser.with_block_phrase(phrase);
+        }
+        for selector in &config.block_selectors {
+            match Selector::parse(selector) {
+                Ok(selector) => parser.block_selectors.push(selector),
+                Err(e) => warn!("Ignoring invalid block selector '{}': {}", selector, e),
+            }
+        }
+        parser.decimal = config.decimal;
+        parser.currency = config.currency.clone();
+        parser
+    }
+
+    /// Parses prices with the given decimal separator instead of the region's.
+    pub fn with_decimal(mut self, decimal: DecimalSeparator) -> Self {
+        self.decimal = Some(decimal);
+        self
+    }
+
+    /// Labels prices with the given currency code instead of the region's.
+    pub fn with_currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    /// Keeps the last-known price on out-of-stock listings instead of dropping it.
+    pub fn with_out_of_stock_price(mut self, keep: bool) -> Self {
+        self.out_of_stock_price = keep;
+        self
+    }
+
+    /// Only sets `is_prime` on product pages when the Prime badge is in the
+    /// buy box, not in carousels or ads elsewhere on the page.
+    pub fn with_strict_prime(mut self, strict: bool) -> Self {
+        self.strict_prime = strict;
+        self
+    }
+
+    /// Returns an error when a search card has no title, or a listing that
+    /// isn't marked unavailable has no price, instead of using "Unknown"/None.
+    pub fn with_strict_parse(mut self, strict: bool) -> Self {
+        self.strict_parse = strict;
+        self
+    }
+
+    /// Records the raw title, price and rating text on each product
+    /// (`Product::raw`), for debugging selectors.
+    pub fn with_raw_capture(mut self, capture: bool) -> Self {
+        self.capture_raw = capture;
+        self
+    }
+
+    /// Keeps the outer HTML of every parsed search card in
+    /// `SearchResults::card_html` (index `i` belongs to `products[i]`), and of
+    /// every card that failed to parse in `SearchResults::failed_card_html`,
+    /// so fields can be re-extracted offline. Off by default: it roughly
+    /// doubles the memory held per results page.
+    pub fn with_card_html(mut self, capture: bool) -> Self {
+        self.capture_card_html = capture;
+        self
+    }
+
+    /// Treats pages containing `phrase` (case-insensitive) as a soft block,
+    /// in addition to the built-in phrases.
+    pub fn with_block_phrase(mut self, phrase: &str) -> Self {
+        self.block_phrases.push(normalize_text(&phrase.to_lowercase()));
+        self
+    }
+
+    /// Returns true if the page is Amazon's CAPTCHA challenge.
+    pub fn is_captcha(&self, html: &str) -> bool {
+        Html::parse_document(html).select(&errors::CAPTCHA).next().is_some()
+    }
+
+    /// Returns true if the page is a CAPTCHA, error page or soft block, i.e.
+    /// anything the parse methods would reject as blocked.
+    pub fn is_blocked(&self, html: &str) -> bool {
+        self.check_for_errors(&Html::parse_document(html)).is_err()
     }
 
     /// Parses search results HTML into structured data.
@@ -85,20 +627,34 @@ impl Parser {
                 Ok(Some(product)) => {
                     trace!("Parsed product: {} - {}", product.asin, product.title);
                     results.products.push(product);
+                    if self.capture_card_html {
+                        results.card_html.push(element.html());
+                    }
                 }
                 Ok(None) => {
                     // Empty ASIN, skip (ad placeholder or similar)
                     trace!("Skipping empty result card");
                 }
+                Err(e) if self.strict_parse => {
+                    return Err(e.context(format!("Strict parse failed on page {}", page)));
+                }
                 Err(e) => {
                     warn!("Failed to parse product card: {}", e);
+                    if self.capture_card_html {
+                        results.failed_card_html.push(element.html());
+                    }
                     // Continue parsing other products
                 }
             }
         }
 
         // Check for next page
-        results.has_more = document.select(&search::NEXT_PAGE).next().is_some();
+        let next = document.select(&search::NEXT_PAGE).next();
+        results.has_more = next.is_some();
+        results.next_url = next
+            .and_then(|e| e.value().attr("href"))
+            .filter(|href| !href.is_empty())
+            .map(String::from);
 
         debug!(
             "Parsed {} products from page {} (has_more: {})",
@@ -110,25 +666,156 @@ impl Parser {
         Ok(results)
     }
 
+    /// Parses a best seller list page into products carrying their rank.
+    pub fn parse_bestsellers(&self, html: &str) -> Result<Vec<Product>> {
+        let document = Html::parse_document(html);
+        self.check_for_errors(&document)?;
+
+        let mut products: Vec<Product> = Vec::new();
+        for element in document.select(&bestsellers::ITEM) {
+            // Layouts nest the item containers, so the same ASIN can match twice
+            let Some(product) = self.parse_bestseller_item(element, products.len() as u32 + 1)
+            else {
+                continue;
+            };
+            if !products.iter().any(|p| p.asin == product.asin) {
+                trace!("Parsed best seller #{:?}: {}", product.rank, product.asin);
+                products.push(product);
+            }
+        }
+
+        debug!("Parsed {} best sellers", products.len());
+        Ok(products)
+    }
+
+    /// Parses one ranked item; `position` is used when it has no rank badge.
+    fn parse_bestseller_item(&self, element: ElementRef, position: u32) -> Option<Product> {
+        let href = element
+            .select(&bestsellers::LINK)
+            .next()
+            .and_then(|e| e.value().attr("href"))
+            .unwrap_or_default();
+        let asin = element
+            .select(&bestsellers::ASIN_HOLDER)
+            .flat_map(|e| [e.value().attr("data-asin"), e.value().attr("id")])
+            .flatten()
+            .find(|id| is_asin(id))
+            .or_else(|| asin_from_url(href))?
+            .to_string();
+
+        let rank = element
+            .select(&bestsellers::RANK)
+            .next()
+            .and_then(|e| {
+                let digits: String =
+                    e.text().collect::<String>().chars().filter(char::is_ascii_digit).collect();
+                digits.parse().ok()
+            })
+            .unwrap_or(position);
+
+        let image = element.select(&bestsellers::IMAGE).next();
+        let title = element
+            .select(&bestsellers::TITLE)
+            .map(|e| normalize_text(&e.text().collect::<String>()))
+            .find(|title| !title.is_empty())
+            .or_else(|| image.and_then(|e| e.value().attr("alt")).map(normalize_text))
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let image_url = image.and_then(|e| e.value().attr("src")).map(String::from);
+
+        let price = element.select(&bestsellers::PRICE).find_map(|e| {
+            let text = e.text().collect::<String>();
+            let (current, symbol) = self.parse_price_with_symbol(&text)?;
+            Some(Price {
+                symbol: symbol.map(String::from),
+                ..Price::simple(current, self.currency())
+            })
+        });
+
+        let rating = element.select(&bestsellers::RATING_STARS).next().and_then(|e| {
+            let stars = self.parse_stars(&e.text().collect::<String>())?;
+            let count = element
+                .select(&bestsellers::RATING_COUNT)
+                .next()
+                .map(|e| self.parse_review_count(&e.text().collect::<String>()))
+                .unwrap_or(0);
+            Some(Rating::new(stars, count))
+        });
+
+        let currency_mismatch = self.check_currency_mismatch(&asin, price.as_ref());
+        let is_digital = looks_digital(&title, href);
+        let raw = self.capture_raw.then(|| RawFields {
+            title: first_text(element.select(&bestsellers::TITLE)),
+            price: first_text(element.select(&bestsellers::PRICE)),
+            rating: first_text(element.select(&bestsellers::RATING_STARS)),
+        });
+
+        Some(Product {
+            url: format!("{}/dp/{}", self.region.base_url(), asin),
+            asin,
+            title,
+            image_url,
+            in_stock: price.is_some(),
+            price,
+            rating,
+            is_sponsored: false,
+            is_prime: element.select(&bestsellers::PRIME_BADGE).next().is_some(),
+            is_amazon_choice: false,
+            brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch,
+            is_digital,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: Some(rank),
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
+        })
+    }
+
     /// Parses a single product page by ASIN.
     pub fn parse_product_page(&self, html: &str, asin: &str) -> Result<Product> {
+        self.parse_product_document(&Html::parse_document(html), asin)
+    }
+
+    /// Parses a product page, reading the ASIN from the page itself (the
+    /// hidden `ASIN` input or the canonical link), e.g. for dumped HTML.
+    pub fn parse_product_page_autoasin(&self, html: &str) -> Result<Product> {
         let document = Html::parse_document(html);
+        self.check_for_errors(&document)?;
+        self.check_for_age_gate(&document, None)?;
+
+        let asin = page_asin(&document).context("Could not find the ASIN on the product page")?;
+        self.parse_product_document(&document, &asin)
+    }
 
+    fn parse_product_document(&self, document: &Html, asin: &str) -> Result<Product> {
         // Check for error pages
-        self.check_for_errors(&document)?;
+        self.check_for_errors(document)?;
+        self.check_for_age_gate(document, Some(asin))?;
 
         // Parse title
         let title = document
             .select(&product::TITLE)
             .next()
-            .map(|e| e.text().collect::<String>().trim().to_string())
+            .map(|e| normalize_text(&e.text().collect::<String>()))
             .context("Could not find product title")?;
 
         // Parse price
-        let price = self.parse_product_page_price(&document);
+        let price = self.parse_product_page_price(document);
 
         // Parse rating
-        let rating = self.parse_product_page_rating(&document);
+        let rating = self.parse_product_page_rating(document);
 
         // Parse image
         let image_url = document.select(&product::IMAGE).next().and_then(|e| {
@@ -137,10 +824,7 @@ impl Parser {
 
         // Parse brand
         let brand = document.select(&product::BRAND).next().map(|e| {
-            let text = e.text().collect::<String>();
-            let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
-            normalized
-                .trim()
+            normalize_text(&e.text().collect::<String>())
                 .trim_start_matches("Brand:")
                 .trim_start_matches("Visit the")
                 .trim_end_matches("Store")
@@ -150,18 +834,91 @@ impl Parser {
                 .to_string()
         });
 
-        // Check availability
-        let in_stock = document.select(&product::AVAILABILITY).next().is_some_and(|e| {
-            let text = e.text().collect::<String>().to_lowercase();
-            text.contains("in stock") || text.contains("available")
-        });
+        // Check availability ("Currently unavailable" also contains "available",
+        // so negative phrases win)
+        let availability = document
+            .select(&product::AVAILABILITY)
+            .next()
+            .map(|e| e.text().collect::<String>().to_lowercase())
+            .unwrap_or_default();
+        let unavailable = UNAVAILABLE_PHRASES.iter().any(|phrase| availability.contains(phrase));
+        let in_stock = !unavailable
+            && (availability.contains("in stock") || availability.contains("available"));
+        if self.strict_parse && in_stock && price.is_none() {
+            anyhow::bail!("Product page {} is in stock but has no price", asin);
+        }
+        let price = self.stock_price(price, !unavailable);
+        let stock_remaining = parse_stock_remaining(&availability);
 
         // Check for Prime
-        let is_prime = document.select(&product::PRIME).next().is_some();
+        let prime = if self.strict_prime { &product::PRIME_BUY_BOX } else { &product::PRIME };
+        let is_prime = document.select(prime).next().is_some();
 
         // Check for Amazon's Choice
         let is_amazon_choice = document.select(&product::AMAZON_CHOICE).next().is_some();
 
+        // Flag prices shown in a different currency than the region expects
+        let currency_mismatch = self.check_currency_mismatch(asin, price.as_ref());
+
+        // Detect ebooks, gift cards, etc.
+        let is_digital = looks_digital(&title, "");
+
+        // Amazon's "Frequently returned item" warning (the feature div can be
+        // present but empty)
+        let is_frequently_returned = document
+            .select(&product::FREQUENTLY_RETURNED)
+            .any(|e| !e.text().collect::<String>().trim().is_empty());
+
+        // Multi-buy / promotion message
+        let promotion = document.select(&product::PROMOTION).find_map(promotion_text);
+
+        // Whether the item ships to the buyer's location (heuristic)
+        let ships_internationally = ships_internationally(document);
+
+        // Promo code from the coupon/promotion messages ("clip coupon" boxes have none)
+        let coupon_code = document
+            .select(&product::COUPON)
+            .find_map(|e| parse_coupon_code(&e.text().collect::<Vec<_>>().join(" ")));
+
+        // Subscribe & Save price, and its saving over the one-time price
+        let subscribe_price = document
+            .select(&product::SUBSCRIBE_PRICE)
+            .find_map(|e| self.parse_price_value(&e.text().collect::<String>()));
+        let subscribe_discount_percent = subscribe_price.and_then(|subscribe| {
+            let one_time = price.as_ref().filter(|p| !p.is_hidden)?.current;
+            Product::subscribe_discount(one_time, subscribe)
+        });
+
+        // Grocery-style price per unit
+        let unit_price = document
+            .select(&product::UNIT_PRICE)
+            .find_map(|e| self.parse_unit_price(&e.text().collect::<String>()));
+
+        // Unparsed text behind the main fields, for --debug-raw
+        let raw = self.capture_raw.then(|| RawFields {
+            title: first_text(document.select(&product::TITLE)),
+            price: first_text(document.select(&product::PRICE)),
+            rating: first_text(document.select(&product::RATING)),
+        });
+
+        // EU energy efficiency label
+        let energy_class = document.select(&product::ENERGY_CLASS).find_map(energy_class);
+
+        // Dimensions and weight from the product details table/bullets
+        let (dimensions, weight) = parse_dimensions_and_weight(document);
+
+        // Newest of the reviews shown on the page
+        let latest_review_date = latest_review_date(document);
+
+        // Lowest "New & Used from" / "Other Sellers on Amazon" offer
+        let other_sellers_from = document
+            .select(&product::OTHER_SELLERS_PRICE)
+            .filter_map(|e| self.parse_price_value(&e.text().collect::<String>()))
+            .min_by(f64::total_cmp);
+
+        // Collect "also viewed" ASINs
+        let related_asins = self.parse_related_asins(document, asin);
+
         Ok(Product {
             asin: asin.to_string(),
             title,
@@ -174,6 +931,24 @@ impl Parser {
             is_amazon_choice,
             in_stock,
             brand,
+            related_asins,
+            currency_mismatch,
+            is_digital,
+            is_frequently_returned,
+            promotion,
+            energy_class,
+            dimensions,
+            weight,
+            latest_review_date,
+            other_sellers_from,
+            rank: None,
+            coupon_code,
+            subscribe_price,
+            subscribe_discount_percent,
+            raw,
+            unit_price,
+            ships_internationally,
+            stock_remaining,
         })
     }
 
@@ -181,23 +956,43 @@ impl Parser {
     fn check_for_errors(&self, document: &Html) -> Result<()> {
         // Check for CAPTCHA
         if document.select(&errors::CAPTCHA).next().is_some() {
-            anyhow::bail!(
-                "CAPTCHA detected. Amazon is blocking requests. \
-                Try using a proxy or waiting before retrying."
-            );
+            return Err(BlockedError::Captcha.into());
         }
 
         // Check for dog page (503 error page)
         if document.select(&errors::DOG_PAGE).next().is_some() {
-            anyhow::bail!(
-                "Amazon error page detected (503). \
-                The service may be temporarily unavailable."
-            );
+            return Err(BlockedError::ErrorPage.into());
+        }
+
+        // Soft blocks: a 200 page that's neither, but matches a block indicator
+        if let Some(selector) =
+            self.block_selectors.iter().find(|s| document.select(s).next().is_some())
+        {
+            debug!("Block selector matched: {:?}", selector);
+            return Err(BlockedError::SoftBlock.into());
+        }
+        let text = document.root_element().text().collect::<Vec<_>>().join(" ");
+        let text = normalize_text(&text.to_lowercase());
+        if let Some(phrase) = self.block_phrases.iter().find(|p| text.contains(p.as_str())) {
+            debug!("Block phrase matched: {}", phrase);
+            return Err(BlockedError::SoftBlock.into());
         }
 
         Ok(())
     }
 
+    /// Fails with a clear message when a product page is Amazon's age
+    /// verification interstitial, which has no product title or details.
+    fn check_for_age_gate(&self, document: &Html, asin: Option<&str>) -> Result<()> {
+        if document.select(&errors::AGE_GATE).next().is_none() {
+            return Ok(());
+        }
+        match asin {
+            Some(asin) => anyhow::bail!("Product {} is age-restricted and requires sign-in", asin),
+            None => anyhow::bail!("Product is age-restricted and requires sign-in"),
+        }
+    }
+
     /// Parses a single product card from search results.
     fn parse_product_card(&self, element: ElementRef) -> Result<Option<Product>> {
         // Get ASIN
@@ -210,8 +1005,13 @@ impl Parser {
         let title = element
             .select(&search::TITLE)
             .next()
-            .map(|e| e.text().collect::<String>().trim().to_string())
-            .unwrap_or_else(|| "Unknown".to_string());
+            .map(|e| normalize_text(&e.text().collect::<String>()))
+            .filter(|title| !title.is_empty());
+        let title = match title {
+            Some(title) => title,
+            None if self.strict_parse => anyhow::bail!("Product card {} has no title", asin),
+            None => "Unknown".to_string(),
+        };
 
         // Build canonical product URL from ASIN
         let url = format!("{}/dp/{}", self.region.base_url(), asin);
@@ -241,11 +1041,7 @@ impl Parser {
         // filter out badges, delivery dates, and "no offer" text that share
         // the same selectors.
         let brand = element.select(&search::BRAND).find_map(|e| {
-            let text = e.text().collect::<String>();
-            let cleaned = text
-                .split_whitespace()
-                .collect::<Vec<_>>()
-                .join(" ")
+            let cleaned = normalize_text(&e.text().collect::<String>())
                 .trim_start_matches("by ")
                 .trim()
                 .to_string();
@@ -256,8 +1052,48 @@ impl Parser {
             }
         });
 
-        // Check stock (assume in stock if price is shown)
-        let in_stock = price.is_some();
+        // Check stock (assume in stock if price is shown, unless the card
+        // explicitly says the item is unavailable)
+        let unavailable = self.is_unavailable(element);
+        if self.strict_parse && price.is_none() && !unavailable {
+            anyhow::bail!("Product card {} has no price", asin);
+        }
+        let in_stock = price.is_some() && !unavailable;
+        let price = self.stock_price(price, !unavailable);
+
+        // "Only 3 left in stock" urgency text
+        let stock_remaining = element
+            .select(&search::AVAILABILITY)
+            .find_map(|e| parse_stock_remaining(&e.text().collect::<String>()));
+
+        let currency_mismatch = self.check_currency_mismatch(&asin, price.as_ref());
+
+        // Detect ebooks, gift cards, etc. from the card text (title and
+        // format badges) and the original listing link
+        let href = element
+            .select(&search::TITLE_LINK)
+            .next()
+            .and_then(|e| e.value().attr("href"))
+            .unwrap_or_default();
+        let is_digital = looks_digital(&element.text().collect::<String>(), href);
+
+        // Multi-buy / promotion message (informational only)
+        let promotion = element.select(&search::PROMOTION).find_map(promotion_text);
+
+        // Grocery-style price per unit, among the card's secondary labels
+        let unit_price = element
+            .select(&search::UNIT_PRICE)
+            .find_map(|e| self.parse_unit_price(&e.text().collect::<String>()));
+
+        // Unparsed text behind the main fields, for --debug-raw
+        let raw = self.capture_raw.then(|| RawFields {
+            title: first_text(element.select(&search::TITLE)),
+            price: first_text(element.select(&search::PRICE_CURRENT)),
+            rating: first_text(element.select(&search::RATING_STARS)),
+        });
+
+        // EU energy efficiency badge
+        let energy_class = element.select(&search::ENERGY_CLASS).find_map(energy_class);
 
         Ok(Some(Product {
             asin,
@@ -271,9 +1107,50 @@ impl Parser {
             is_amazon_choice,
             in_stock,
             brand,
+            related_asins: Vec::new(),
+            currency_mismatch,
+            is_digital,
+            is_frequently_returned: false,
+            promotion,
+            energy_class,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw,
+            unit_price,
+            ships_internationally: None,
+            stock_remaining,
         }))
     }
 
+    /// Collects unique ASINs from the related products carousel, skipping the
+    /// page's own ASIN and capping the list at `MAX_RELATED_ASINS`.
+    fn parse_related_asins(&self, document: &Html, own_asin: &str) -> Vec<String> {
+        let mut asins: Vec<String> = Vec::new();
+
+        for element in document.select(&product::RELATED_ITEMS) {
+            let Some(asin) = element.value().attr("data-asin") else { continue };
+            let asin = asin.trim();
+            if !is_asin(asin) {
+                continue;
+            }
+            if asin.eq_ignore_ascii_case(own_asin) || asins.iter().any(|a| a == asin) {
+                continue;
+            }
+            asins.push(asin.to_string());
+            if asins.len() >= MAX_RELATED_ASINS {
+                break;
+            }
+        }
+
+        asins
+    }
+
     /// Parses price from a search result card.
     fn parse_search_price(&self, element: ElementRef) -> Option<Price> {
         // Try to get the offscreen price text first (most reliable)
@@ -284,16 +1161,17 @@ impl Parser {
         if current_text.to_lowercase().contains("cart")
             || current_text.to_lowercase().contains("see price")
         {
-            return Some(Price::hidden(self.region.currency()));
+            return Some(Price::hidden(self.currency()));
         }
 
-        let current = self.parse_price_value(&current_text)?;
+        let (current, symbol) = self.parse_price_with_symbol(&current_text)?;
 
         // Check for original price
         let original = element
             .select(&search::PRICE_ORIGINAL)
             .next()
-            .and_then(|e| self.parse_price_value(&e.text().collect::<String>()));
+            .and_then(|e| self.parse_price_value(&e.text().collect::<String>()))
+            .or_else(|| self.parse_inline_original(&element.text().collect::<String>(), current));
 
         // Check for price range
         let range = self.detect_price_range(element, current);
@@ -301,33 +1179,109 @@ impl Parser {
         Some(Price {
             current,
             original,
-            currency: self.region.currency().to_string(),
+            currency: self.currency().to_string(),
             range,
             is_hidden: false,
+            symbol: symbol.map(String::from),
+            list_price: None,
         })
     }
 
     /// Parses price from a product detail page.
     fn parse_product_page_price(&self, document: &Html) -> Option<Price> {
-        let current = document
+        let (current, symbol) = document
             .select(&product::PRICE)
             .map(|e| e.text().collect::<String>())
-            .find_map(|t| self.parse_price_value(&t))?;
+            .find_map(|t| {
+                let (value, symbol) = self.parse_price_with_symbol(&t)?;
+                Some((value, symbol.map(String::from)))
+            })?;
 
         let original = document
             .select(&product::PRICE_ORIGINAL)
             .next()
-            .and_then(|e| self.parse_price_value(&e.text().collect::<String>()));
+            .and_then(|e| self.parse_price_value(&e.text().collect::<String>()))
+            .or_else(|| {
+                document.select(&product::PRICE_BLOCK).find_map(|e| {
+                    self.parse_inline_original(&e.text().collect::<String>(), current)
+                })
+            });
 
         Some(Price {
             current,
             original,
-            currency: self.region.currency().to_string(),
+            currency: self.currency().to_string(),
             range: None,
             is_hidden: false,
+            symbol,
+            list_price: self.parse_list_price(document),
         })
     }
 
+    /// Finds an original price written inline after a "List:"/"Was:"-style
+    /// prefix. Only prices above `current` are accepted as an original.
+    fn parse_inline_original(&self, text: &str, current: f64) -> Option<f64> {
+        let lower = text.to_lowercase();
+        INLINE_ORIGINAL_PREFIXES.iter().find_map(|prefix| {
+            let (_, rest) = lower.split_once(prefix)?;
+            let rest = rest.trim_start_matches(|c: char| !c.is_ascii_digit());
+            let end = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                .unwrap_or(rest.len());
+            let value = self.parse_price_value(&rest[..end])?;
+            (value > current).then_some(value)
+        })
+    }
+
+    /// Parses the labeled "List Price" (MSRP) from a product detail page.
+    fn parse_list_price(&self, document: &Html) -> Option<f64> {
+        document
+            .select(&product::BASIS_PRICE)
+            .filter(|e| {
+                let label = e.text().collect::<String>().to_lowercase();
+                LIST_PRICE_LABELS.iter().any(|l| label.contains(l))
+            })
+            .find_map(|e| {
+                let value = e.select(&product::BASIS_PRICE_VALUE).next()?;
+                self.parse_price_value(&value.text().collect::<String>())
+            })
+    }
+
+    /// Returns true (and logs a warning) if the price's raw symbol doesn't
+    /// match the region's currency, e.g. after a silent redirect to another store.
+    fn check_currency_mismatch(&self, asin: &str, price: Option<&Price>) -> bool {
+        let Some(symbol) = price.and_then(|p| p.symbol.as_deref()) else {
+            return false;
+        };
+
+        // Without a known symbol for an overridden currency there's nothing to compare
+        let expected = match &self.currency {
+            Some(code) => match Region::from_currency(code) {
+                Some(region) => region.currency_symbol(),
+                None => return false,
+            },
+            None => self.region.currency_symbol(),
+        };
+        if symbol == expected {
+            return false;
+        }
+
+        warn!(
+            "Currency mismatch for {}: found '{}' but {} expects '{}'",
+            asin,
+            symbol,
+            self.region.domain(),
+            expected
+        );
+        true
+    }
+
+    /// Parses a price value along with its currency symbol, so callers can
+    /// cross-check the symbol against the region.
+    fn parse_price_with_symbol<'a>(&self, text: &'a str) -> Option<(f64, Option<&'a str>)> {
+        Some((self.parse_price_value(text)?, detect_currency_symbol(text)))
+    }
+
     /// Parses a price value from text, handling different regional formats.
     fn parse_price_value(&self, text: &str) -> Option<f64> {
         let cleaned: String = text
@@ -350,6 +1304,70 @@ impl Parser {
         self.parse_single_price(&cleaned)
     }
 
+    /// Parses a unit price label like "(€2.50/100 g)" or "(1,99 €/l)",
+    /// normalizing weights to per-kg, volumes to per-liter and counts to
+    /// per-item prices.
+    fn parse_unit_price(&self, text: &str) -> Option<UnitPrice> {
+        let text = normalize_text(text);
+        let inner = text.trim_start_matches('(').trim_end_matches(')');
+        let (price, per) = inner.rsplit_once('/')?;
+        // The price is often rendered twice (offscreen + visible): "€2.50€2.50"
+        let price = price.trim();
+        let half = price.len() / 2;
+        let price = match price.split_at_checked(half) {
+            Some((first, second)) if first == second => first,
+            _ => price,
+        };
+        // A known currency symbol keeps "5G/LTE" or "Nr. 1 in Haferflocken/Müsli" out
+        let symbol = detect_currency_symbol(price)?;
+        if !CURRENCY_SYMBOLS.iter().any(|(_, canonical)| *canonical == symbol) {
+            return None;
+        }
+        let amount = self.parse_price_value(price)?;
+
+        // "100 g", "100g", "l", "1,5 l": an optional quantity, then the unit
+        let per = per.trim();
+        let split =
+            per.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')).unwrap_or(per.len());
+        let (quantity, unit) = per.split_at(split);
+        let quantity = match quantity.trim() {
+            "" => 1.0,
+            quantity => self.parse_single_price(quantity)?,
+        };
+        let unit = unit.trim().to_lowercase();
+        if quantity <= 0.0 || !unit.starts_with(char::is_alphabetic) {
+            return None;
+        }
+
+        let (factor, base) = match unit.as_str() {
+            "mg" => (1_000_000.0, "kg"),
+            "g" | "gr" | "gram" | "grams" | "gramm" | "grammes" => (1000.0, "kg"),
+            "kg" | "kilo" | "kilogram" | "kilogramm" | "kilogramme" => (1.0, "kg"),
+            "ml" | "milliliter" | "millilitre" => (1000.0, "l"),
+            "cl" => (100.0, "l"),
+            "dl" => (10.0, "l"),
+            "l" | "lt" | "liter" | "litre" | "litro" => (1.0, "l"),
+            "count" | "ct" | "each" | "unit" | "item" | "stück" | "stk" | "pièce" | "unidad"
+            | "pezzo" => (1.0, "count"),
+            other => (1.0, other),
+        };
+        let amount = (amount / quantity * factor * 10_000.0).round() / 10_000.0;
+        Some(UnitPrice { amount, unit: base.to_string() })
+    }
+
+    /// Returns the currency code for parsed prices.
+    fn currency(&self) -> &str {
+        self.currency.as_deref().unwrap_or(self.region.currency())
+    }
+
+    /// Returns true if prices use a comma as the decimal separator.
+    fn uses_comma_decimal(&self) -> bool {
+        match self.decimal {
+            Some(decimal) => decimal == DecimalSeparator::Comma,
+            None => self.region.uses_comma_decimal(),
+        }
+    }
+
     /// Parses a single price number.
     fn parse_single_price(&self, text: &str) -> Option<f64> {
         let cleaned = text.trim();
@@ -357,8 +1375,8 @@ impl Parser {
             return None;
         }
 
-        // Determine decimal separator based on region
-        let normalized = if self.region.uses_comma_decimal() {
+        // Determine decimal separator based on region (or the override)
+        let normalized = if self.uses_comma_decimal() {
             // EU format: 1.234,56 -> 1234.56
             cleaned.replace('.', "").replace(',', ".")
         } else {
@@ -370,20 +1388,21 @@ impl Parser {
     }
 
     /// Detects if there's a price range.
+    ///
+    /// Cards often repeat the current price (e.g. an accessibility duplicate)
+    /// or show a struck-through original next to it, so only a non-struck
+    /// price strictly above `min` counts as the range's max.
     fn detect_price_range(&self, element: ElementRef, min: f64) -> Option<PriceRange> {
         // Check for explicit price range container
-        if element.select(&search::PRICE_RANGE).next().is_some() {
-            // Try to find max price from second price element
-            let prices: Vec<_> = element.select(&search::PRICE_CURRENT).collect();
-            if prices.len() >= 2 {
-                if let Some(max) = self.parse_price_value(&prices[1].text().collect::<String>()) {
-                    if max > min {
-                        return Some(PriceRange { min, max: Some(max) });
-                    }
-                }
-            }
-        }
-        None
+        element.select(&search::PRICE_RANGE).next()?;
+
+        let max = element
+            .select(&search::PRICE_UNSTRUCK)
+            .filter_map(|e| self.parse_price_value(&e.text().collect::<String>()))
+            .filter(|&price| price - min >= PRICE_EPSILON)
+            .reduce(f64::max)?;
+
+        Some(PriceRange { min, max: Some(max) })
     }
 
     /// Parses rating from a search result card.
@@ -424,12 +1443,34 @@ impl Parser {
         Some(Rating::new(stars, review_count))
     }
 
-    /// Extracts star rating from text like "4.5 out of 5 stars".
+    /// Extracts star rating from text like "4.5 out of 5 stars",
+    /// "Bewertet mit 4,5 von 5 Sternen" or "5つ星のうち4.3".
     fn parse_stars(&self, text: &str) -> Option<f32> {
-        // Look for a decimal number (e.g., "4.5")
-        let re_pattern = text.split_whitespace().next()?.replace(',', "."); // Handle "4,5" format
+        // Collect numbers like "4", "4.5" or "4,5" wherever they appear
+        let mut numbers: Vec<String> = Vec::new();
+        let mut current = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c.is_ascii_digit() {
+                current.push(c);
+            } else if (c == '.' || c == ',')
+                && !current.is_empty()
+                && !current.contains('.')
+                && chars.peek().is_some_and(|next| next.is_ascii_digit())
+            {
+                current.push('.');
+            } else if !current.is_empty() {
+                numbers.push(std::mem::take(&mut current));
+            }
+        }
+        if !current.is_empty() {
+            numbers.push(current);
+        }
 
-        re_pattern.parse().ok()
+        // Prefer the first decimal (the "5" in "5 stars" is the scale), else
+        // fall back to the first whole number ("1 out of 5 stars")
+        let rating = numbers.iter().find(|n| n.contains('.')).or(numbers.first())?;
+        rating.parse().ok().filter(|stars| (0.0..=5.0).contains(stars))
     }
 
     /// Extracts review count from text like "1,234" or "1.234 ratings".
@@ -451,6 +1492,23 @@ impl Parser {
         text.contains("sponsored")
     }
 
+    /// Checks if a product card shows a "Currently unavailable" notice.
+    fn is_unavailable(&self, element: ElementRef) -> bool {
+        element.select(&search::AVAILABILITY).any(|e| {
+            let text = e.text().collect::<String>().to_lowercase();
+            UNAVAILABLE_PHRASES.iter().any(|phrase| text.contains(phrase))
+        })
+    }
+
+    /// Drops the price of a listing marked unavailable unless configured to keep it.
+    fn stock_price(&self, price: Option<Price>, available: bool) -> Option<Price> {
+        if available || self.out_of_stock_price {
+            price
+        } else {
+            None
+        }
+    }
+
     /// Checks if a product has Amazon's Choice badge.
     fn is_amazon_choice(&self, element: ElementRef) -> bool {
         // Check for badge selector
@@ -529,6 +1587,14 @@ mod tests {
         assert_eq!(parser.parse_price_value("¥2,999"), Some(2999.0));
     }
 
+    #[test]
+    fn test_parse_price_turkey() {
+        let parser = Parser::new(Region::Tr);
+        assert_eq!(parser.parse_price_value("1.234,56 ₺"), Some(1234.56));
+        assert_eq!(parser.parse_price_value("₺1.234,56"), Some(1234.56));
+        assert_eq!(parser.parse_price_value("89,90 TL"), Some(89.9));
+    }
+
     #[test]
     fn test_parse_price_with_range() {
         let parser = Parser::new(Region::Us);
@@ -537,6 +1603,40 @@ mod tests {
         assert_eq!(parser.parse_price_value("10-20"), Some(10.0));
     }
 
+    #[test]
+    fn test_detect_price_range_ignores_duplicates() {
+        let parser = Parser::new(Region::Us);
+        let card = |prices: &str| {
+            format!(
+                r#"<html><body>
+                    <div data-component-type="s-search-result" data-asin="B000000001">
+                        <h2><a href="/dp/B000000001"><span>Phone Case</span></a></h2>
+                        {}
+                    </div>
+                </body></html>"#,
+                prices
+            )
+        };
+        let price = |amount: &str| {
+            format!(r#"<span class="a-price"><span class="a-offscreen">{}</span></span>"#, amount)
+        };
+        let range_of = |html: String| {
+            let results = parser.parse_search(&html, "case", 1).unwrap();
+            results.products[0].price.as_ref().unwrap().range.as_ref().map(|r| (r.min, r.max))
+        };
+
+        // Accessibility duplicate of the same price
+        assert_eq!(range_of(card(&format!("{}{}", price("$12.99"), price("$12.99")))), None);
+
+        // Struck-through original next to the current price
+        let strike = r#"<span class="a-price" data-a-strike="true"><span class="a-offscreen">$19.99</span></span>"#;
+        assert_eq!(range_of(card(&format!("{}{}", price("$12.99"), strike))), None);
+
+        // Genuine range, each end duplicated
+        let prices = [price("$10.00"), price("$10.00"), price("$20.00"), price("$20.00")].concat();
+        assert_eq!(range_of(card(&prices)), Some((10.0, Some(20.0))));
+    }
+
     #[test]
     fn test_parse_price_empty() {
         let parser = Parser::new(Region::Us);
@@ -552,24 +1652,136 @@ mod tests {
         assert_eq!(parser.parse_single_price("   "), None);
     }
 
-    // Star rating parsing tests
-
     #[test]
-    fn test_parse_stars() {
-        let parser = Parser::new(Region::Us);
-        assert_eq!(parser.parse_stars("4.5 out of 5 stars"), Some(4.5));
-        assert_eq!(parser.parse_stars("4,5 von 5 Sternen"), Some(4.5));
-        assert_eq!(parser.parse_stars("5.0 out of 5 stars"), Some(5.0));
-        assert_eq!(parser.parse_stars("1 out of 5 stars"), Some(1.0));
+    fn test_raw_capture() {
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>  Kaffeemühle
+                        Elektrisch </span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">24,99&nbsp;€</span></span>
+                    <i class="a-icon a-icon-star-small"><span class="a-icon-alt">4,5 von 5 Sternen</span></i>
+                </div>
+            </body></html>
+        "#;
+
+        let parser = Parser::new(Region::De).with_raw_capture(true);
+        let product = &parser.parse_search(html, "test", 1).unwrap().products[0];
+        let raw = product.raw.as_ref().unwrap();
+        assert_eq!(raw.rating.as_deref(), Some("4,5 von 5 Sternen"));
+        assert_eq!(raw.price.as_deref(), Some("24,99\u{a0}€"));
+        assert!(raw.title.as_deref().unwrap().contains("Kaffeemühle\n"));
+        assert_eq!(product.stars(), Some(4.5));
+
+        let json = serde_json::to_value(product).unwrap();
+        assert_eq!(json["_raw"]["rating"], "4,5 von 5 Sternen");
+
+        // Off by default, and left out of the JSON entirely
+        let product = &Parser::new(Region::De).parse_search(html, "test", 1).unwrap().products[0];
+        assert!(product.raw.is_none());
+        assert!(serde_json::to_value(product).unwrap().get("_raw").is_none());
     }
 
     #[test]
-    fn test_parse_stars_edge_cases() {
+    fn test_parse_unit_price() {
         let parser = Parser::new(Region::Us);
-        assert_eq!(parser.parse_stars(""), None);
+        let unit = |text| parser.parse_unit_price(text).map(|u| (u.amount, u.unit));
+
+        assert_eq!(unit("(€2.50/100 g)"), Some((25.0, "kg".to_string())));
+        assert_eq!(unit("($0.25/count)"), Some((0.25, "count".to_string())));
+        assert_eq!(unit("(£3.00 / 500 ml)"), Some((6.0, "l".to_string())));
+        assert_eq!(unit("($1.20$1.20/Ounce)"), Some((1.2, "ounce".to_string())));
+        assert_eq!(unit("($4.99/2 kg)"), Some((2.495, "kg".to_string())));
+
+        let de = Parser::new(Region::De);
+        let unit = |text| de.parse_unit_price(text).map(|u| (u.amount, u.unit));
+        assert_eq!(unit("(2,50 €/100 g)"), Some((25.0, "kg".to_string())));
+        assert_eq!(unit("(1,99 €/l)"), Some((1.99, "l".to_string())));
+        assert_eq!(unit("(3,00 € / 1,5 l)"), Some((2.0, "l".to_string())));
+        assert_eq!(unit("(0,33 €/Stück)"), Some((0.33, "count".to_string())));
+
+        // Not unit prices
+        assert_eq!(unit("4,5 von 5 Sternen"), None);
+        assert_eq!(unit("Lieferung 10/12"), None);
+        assert_eq!(unit("(2,50 €/0 g)"), None);
+        assert_eq!(unit("5G/LTE"), None);
+        assert_eq!(unit("Nr. 1 in Haferflocken/Müsli"), None);
+        assert_eq!(unit("(2,50/100 g)"), None);
     }
 
-    // Review count parsing tests
+    #[test]
+    fn test_parse_search_unit_price() {
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Haferflocken 500 g</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">1,25 €</span></span>
+                    <span class="a-size-base a-color-secondary">Bio</span>
+                    <span class="a-size-base a-color-secondary">(2,50 €/kg)</span>
+                </div>
+            </body></html>
+        "#;
+        let results = Parser::new(Region::De).parse_search(html, "hafer", 1).unwrap();
+        let unit_price = results.products[0].unit_price.as_ref().unwrap();
+        assert_eq!(unit_price.amount, 2.5);
+        assert_eq!(unit_price.unit, "kg");
+    }
+
+    // Star rating parsing tests
+
+    #[test]
+    fn test_parse_stars() {
+        let parser = Parser::new(Region::Us);
+        assert_eq!(parser.parse_stars("4.5 out of 5 stars"), Some(4.5));
+        assert_eq!(parser.parse_stars("4,5 von 5 Sternen"), Some(4.5));
+        assert_eq!(parser.parse_stars("5.0 out of 5 stars"), Some(5.0));
+        assert_eq!(parser.parse_stars("1 out of 5 stars"), Some(1.0));
+    }
+
+    #[test]
+    fn test_parse_price_with_overrides() {
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Staging Item</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">1.234,56 zł</span></span>
+                </div>
+            </body></html>
+        "#;
+
+        let parser =
+            Parser::new(Region::Us).with_decimal(DecimalSeparator::Comma).with_currency("XYZ");
+        let results = parser.parse_search(html, "test", 1).unwrap();
+        let product = &results.products[0];
+        let price = product.price.as_ref().unwrap();
+        assert_eq!(price.current, 1234.56);
+        assert_eq!(price.currency, "XYZ");
+        // No known symbol for the overridden currency, so no mismatch
+        assert!(!product.currency_mismatch);
+
+        // Without the override the US period convention misreads the amount
+        let results = Parser::new(Region::Us).parse_search(html, "test", 1).unwrap();
+        assert_eq!(results.products[0].price.as_ref().unwrap().current, 1.23456);
+    }
+
+    #[test]
+    fn test_parse_stars_localized_phrasings() {
+        let parser = Parser::new(Region::De);
+        assert_eq!(parser.parse_stars("Bewertet mit 4,5 von 5 Sternen"), Some(4.5));
+        assert_eq!(parser.parse_stars("Note 4,3 sur 5"), Some(4.3));
+        assert_eq!(parser.parse_stars("5つ星のうち4.3"), Some(4.3));
+        assert_eq!(parser.parse_stars("Rated 4 out of 5."), Some(4.0));
+    }
+
+    #[test]
+    fn test_parse_stars_edge_cases() {
+        let parser = Parser::new(Region::Us);
+        assert_eq!(parser.parse_stars(""), None);
+        assert_eq!(parser.parse_stars("no rating"), None);
+        assert_eq!(parser.parse_stars("12 ratings"), None);
+    }
+
+    // Review count parsing tests
 
     #[test]
     fn test_parse_review_count() {
@@ -619,6 +1831,70 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("503"));
     }
 
+    #[test]
+    fn test_check_for_errors_soft_block() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div class="a-container">
+                    <h4>Enter the characters you see
+                        below</h4>
+                    <p>Sorry, we just need to make sure you're not a robot.</p>
+                </div>
+            </body></html>
+        "#;
+        let err = parser.parse_search(html, "test", 1).unwrap_err();
+        assert_eq!(BlockedError::find(&err), Some(BlockedError::SoftBlock));
+
+        // Ordinary pages pass
+        let document = Html::parse_document("<html><body><p>Results</p></body></html>");
+        assert!(parser.check_for_errors(&document).is_ok());
+    }
+
+    #[test]
+    fn test_check_for_errors_configured_indicators() {
+        let config = Config {
+            block_phrases: vec!["Unusual   TRAFFIC".to_string()],
+            block_selectors: vec!["#px-captcha".to_string(), "[[invalid".to_string()],
+            ..Config::default()
+        };
+        let parser = Parser::new(Region::Us).with_config(&config);
+
+        let phrase = Html::parse_document("<p>We detected unusual traffic from your network</p>");
+        let err = parser.check_for_errors(&phrase).unwrap_err();
+        assert_eq!(BlockedError::find(&err), Some(BlockedError::SoftBlock));
+
+        let selector = Html::parse_document(r#"<div id="px-captcha"></div>"#);
+        let err = parser.check_for_errors(&selector).unwrap_err();
+        assert_eq!(BlockedError::find(&err), Some(BlockedError::SoftBlock));
+
+        // The built-in phrases still apply, and defaults don't match normal text
+        assert!(Parser::new(Region::Us).check_for_errors(&phrase).is_ok());
+        let builtin = Html::parse_document("<p>To discuss automated access to Amazon data</p>");
+        assert!(parser.check_for_errors(&builtin).is_err());
+    }
+
+    #[test]
+    fn test_parse_product_page_age_gate() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div id="black-curtain-warning">
+                    <h4>This item may contain adult content.</h4>
+                    <p>You must be 18 or older to view this item.</p>
+                    <a id="black-curtain-yes-button" href="/ap/signin?openid.return_to=%2Fdp%2FB000000001">
+                        Sign in to confirm your age
+                    </a>
+                </div>
+            </body></html>
+        "#;
+
+        let err = parser.parse_product_page(html, "B000000001").unwrap_err().to_string();
+        assert_eq!(err, "Product B000000001 is age-restricted and requires sign-in");
+        let err = parser.parse_product_page_autoasin(html).unwrap_err().to_string();
+        assert!(err.contains("age-restricted"), "{}", err);
+    }
+
     #[test]
     fn test_parse_search_empty_results() {
         let parser = Parser::new(Region::Us);
@@ -639,6 +1915,862 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_search_card_html() {
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>First Item</span></a></h2>
+                </div>
+                <div data-component-type="s-search-result" data-asin=""></div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <h2><a href="/dp/B000000002"><span>Second Item</span></a></h2>
+                </div>
+            </body></html>
+        "#;
+
+        let results = Parser::new(Region::Us).parse_search(html, "item", 1).unwrap();
+        assert!(results.card_html.is_empty());
+
+        let results =
+            Parser::new(Region::Us).with_card_html(true).parse_search(html, "item", 1).unwrap();
+        assert_eq!(results.products.len(), 2);
+        assert_eq!(results.card_html.len(), 2);
+        assert!(results.failed_card_html.is_empty());
+        for (product, card) in results.products.iter().zip(&results.card_html) {
+            assert!(card.starts_with("<div"));
+            assert!(card.contains(&format!("data-asin=\"{}\"", product.asin)));
+            assert!(card.contains(&product.title));
+        }
+    }
+
+    #[test]
+    fn test_parse_search_currently_unavailable() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Unavailable Item</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$19.99</span></span>
+                    <span class="a-size-base a-color-price">Currently unavailable.</span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <h2><a href="/dp/B000000002"><span>Available Item</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$29.99</span></span>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "test", 1).unwrap();
+        assert_eq!(results.count(), 2);
+        assert!(!results.products[0].in_stock);
+        assert!(results.products[0].price.is_none());
+        assert!(results.products[1].in_stock);
+
+        let parser = Parser::new(Region::Us).with_out_of_stock_price(true);
+        let results = parser.parse_search(html, "test", 1).unwrap();
+        assert!(!results.products[0].in_stock);
+        assert_eq!(results.products[0].price.as_ref().unwrap().current, 19.99);
+        assert!(results.products[1].in_stock);
+    }
+
+    #[test]
+    fn test_parse_search_out_of_stock_phrases() {
+        let parser = Parser::new(Region::De).with_out_of_stock_price(true);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Out of Stock Brewing Co. Mug</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">19,99 €</span></span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <h2><a href="/dp/B000000002"><span>Tasse</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">9,99 €</span></span>
+                    <div data-cy="availability-recipe"><span>Derzeit nicht auf Lager.</span></div>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "tasse", 1).unwrap();
+        // Only the availability line counts, not the title
+        assert!(results.products[0].in_stock);
+        assert!(!results.products[1].in_stock);
+        assert_eq!(results.products[1].price.as_ref().unwrap().current, 9.99);
+    }
+
+    #[test]
+    fn test_parse_search_promotion() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>AA Batteries 24 Pack</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$14.99</span></span>
+                    <div data-cy="promotion-recipe">
+                        <span>Save 5%</span> <span>when you buy 2</span>
+                    </div>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <h2><a href="/dp/B000000002"><span>AAA Batteries</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$9.99</span></span>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "batteries", 1).unwrap();
+        assert_eq!(results.products[0].promotion.as_deref(), Some("Save 5% when you buy 2"));
+        assert_eq!(results.products[1].promotion, None);
+    }
+
+    #[test]
+    fn test_parse_product_page_coupon_code() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Coffee Pods</span>
+                <div id="promoPriceBlockMessage_feature_div">
+                    <span class="promoPriceBlockMessage">
+                        Save 15% with promo code: <strong>SPRING15</strong>. Terms
+                    </span>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.coupon_code.as_deref(), Some("SPRING15"));
+
+        // Clip-only coupon: detected as a coupon, but there's no code to apply
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Coffee Pods</span>
+                <div id="couponBadgeRegularVpc">
+                    <input type="checkbox"> Apply $5 coupon. Clip coupon, no code needed
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(product.coupon_code.is_none());
+    }
+
+    #[test]
+    fn test_parse_product_page_subscribe_and_save() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Coffee Pods</span>
+                <div id="corePrice_feature_div">
+                    <span class="a-price"><span class="a-offscreen">$20.00</span></span>
+                </div>
+                <div id="snsAccordionRowMiddle">
+                    <span class="a-price"><span class="a-offscreen">$17.00</span></span>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.subscribe_price, Some(17.0));
+        assert_eq!(product.subscribe_discount_percent, Some(15));
+
+        // No S&S offer on the page
+        let html = html.replace("snsAccordionRowMiddle", "somethingElse");
+        let product = parser.parse_product_page(&html, "B000000001").unwrap();
+        assert!(product.subscribe_price.is_none());
+        assert!(product.subscribe_discount_percent.is_none());
+    }
+
+    #[test]
+    fn test_parse_coupon_code() {
+        assert_eq!(parse_coupon_code("Promo code: TAKE10").as_deref(), Some("TAKE10"));
+        assert_eq!(parse_coupon_code("Save 20% with code 20OFFNOW.").as_deref(), Some("20OFFNOW"));
+        assert_eq!(parse_coupon_code("Enter code at checkout"), None);
+        assert_eq!(parse_coupon_code("Save $5 with coupon"), None);
+    }
+
+    #[test]
+    fn test_parse_product_page_promotion() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Coffee Pods</span>
+                <div id="applicable_promotion_list_sec">
+                    <ul><li><span class="a-list-item">Buy 2, save 10%</span></li></ul>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.promotion.as_deref(), Some("Buy 2, save 10%"));
+
+        let html = r#"<html><body><span id="productTitle">Coffee Pods</span></body></html>"#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(product.promotion.is_none());
+    }
+
+    #[test]
+    fn test_parse_search_energy_class() {
+        let parser = Parser::new(Region::De);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Kühlschrank</span></a></h2>
+                    <span class="s-energy-efficiency-badge-standard">
+                        <img alt="Energieeffizienzklasse A" src="/energy-a.svg">
+                    </span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <h2><a href="/dp/B000000002"><span>Waschmaschine</span></a></h2>
+                    <span class="s-energy-efficiency-badge-standard">D</span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000003">
+                    <h2><a href="/dp/B000000003"><span>Buch</span></a></h2>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "kühlschrank", 1).unwrap();
+        assert_eq!(results.products[0].energy_class, Some('A'));
+        assert_eq!(results.products[1].energy_class, Some('D'));
+        assert_eq!(results.products[2].energy_class, None);
+    }
+
+    #[test]
+    fn test_parse_product_page_energy_class() {
+        let parser = Parser::new(Region::Fr);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Lave-linge</span>
+                <div id="energyEfficiency_feature_div">
+                    <span>Classe d'efficacité énergétique : D</span>
+                    <a>Fiche produit</a>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.energy_class, Some('D'));
+    }
+
+    #[test]
+    fn test_parse_product_page_strict_prime() {
+        let html = r#"
+            <html><body>
+                <span id="productTitle">USB Cable</span>
+                <div id="desktop_buybox"><span class="a-price">$9.99</span></div>
+                <div id="sims-simsContainer_feature_div">
+                    <div data-asin="B000000002"><i class="a-icon a-icon-prime"></i></div>
+                </div>
+            </body></html>
+        "#;
+
+        let loose = Parser::new(Region::Us).parse_product_page(html, "B000000001").unwrap();
+        assert!(loose.is_prime);
+
+        let strict = Parser::new(Region::Us).with_strict_prime(true);
+        assert!(!strict.parse_product_page(html, "B000000001").unwrap().is_prime);
+
+        let html = r#"
+            <html><body>
+                <span id="productTitle">USB Cable</span>
+                <div id="desktop_buybox"><i class="a-icon a-icon-prime"></i></div>
+            </body></html>
+        "#;
+        assert!(strict.parse_product_page(html, "B000000001").unwrap().is_prime);
+    }
+
+    #[test]
+    fn test_parse_product_page_dimensions_and_weight() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Desk Lamp</span>
+                <table id="productDetails_techSpec_section_1">
+                    <tr><th> Brand </th><td> Lumos </td></tr>
+                    <tr><th> Product Dimensions </th><td> 20 x 10 x 5 cm </td></tr>
+                    <tr><th> Item Weight </th><td> 450 g </td></tr>
+                </table>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.dimensions.as_deref(), Some("20 x 10 x 5 cm"));
+        assert_eq!(product.weight.as_deref(), Some("450 g"));
+
+        // German detail bullets, dimensions and weight combined in one item
+        let parser = Parser::new(Region::De);
+        let html = "
+            <html><body>
+                <span id=\"productTitle\">Schreibtischlampe</span>
+                <div id=\"detailBullets_feature_div\"><ul>
+                    <li><span class=\"a-text-bold\">Produktabmessungen \u{200f} : \u{200e}</span>
+                        <span>20 x 10 x 5 cm; 450 Gramm</span></li>
+                    <li><span class=\"a-text-bold\">Hersteller \u{200f} : \u{200e}</span>
+                        <span>Lumos</span></li>
+                </ul></div>
+            </body></html>
+        ";
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.dimensions.as_deref(), Some("20 x 10 x 5 cm"));
+        assert_eq!(product.weight.as_deref(), Some("450 Gramm"));
+
+        let html = r#"<html><body><span id="productTitle">Lamp</span></body></html>"#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(product.dimensions.is_none() && product.weight.is_none());
+    }
+
+    #[test]
+    fn test_parse_product_page_autoasin() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Echo Dot</span>
+                <form><input type="hidden" name="ASIN" value="B08N5WRWNW"></form>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page_autoasin(html).unwrap();
+        assert_eq!(product.asin, "B08N5WRWNW");
+        assert_eq!(product.url, "https://www.amazon.com/dp/B08N5WRWNW");
+
+        // Falls back to the canonical link
+        let html = r#"
+            <html><head>
+                <link rel="canonical" href="https://www.amazon.com/Echo-Dot/dp/B07XJ8C8F5?ref=x">
+            </head><body><span id="productTitle">Echo Dot</span></body></html>
+        "#;
+        let product = parser.parse_product_page_autoasin(html).unwrap();
+        assert_eq!(product.asin, "B07XJ8C8F5");
+
+        let html = r#"<html><body><span id="productTitle">Echo Dot</span></body></html>"#;
+        assert!(parser.parse_product_page_autoasin(html).is_err());
+    }
+
+    #[test]
+    fn test_parse_product_page_frequently_returned() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Budget Earbuds</span>
+                <div id="frequentlyReturnedBadge_feature_div">
+                    <span class="a-text-bold">Frequently returned item</span>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(product.is_frequently_returned);
+
+        // Empty placeholder block is not a warning
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Budget Earbuds</span>
+                <div id="frequentlyReturnedBadge_feature_div"> </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(!product.is_frequently_returned);
+    }
+
+    #[test]
+    fn test_parse_product_page_ships_internationally() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Espresso Machine</span>
+                <div id="mir-layout-DELIVERY_BLOCK">
+                    <span>This item does not deliver to your location.
+                    Select a different delivery location.</span>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.ships_internationally, Some(false));
+
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Espresso Machine</span>
+                <div id="amazonGlobal_feature_div">Eligible for AmazonGlobal. Ships to Germany.</div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.ships_internationally, Some(true));
+
+        // No delivery message: unknown rather than false
+        let html = r#"<html><body><span id="productTitle">Espresso Machine</span></body></html>"#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.ships_internationally, None);
+    }
+
+    #[test]
+    fn test_parse_delivery_message_localized() {
+        assert_eq!(
+            parse_delivery_message(
+                "Dieser Artikel kann nicht an Ihren ausgewählten Lieferort versendet werden."
+            ),
+            Some(false)
+        );
+        assert_eq!(parse_delivery_message("Livraison internationale disponible"), Some(true));
+        assert_eq!(parse_delivery_message("In Stock"), None);
+    }
+
+    #[test]
+    fn test_parse_stock_remaining() {
+        assert_eq!(parse_stock_remaining("Only 3 left in stock - order soon."), Some(3));
+        assert_eq!(parse_stock_remaining("Only 12 left in stock (more on the way)."), Some(12));
+        assert_eq!(parse_stock_remaining("Nur noch 2 auf Lager (mehr ist unterwegs)."), Some(2));
+        assert_eq!(parse_stock_remaining("Noch 5 auf Lager"), Some(5));
+        assert_eq!(
+            parse_stock_remaining("Il ne reste plus que 4 exemplaire(s) en stock."),
+            Some(4)
+        );
+        assert_eq!(parse_stock_remaining("In Stock"), None);
+        assert_eq!(parse_stock_remaining("Auf Lager."), None);
+        assert_eq!(parse_stock_remaining("Only available from these sellers."), None);
+    }
+
+    #[test]
+    fn test_parse_product_page_stock_remaining() {
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Espresso Machine</span>
+                <div id="availability"><span>Only 3 left in stock - order soon.</span></div>
+            </body></html>
+        "#;
+        let product = Parser::new(Region::Us).parse_product_page(html, "B000000001").unwrap();
+        assert!(product.in_stock);
+        assert_eq!(product.stock_remaining, Some(3));
+
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Espressomaschine</span>
+                <div id="availability"><span>Nur noch 1 auf Lager</span></div>
+            </body></html>
+        "#;
+        let product = Parser::new(Region::De).parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.stock_remaining, Some(1));
+
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Espresso Machine</span>
+                <div id="availability"><span>In Stock</span></div>
+            </body></html>
+        "#;
+        let product = Parser::new(Region::Us).parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.stock_remaining, None);
+    }
+
+    #[test]
+    fn test_parse_product_page_currently_unavailable() {
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Discontinued Keyboard</span>
+                <span class="a-price"><span class="a-offscreen">$49.99</span></span>
+                <div id="availability"><span>Currently unavailable.</span></div>
+            </body></html>
+        "#;
+
+        let product = Parser::new(Region::Us).parse_product_page(html, "B000000001").unwrap();
+        assert!(!product.in_stock);
+        assert!(product.price.is_none());
+
+        let parser = Parser::new(Region::Us).with_out_of_stock_price(true);
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(!product.in_stock);
+        assert_eq!(product.price.unwrap().current, 49.99);
+    }
+
+    #[test]
+    fn test_parse_search_currency_mismatch() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Redirected Item</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">£19.99</span></span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <h2><a href="/dp/B000000002"><span>Local Item</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$29.99</span></span>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "test", 1).unwrap();
+        assert_eq!(results.count(), 2);
+        assert!(results.products[0].currency_mismatch);
+        assert_eq!(results.products[0].price.as_ref().unwrap().symbol.as_deref(), Some("£"));
+        assert!(!results.products[1].currency_mismatch);
+    }
+
+    #[test]
+    fn test_parse_search_turkish_lira_no_mismatch() {
+        let parser = Parser::new(Region::Tr);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Lira Item</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">89,90 TL</span></span>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "test", 1).unwrap();
+        let product = &results.products[0];
+        assert!(!product.currency_mismatch);
+        assert_eq!(product.price.as_ref().unwrap().symbol.as_deref(), Some("₺"));
+        assert_eq!(product.price.as_ref().unwrap().current, 89.9);
+    }
+
+    #[test]
+    fn test_detect_currency_symbol() {
+        assert_eq!(detect_currency_symbol("$29.99"), Some("$"));
+        assert_eq!(detect_currency_symbol("29,99\u{a0}€"), Some("€"));
+        assert_eq!(detect_currency_symbol("R$ 99,90"), Some("R$"));
+        assert_eq!(detect_currency_symbol("￥1,234"), Some("¥"));
+        assert_eq!(detect_currency_symbol("$10 - $20"), Some("$"));
+        assert_eq!(detect_currency_symbol("29.99"), None);
+    }
+
+    #[test]
+    fn test_detect_currency_symbol_maps_to_currency() {
+        let cases = [
+            ("$29.99", "$", "USD"),
+            ("29,99\u{a0}€", "€", "EUR"),
+            ("£19.99", "£", "GBP"),
+            ("￥1,234", "¥", "JPY"),
+            ("₹1,499.00", "₹", "INR"),
+            ("R$ 99,90", "R$", "BRL"),
+            ("199,00 kr", "kr", "SEK"),
+            ("49,99 zł", "zł", "PLN"),
+            ("US$29.99", "$", "USD"),
+        ];
+        for (text, symbol, currency) in cases {
+            assert_eq!(detect_currency_symbol(text), Some(symbol), "{}", text);
+            assert_eq!(Region::currency_for_symbol(symbol), Some(currency), "{}", text);
+        }
+
+        // Unknown symbols are kept as written, for mismatch warnings
+        assert_eq!(detect_currency_symbol("CHF 12.50"), Some("CHF"));
+
+        let parser = Parser::new(Region::Br);
+        assert_eq!(parser.parse_price_with_symbol("R$ 99,90"), Some((99.90, Some("R$"))));
+        assert_eq!(parser.parse_price_with_symbol("sem preço"), None);
+    }
+
+    #[test]
+    fn test_parse_product_page_list_price() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Noise Cancelling Headphones</span>
+                <div id="corePriceDisplay_desktop_feature_div">
+                    <span class="a-price priceToPay"><span aria-hidden="true">$29.99</span></span>
+                    <span class="a-size-small a-color-secondary basisPrice">List Price:
+                        <span class="a-price a-text-price" data-a-strike="true">
+                            <span class="a-offscreen">$49.99</span>
+                            <span aria-hidden="true">$49.99</span>
+                        </span>
+                    </span>
+                </div>
+            </body></html>
+        "#;
+
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        let price = product.price.unwrap();
+        assert_eq!(price.current, 29.99);
+        assert_eq!(price.list_price, Some(49.99));
+    }
+
+    #[test]
+    fn test_parse_product_page_typical_price_is_not_list_price() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Noise Cancelling Headphones</span>
+                <div id="corePriceDisplay_desktop_feature_div">
+                    <span class="a-price priceToPay"><span aria-hidden="true">$29.99</span></span>
+                    <span class="a-size-small a-color-secondary basisPrice">Typical price:
+                        <span class="a-price a-text-price" data-a-strike="true">
+                            <span class="a-offscreen">$39.99</span>
+                        </span>
+                    </span>
+                </div>
+            </body></html>
+        "#;
+
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        let price = product.price.unwrap();
+        assert_eq!(price.original, Some(39.99));
+        assert_eq!(price.list_price, None);
+    }
+
+    #[test]
+    fn test_parse_search_inline_list_price() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a class="a-link-normal" href="/dp/B000000001"><span>USB-C Hub</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$29.99</span></span>
+                    <span class="a-size-base a-color-secondary">List: $39.99</span>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "hub", 1).unwrap();
+        let price = results.products[0].price.as_ref().unwrap();
+        assert_eq!(price.current, 29.99);
+        assert_eq!(price.original, Some(39.99));
+    }
+
+    #[test]
+    fn test_parse_product_page_inline_uvp() {
+        let parser = Parser::new(Region::De);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Kabellose Maus</span>
+                <div id="corePriceDisplay_desktop_feature_div">
+                    <span class="a-price priceToPay"><span aria-hidden="true">39,99 €</span></span>
+                    <span class="a-size-small a-color-secondary">UVP: 1.049,99 €</span>
+                </div>
+            </body></html>
+        "#;
+
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        let price = product.price.unwrap();
+        assert_eq!(price.current, 39.99);
+        assert_eq!(price.original, Some(1049.99));
+    }
+
+    #[test]
+    fn test_inline_original_must_exceed_current() {
+        let parser = Parser::new(Region::Us);
+        assert_eq!(parser.parse_inline_original("Was: $19.99", 29.99), None);
+        assert_eq!(parser.parse_inline_original("Typical: $39.99", 29.99), None);
+        assert_eq!(parser.parse_inline_original("was: $39.99 (25% off)", 29.99), Some(39.99));
+    }
+
+    #[test]
+    fn test_looks_digital() {
+        assert!(looks_digital("The Hobbit (Kindle Edition)", ""));
+        assert!(looks_digital("Amazon.com eGift Card", ""));
+        assert!(looks_digital("Dune", "/Dune-Frank-Herbert-ebook/dp/B00B7NPRY8"));
+        assert!(!looks_digital("Kindle Paperwhite 16GB", "/dp/B08KTZ8249"));
+        assert!(!looks_digital("Logitech MX Master 3S", "/dp/B09HM94VDS"));
+    }
+
+    #[test]
+    fn test_parse_search_digital_products() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a class="a-link-normal" href="/Hobbit-ebook/dp/B000000001"><span>The Hobbit</span></a></h2>
+                    <a class="a-size-base a-link-normal">Kindle Edition</a>
+                    <span class="a-price"><span class="a-offscreen">$9.99</span></span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <h2><a class="a-link-normal" href="/dp/B000000002"><span>Amazon.com Gift Card in a Greeting Card</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$25.00</span></span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000003">
+                    <h2><a class="a-link-normal" href="/dp/B000000003"><span>The Hobbit Paperback</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$12.99</span></span>
+                </div>
+            </body></html>
+        "#;
+
+        let results = parser.parse_search(html, "hobbit", 1).unwrap();
+        assert_eq!(results.count(), 3);
+        assert!(results.products[0].is_digital);
+        assert!(results.products[1].is_digital);
+        assert!(!results.products[2].is_digital);
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        assert_eq!(
+            normalize_text("  Wireless\u{a0}\u{a0}Mouse,\n   Black \t"),
+            "Wireless Mouse, Black"
+        );
+        assert_eq!(normalize_text("\u{a0}\n "), "");
+    }
+
+    #[test]
+    fn test_titles_normalized_in_both_parsers() {
+        let parser = Parser::new(Region::Us);
+        let html = "
+            <html><body>
+                <div data-component-type=\"s-search-result\" data-asin=\"B000000001\">
+                    <h2><a href=\"/dp/B000000001\"><span>
+                        Wireless\u{a0}\u{a0}Mouse,
+                        Black
+                    </span></a></h2>
+                </div>
+            </body></html>
+        ";
+        let results = parser.parse_search(html, "mouse", 1).unwrap();
+        assert_eq!(results.products[0].title, "Wireless Mouse, Black");
+
+        let html = "
+            <html><body>
+                <span id=\"productTitle\">\n   Wireless\u{a0}Mouse,\n\n  Black   </span>
+                <a id=\"bylineInfo\">Visit the\u{a0}Logi\u{a0}\nStore</a>
+            </body></html>
+        ";
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.title, "Wireless Mouse, Black");
+        assert_eq!(product.brand.as_deref(), Some("Logi"));
+    }
+
+    #[test]
+    fn test_parse_product_page_latest_review_date() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Desk Lamp</span>
+                <div id="cm-cr-dp-review-list">
+                    <span data-hook="review-date">Reviewed in the United States on March 3, 2021</span>
+                    <span data-hook="review-date">Reviewed in the United States on January 15, 2024</span>
+                    <span data-hook="review-date">Reviewed in the United States on December 1, 2023</span>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.latest_review_date.as_deref(), Some("January 15, 2024"));
+
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Schreibtischlampe</span>
+                <span data-hook="review-date">Rezension aus Deutschland vom 3. März 2022</span>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.latest_review_date.as_deref(), Some("3. März 2022"));
+
+        let html = r#"<html><body><span id="productTitle">Lamp</span></body></html>"#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(product.latest_review_date.is_none());
+    }
+
+    #[test]
+    fn test_parse_product_page_other_sellers() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <span id="productTitle">Desk Lamp</span>
+                <div id="corePrice_feature_div"><span class="a-price"><span class="a-offscreen">$24.99</span></span></div>
+                <div id="olp_feature_div">
+                    <a href="/gp/offer-listing/B000000001">New &amp; Used (5) from <span class="a-color-price">$19.49</span></a>
+                </div>
+                <div id="mbc">
+                    <h5>Other Sellers on Amazon</h5>
+                    <span class="a-price"><span class="a-offscreen">$21.00</span></span>
+                    <span class="a-price"><span class="a-offscreen">$18.75</span></span>
+                </div>
+            </body></html>
+        "#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert_eq!(product.other_sellers_from, Some(18.75));
+        assert_eq!(product.current_price(), Some(24.99));
+
+        let html = r#"<html><body><span id="productTitle">Lamp</span></body></html>"#;
+        let product = parser.parse_product_page(html, "B000000001").unwrap();
+        assert!(product.other_sellers_from.is_none());
+    }
+
+    #[test]
+    fn test_strict_parse_rejects_malformed_card() {
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000001">
+                    <h2><a href="/dp/B000000001"><span>Good Item</span></a></h2>
+                    <span class="a-price"><span class="a-offscreen">$19.99</span></span>
+                </div>
+                <div data-component-type="s-search-result" data-asin="B000000002">
+                    <span class="a-price"><span class="a-offscreen">$29.99</span></span>
+                </div>
+            </body></html>
+        "#;
+
+        let lenient = Parser::new(Region::Us).parse_search(html, "test", 1).unwrap();
+        assert_eq!(lenient.products[1].title, "Unknown");
+
+        let strict = Parser::new(Region::Us).with_strict_parse(true);
+        let err = strict.parse_search(html, "test", 1).unwrap_err();
+        assert!(format!("{:#}", err).contains("B000000002 has no title"));
+
+        // A titled card with no price and no "unavailable" text is degraded too
+        let html = r#"
+            <html><body>
+                <div data-component-type="s-search-result" data-asin="B000000003">
+                    <h2><a href="/dp/B000000003"><span>Priceless Item</span></a></h2>
+                </div>
+            </body></html>
+        "#;
+        assert!(Parser::new(Region::Us).parse_search(html, "test", 1).is_ok());
+        let err = strict.parse_search(html, "test", 1).unwrap_err();
+        assert!(format!("{:#}", err).contains("B000000003 has no price"));
+    }
+
+    #[test]
+    fn test_parse_bestsellers() {
+        let parser = Parser::new(Region::Us);
+        let html = r#"
+            <html><body>
+                <div class="p13n-gridRow">
+                    <div id="gridItemRoot">
+                        <div class="zg-grid-general-faceout">
+                            <span class="zg-bdg-text">#1</span>
+                            <div id="B0AAAAAAA1" class="p13n-sc-uncoverable-faceout">
+                                <a class="a-link-normal" href="/Echo-Dot/dp/B0AAAAAAA1/ref=zg_bs_c_1">
+                                    <img alt="Echo Dot (5th Gen)" src="https://images.amazon.com/dot.jpg">
+                                    <div class="_cDEzb_p13n-sc-css-line-clamp-3_g3dy1">Echo Dot (5th Gen)</div>
+                                </a>
+                                <div class="a-icon-row">
+                                    <i class="a-icon a-icon-star-small"><span class="a-icon-alt">4.7 out of 5 stars</span></i>
+                                    <span class="a-size-small">12,345</span>
+                                </div>
+                                <span class="_cDEzb_p13n-sc-price_3mJ9Z">$49.99</span>
+                            </div>
+                        </div>
+                    </div>
+                    <div id="gridItemRoot">
+                        <div class="zg-grid-general-faceout">
+                            <span class="zg-bdg-text">#2</span>
+                            <div id="B0AAAAAAA2" class="p13n-sc-uncoverable-faceout">
+                                <a class="a-link-normal" href="/Fire-Stick/dp/B0AAAAAAA2/ref=zg_bs_c_2">
+                                    <img alt="Fire TV Stick" src="https://images.amazon.com/stick.jpg">
+                                </a>
+                            </div>
+                        </div>
+                    </div>
+                </div>
+            </body></html>
+        "#;
+
+        let products = parser.parse_bestsellers(html).unwrap();
+        assert_eq!(products.len(), 2);
+
+        assert_eq!(products[0].asin, "B0AAAAAAA1");
+        assert_eq!(products[0].rank, Some(1));
+        assert_eq!(products[0].title, "Echo Dot (5th Gen)");
+        assert_eq!(products[0].url, "https://www.amazon.com/dp/B0AAAAAAA1");
+        assert_eq!(products[0].current_price(), Some(49.99));
+        assert_eq!(products[0].stars(), Some(4.7));
+        assert_eq!(products[0].rating.as_ref().unwrap().review_count, 12345);
+
+        // No title element or price: falls back to the image alt text
+        assert_eq!(products[1].asin, "B0AAAAAAA2");
+        assert_eq!(products[1].rank, Some(2));
+        assert_eq!(products[1].title, "Fire TV Stick");
+        assert!(products[1].price.is_none() && !products[1].in_stock);
+    }
+
+    #[test]
+    fn test_review_date_key() {
+        assert_eq!(review_date_key("March 3, 2024"), Some((2024, 3, 3)));
+        assert_eq!(review_date_key("3. März 2024"), Some((2024, 3, 3)));
+        assert_eq!(review_date_key("12 juillet 2023"), Some((2023, 7, 12)));
+        assert_eq!(review_date_key("June 3, 2024"), Some((2024, 6, 3)));
+        assert_eq!(review_date_key("July 1, 2023"), Some((2023, 7, 1)));
+        assert_eq!(review_date_key("1. Juli 2023"), Some((2023, 7, 1)));
+        assert_eq!(review_date_key("3 de junio de 2024"), Some((2024, 6, 3)));
+        assert_eq!(review_date_key("2024年3月3日"), None);
+    }
+
     #[test]
     fn test_parse_product_page_missing_title() {
         let parser = Parser::new(Region::Us);
@@ -664,6 +2796,23 @@ mod tests {
         assert!(!product.in_stock);
     }
 
+    #[test]
+    fn test_parse_related_asins_capped() {
+        let parser = Parser::new(Region::Us);
+        let mut html = String::from(
+            r#"<html><body><span id="productTitle">Test</span>
+               <div id="sims-simsContainer_feature_div">"#,
+        );
+        for i in 0..30 {
+            html.push_str(&format!(r#"<div data-asin="B{:09}"></div>"#, i));
+        }
+        html.push_str("</div></body></html>");
+
+        let product = parser.parse_product_page(&html, "B08N5WRWNW").unwrap();
+        assert_eq!(product.related_asins.len(), MAX_RELATED_ASINS);
+        assert_eq!(product.related_asins[0], "B000000000");
+    }
+
     #[test]
     fn test_parser_new() {
         let parser = Parser::new(Region::Uk);
@@ -676,7 +2825,9 @@ mod tests {
     #[test]
     fn test_parse_price_all_eu_regions() {
         // All EU regions use comma as decimal separator
-        for region in [Region::De, Region::Fr, Region::Es, Region::It, Region::Nl, Region::Pl] {
+        for region in
+            [Region::De, Region::Fr, Region::Es, Region::It, Region::Nl, Region::Pl, Region::Tr]
+        {
             let parser = Parser::new(region);
             assert_eq!(
                 parser.parse_price_value("29,99"),
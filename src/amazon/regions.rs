@@ -24,6 +24,7 @@ pub enum Region {
     Nl,
     Se,
     Pl,
+    Tr,
 }
 
 impl Region {
@@ -45,6 +46,7 @@ impl Region {
             Region::Nl => "amazon.nl",
             Region::Se => "amazon.se",
             Region::Pl => "amazon.pl",
+            Region::Tr => "amazon.com.tr",
         }
     }
 
@@ -67,9 +69,69 @@ impl Region {
             Region::Mx => "MXN",
             Region::Se => "SEK",
             Region::Pl => "PLN",
+            Region::Tr => "TRY",
         }
     }
 
+    /// Returns the currency symbol shown on listings for this region.
+    pub fn currency_symbol(&self) -> &'static str {
+        match self {
+            Region::Us | Region::Ca | Region::Au | Region::Mx => "$",
+            Region::Uk => "£",
+            Region::De | Region::Fr | Region::Es | Region::It | Region::Nl => "€",
+            Region::Jp => "¥",
+            Region::In => "₹",
+            Region::Br => "R$",
+            Region::Se => "kr",
+            Region::Pl => "zł",
+            Region::Tr => "₺",
+        }
+    }
+
+    /// Returns whether the currency symbol follows the amount (e.g. "29,99 €").
+    pub fn symbol_after_amount(&self) -> bool {
+        matches!(
+            self,
+            Region::De
+                | Region::Fr
+                | Region::Es
+                | Region::It
+                | Region::Nl
+                | Region::Se
+                | Region::Pl
+                | Region::Tr
+        )
+    }
+
+    /// Returns the currency code for a listing's currency symbol. "$" maps to
+    /// USD, although CAD, AUD and MXN prices use it too.
+    pub fn currency_for_symbol(symbol: &str) -> Option<&'static str> {
+        match symbol {
+            "$" => Some("USD"),
+            "€" => Some("EUR"),
+            "£" => Some("GBP"),
+            "¥" => Some("JPY"),
+            "₹" => Some("INR"),
+            "R$" => Some("BRL"),
+            "kr" => Some("SEK"),
+            "zł" => Some("PLN"),
+            "₺" => Some("TRY"),
+            _ => None,
+        }
+    }
+
+    /// Returns the first region that uses the given currency code.
+    pub fn from_currency(code: &str) -> Option<Region> {
+        Region::all().iter().copied().find(|r| r.currency().eq_ignore_ascii_case(code))
+    }
+
+    /// Returns the region for an Amazon domain, with or without the `www.` prefix.
+    pub fn from_domain(domain: &str) -> Option<Region> {
+        let domain = domain.trim().trim_end_matches('.').to_lowercase();
+        let domain = domain.strip_prefix("www.").unwrap_or(&domain);
+        Region::all().iter().copied().find(|r| r.domain() == domain)
+    }
+
     /// Returns the Accept-Language header value for this region.
     pub fn accept_language(&self) -> &'static str {
         match self {
@@ -85,6 +147,7 @@ impl Region {
             Region::Nl => "nl-NL,nl;q=0.9,en;q=0.8",
             Region::Se => "sv-SE,sv;q=0.9,en;q=0.8",
             Region::Pl => "pl-PL,pl;q=0.9,en;q=0.8",
+            Region::Tr => "tr-TR,tr;q=0.9,en;q=0.8",
         }
     }
 
@@ -100,6 +163,7 @@ impl Region {
                 | Region::Se
                 | Region::Pl
                 | Region::Br
+                | Region::Tr
         )
     }
 
@@ -121,8 +185,14 @@ impl Region {
             Region::Nl,
             Region::Se,
             Region::Pl,
+            Region::Tr,
         ]
     }
+
+    /// Returns every region code, one per line (for shell completions and scripts).
+    pub fn codes() -> String {
+        Self::all().iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+    }
 }
 
 impl fmt::Display for Region {
@@ -143,6 +213,7 @@ impl fmt::Display for Region {
             Region::Nl => "nl",
             Region::Se => "se",
             Region::Pl => "pl",
+            Region::Tr => "tr",
         };
         write!(f, "{}", code)
     }
@@ -168,6 +239,7 @@ impl FromStr for Region {
             "nl" | "netherlands" => Ok(Region::Nl),
             "se" | "sweden" => Ok(Region::Se),
             "pl" | "poland" => Ok(Region::Pl),
+            "tr" | "turkey" | "türkiye" => Ok(Region::Tr),
             _ => Err(RegionParseError(s.to_string())),
         }
     }
@@ -180,7 +252,7 @@ impl fmt::Display for RegionParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Unknown region '{}'. Valid regions: us, uk, de, fr, es, it, ca, au, jp, in, br, mx, nl, se, pl",
+            "Unknown region '{}'. Valid regions: us, uk, de, fr, es, it, ca, au, jp, in, br, mx, nl, se, pl, tr",
             self.0
         )
     }
@@ -227,6 +299,8 @@ mod tests {
         assert_eq!(Region::from_str("sweden").unwrap(), Region::Se);
         assert_eq!(Region::from_str("pl").unwrap(), Region::Pl);
         assert_eq!(Region::from_str("poland").unwrap(), Region::Pl);
+        assert_eq!(Region::from_str("tr").unwrap(), Region::Tr);
+        assert_eq!(Region::from_str("turkey").unwrap(), Region::Tr);
 
         // Case insensitive
         assert_eq!(Region::from_str("US").unwrap(), Region::Us);
@@ -237,6 +311,15 @@ mod tests {
         assert!(Region::from_str("").is_err());
     }
 
+    #[test]
+    fn test_region_codes() {
+        assert_eq!(
+            Region::codes(),
+            "us\nuk\nde\nfr\nes\nit\nca\nau\njp\nin\nbr\nmx\nnl\nse\npl\ntr"
+        );
+        assert_eq!(Region::codes().lines().count(), Region::all().len());
+    }
+
     #[test]
     fn test_region_domains_all() {
         assert_eq!(Region::Us.domain(), "amazon.com");
@@ -254,6 +337,7 @@ mod tests {
         assert_eq!(Region::Nl.domain(), "amazon.nl");
         assert_eq!(Region::Se.domain(), "amazon.se");
         assert_eq!(Region::Pl.domain(), "amazon.pl");
+        assert_eq!(Region::Tr.domain(), "amazon.com.tr");
     }
 
     #[test]
@@ -280,6 +364,65 @@ mod tests {
         assert_eq!(Region::Mx.currency(), "MXN");
         assert_eq!(Region::Se.currency(), "SEK");
         assert_eq!(Region::Pl.currency(), "PLN");
+        assert_eq!(Region::Tr.currency(), "TRY");
+    }
+
+    #[test]
+    fn test_currency_symbols() {
+        assert_eq!(Region::Us.currency_symbol(), "$");
+        assert_eq!(Region::Uk.currency_symbol(), "£");
+        assert_eq!(Region::De.currency_symbol(), "€");
+        assert_eq!(Region::Jp.currency_symbol(), "¥");
+        assert_eq!(Region::Br.currency_symbol(), "R$");
+        assert_eq!(Region::Pl.currency_symbol(), "zł");
+        assert_eq!(Region::Tr.currency_symbol(), "₺");
+
+        assert!(Region::De.symbol_after_amount());
+        assert!(Region::Se.symbol_after_amount());
+        assert!(Region::Tr.symbol_after_amount());
+        assert!(!Region::Us.symbol_after_amount());
+        assert!(!Region::Br.symbol_after_amount());
+    }
+
+    #[test]
+    fn test_currency_for_symbol() {
+        for region in Region::all() {
+            let currency = Region::currency_for_symbol(region.currency_symbol());
+            // "$" is shared by several currencies
+            if region.currency_symbol() != "$" {
+                assert_eq!(currency, Some(region.currency()), "{}", region);
+            }
+        }
+        assert_eq!(Region::currency_for_symbol("$"), Some("USD"));
+        assert_eq!(Region::currency_for_symbol("CHF"), None);
+    }
+
+    #[test]
+    fn test_region_from_domain() {
+        for region in Region::all() {
+            assert_eq!(Region::from_domain(region.domain()), Some(*region));
+            assert_eq!(Region::from_domain(&format!("www.{}", region.domain())), Some(*region));
+        }
+
+        assert_eq!(Region::from_domain("amazon.co.uk"), Some(Region::Uk));
+        assert_eq!(Region::from_domain("WWW.Amazon.Com.Au"), Some(Region::Au));
+        assert_eq!(Region::from_domain("www.amazon.de."), Some(Region::De));
+    }
+
+    #[test]
+    fn test_region_from_domain_unknown() {
+        assert_eq!(Region::from_domain("amazon.cn"), None);
+        assert_eq!(Region::from_domain("example.com"), None);
+        assert_eq!(Region::from_domain("smile.amazon.com"), None);
+        assert_eq!(Region::from_domain(""), None);
+    }
+
+    #[test]
+    fn test_region_from_currency() {
+        assert_eq!(Region::from_currency("USD"), Some(Region::Us));
+        assert_eq!(Region::from_currency("eur"), Some(Region::De));
+        assert_eq!(Region::from_currency("GBP"), Some(Region::Uk));
+        assert_eq!(Region::from_currency("XYZ"), None);
     }
 
     #[test]
@@ -299,6 +442,7 @@ mod tests {
         assert!(Region::Nl.accept_language().contains("nl-NL"));
         assert!(Region::Se.accept_language().contains("sv-SE"));
         assert!(Region::Pl.accept_language().contains("pl-PL"));
+        assert!(Region::Tr.accept_language().contains("tr-TR"));
     }
 
     #[test]
@@ -321,14 +465,16 @@ mod tests {
         assert!(Region::Se.uses_comma_decimal());
         assert!(Region::Pl.uses_comma_decimal());
         assert!(Region::Br.uses_comma_decimal());
+        assert!(Region::Tr.uses_comma_decimal());
     }
 
     #[test]
     fn test_region_all() {
         let all = Region::all();
-        assert_eq!(all.len(), 15);
+        assert_eq!(all.len(), 16);
         assert!(all.contains(&Region::Us));
         assert!(all.contains(&Region::Pl));
+        assert!(all.contains(&Region::Tr));
     }
 
     #[test]
@@ -348,6 +494,7 @@ mod tests {
         assert_eq!(Region::Nl.to_string(), "nl");
         assert_eq!(Region::Se.to_string(), "se");
         assert_eq!(Region::Pl.to_string(), "pl");
+        assert_eq!(Region::Tr.to_string(), "tr");
     }
 
     #[test]
@@ -86,6 +86,10 @@ pub mod search {
         .unwrap()
     });
 
+    /// Secondary price labels, including grocery unit prices ("(€2.50/100 g)").
+    pub static UNIT_PRICE: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("span.a-size-base.a-color-secondary").unwrap());
+
     /// Original price (strikethrough).
     pub static PRICE_ORIGINAL: LazyLock<Selector> = LazyLock::new(|| {
         Selector::parse(
@@ -96,6 +100,10 @@ pub mod search {
         .unwrap()
     });
 
+    /// Price values that aren't struck through (candidates for a range max).
+    pub static PRICE_UNSTRUCK: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse(".a-price:not([data-a-strike]) .a-offscreen").unwrap());
+
     /// Price range container.
     pub static PRICE_RANGE: LazyLock<Selector> =
         LazyLock::new(|| Selector::parse(".a-price-range, .a-price + .a-price").unwrap());
@@ -195,6 +203,36 @@ pub mod search {
         )
         .unwrap()
     });
+
+    /// Multi-buy / promotion message on a search card.
+    pub static PROMOTION: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "[data-cy='promotion-recipe'], \
+             .s-promotion-text, \
+             .a-section .s-coupon-highlight-color",
+        )
+        .unwrap()
+    });
+
+    /// EU energy efficiency class badge on a search card.
+    pub static ENERGY_CLASS: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            ".s-energy-efficiency-badge-standard, \
+             .s-energy-efficiency-badge, \
+             [data-csa-c-content-id*='energy-efficiency']",
+        )
+        .unwrap()
+    });
+
+    /// Availability text on a search card ("Currently unavailable.", "Out of stock").
+    pub static AVAILABILITY: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "[data-cy='availability-recipe'], \
+             span.a-color-price, \
+             span.a-color-secondary",
+        )
+        .unwrap()
+    });
 }
 
 /// Selectors for individual product pages (ASIN lookup).
@@ -224,6 +262,16 @@ pub mod product {
         .unwrap()
     });
 
+    /// Price per unit next to the buy box price ("(€25.00 / kg)").
+    pub static UNIT_PRICE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            ".pricePerUnit, \
+             #corePrice_feature_div .a-size-mini, \
+             #corePriceDisplay_desktop_feature_div .a-size-mini",
+        )
+        .unwrap()
+    });
+
     /// Original price (before discount).
     pub static PRICE_ORIGINAL: LazyLock<Selector> = LazyLock::new(|| {
         Selector::parse(
@@ -234,6 +282,116 @@ pub mod product {
         .unwrap()
     });
 
+    /// Price display containers, scanned for inline "List:"/"Was:" text.
+    pub static PRICE_BLOCK: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#corePriceDisplay_desktop_feature_div, \
+             #corePrice_desktop, \
+             #corePrice_feature_div, \
+             #price",
+        )
+        .unwrap()
+    });
+
+    /// Labeled reference price block ("List Price:", "Typical price:", ...).
+    pub static BASIS_PRICE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#corePriceDisplay_desktop_feature_div .basisPrice, \
+             #corePrice_desktop .basisPrice, \
+             .basisPrice",
+        )
+        .unwrap()
+    });
+
+    /// Price value inside a labeled price block.
+    pub static BASIS_PRICE_VALUE: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse(".a-offscreen").unwrap());
+
+    /// Promotion messages ("Buy 2, save 5%") near the buy box.
+    pub static PROMOTION: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#promoPriceBlockMessage_feature_div .promoPriceBlockMessage, \
+             #applicable_promotion_list_sec .a-list-item, \
+             .promotions-feature .a-text-bold",
+        )
+        .unwrap()
+    });
+
+    /// Coupon and promo-code messages ("Save 15% with code SPRING15").
+    pub static COUPON: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#promoPriceBlockMessage_feature_div, \
+             #applicable_promotion_list_sec .a-list-item, \
+             #couponBadgeRegularVpc, \
+             [id^='promoMessage']",
+        )
+        .unwrap()
+    });
+
+    /// Subscribe & Save price in the buy box accordion.
+    pub static SUBSCRIBE_PRICE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#sns-base-price, \
+             #subscriptionPrice .a-offscreen, \
+             #snsAccordionRowMiddle .a-price .a-offscreen",
+        )
+        .unwrap()
+    });
+
+    /// Rows of the product details / technical specification tables.
+    pub static DETAIL_ROWS: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#productDetails_techSpec_section_1 tr, \
+             #productDetails_techSpec_section_2 tr, \
+             #productDetails_detailBullets_sections1 tr",
+        )
+        .unwrap()
+    });
+
+    /// Label cell of a product details row.
+    pub static DETAIL_LABEL: LazyLock<Selector> = LazyLock::new(|| Selector::parse("th").unwrap());
+
+    /// Value cell of a product details row.
+    pub static DETAIL_VALUE: LazyLock<Selector> = LazyLock::new(|| Selector::parse("td").unwrap());
+
+    /// "Label : value" items of the product details bullet list.
+    pub static DETAIL_BULLETS: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse("#detailBullets_feature_div li, #detailBulletsWrapper_feature_div li")
+            .unwrap()
+    });
+
+    /// EU energy efficiency class label.
+    pub static ENERGY_CLASS: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#energyEfficiency_feature_div, \
+             #energyEfficiencyLabel_feature_div, \
+             .energy-efficiency-badge",
+        )
+        .unwrap()
+    });
+
+    /// "Frequently returned item" warning block.
+    pub static FREQUENTLY_RETURNED: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#frequentlyReturnedBadge_feature_div, \
+             #frequently-returned-badge_feature_div, \
+             .frequently-returned-badge",
+        )
+        .unwrap()
+    });
+
+    /// Delivery and export messages ("This item does not deliver to your
+    /// location", AmazonGlobal "Ships to ..." notes).
+    pub static DELIVERY_MESSAGE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#mir-layout-DELIVERY_BLOCK, \
+             #exports_desktop_undeliverable_buybox_feature_div, \
+             #amazonGlobal_feature_div, \
+             #availability",
+        )
+        .unwrap()
+    });
+
     /// Main product image.
     pub static IMAGE: LazyLock<Selector> = LazyLock::new(|| {
         Selector::parse(
@@ -262,6 +420,22 @@ pub mod product {
         .unwrap()
     });
 
+    /// Prices in the "New & Used from" / "Other Sellers on Amazon" blocks.
+    pub static OTHER_SELLERS_PRICE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#olp_feature_div .a-color-price, \
+             #olp_feature_div .a-price .a-offscreen, \
+             #olpLinkWidget_feature_div .a-color-price, \
+             #mbc .a-color-price, \
+             #mbc .a-price .a-offscreen",
+        )
+        .unwrap()
+    });
+
+    /// Dates of the reviews shown in the reviews section.
+    pub static REVIEW_DATE: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("[data-hook='review-date']").unwrap());
+
     /// Brand/manufacturer.
     pub static BRAND: LazyLock<Selector> = LazyLock::new(|| {
         Selector::parse(
@@ -282,6 +456,20 @@ pub mod product {
         .unwrap()
     });
 
+    /// Prime badge within the buy box and price/delivery area, ignoring logos
+    /// in carousels elsewhere on the page.
+    pub static PRIME_BUY_BOX: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#prime-badge, \
+             #buybox .a-icon-prime, \
+             #desktop_buybox .a-icon-prime, \
+             #apex_desktop .a-icon-prime, \
+             #corePriceDisplay_desktop_feature_div .a-icon-prime, \
+             #deliveryBlockMessage .a-icon-prime",
+        )
+        .unwrap()
+    });
+
     /// Prime badge on detail page.
     pub static PRIME: LazyLock<Selector> = LazyLock::new(|| {
         Selector::parse(
@@ -301,6 +489,20 @@ pub mod product {
         .unwrap()
     });
 
+    /// Items in the "Customers who viewed this also viewed" carousel.
+    pub static RELATED_ITEMS: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#sims-simsContainer_feature_div [data-asin], \
+             #sims-consolidated-1_feature_div [data-asin], \
+             #similarities_feature_div [data-asin]",
+        )
+        .unwrap()
+    });
+
+    /// Canonical link, whose href carries the page's `/dp/<asin>` URL.
+    pub static CANONICAL_LINK: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("link[rel='canonical']").unwrap());
+
     /// ASIN from page (backup extraction).
     pub static ASIN: LazyLock<Selector> = LazyLock::new(|| {
         Selector::parse(
@@ -311,6 +513,65 @@ pub mod product {
     });
 }
 
+/// Selectors for best seller ranking pages (`/gp/bestsellers/<category>`).
+pub mod bestsellers {
+    use super::*;
+
+    /// Ranked item container.
+    pub static ITEM: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse("#gridItemRoot, .zg-grid-general-faceout, li.zg-item-immersion").unwrap()
+    });
+
+    /// Element carrying the item's ASIN in `data-asin` (or `id` on older layouts).
+    pub static ASIN_HOLDER: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("[data-asin], .p13n-sc-uncoverable-faceout").unwrap());
+
+    /// Rank badge, e.g. "#1".
+    pub static RANK: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse(".zg-bdg-text, .zg-badge-text").unwrap());
+
+    /// Product link (`/<slug>/dp/<asin>/...`).
+    pub static LINK: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("a.a-link-normal[href*='/dp/']").unwrap());
+
+    /// Product title.
+    pub static TITLE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "[class*='p13n-sc-css-line-clamp'], \
+             .p13n-sc-truncate, \
+             .p13n-sc-truncated",
+        )
+        .unwrap()
+    });
+
+    /// Product image (its alt text doubles as a title fallback).
+    pub static IMAGE: LazyLock<Selector> = LazyLock::new(|| Selector::parse("img").unwrap());
+
+    /// Price text.
+    pub static PRICE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            ".p13n-sc-price, \
+             [class*='p13n-sc-price'], \
+             .a-price .a-offscreen",
+        )
+        .unwrap()
+    });
+
+    /// Star rating text, e.g. "4.6 out of 5 stars".
+    pub static RATING_STARS: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse(".a-icon-star-small .a-icon-alt, .a-icon-alt").unwrap());
+
+    /// Review count next to the stars.
+    pub static RATING_COUNT: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(".a-icon-row .a-size-small, a[href*='product-reviews'] .a-size-small")
+            .unwrap()
+    });
+
+    /// Prime badge.
+    pub static PRIME_BADGE: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse(".a-icon-prime").unwrap());
+}
+
 /// Selectors for detecting error/captcha pages.
 pub mod errors {
     use super::*;
@@ -334,6 +595,18 @@ pub mod errors {
         .unwrap_or_else(|_| Selector::parse(".s-no-search-results").unwrap())
     });
 
+    /// Age-verification interstitial ("black curtain") shown instead of
+    /// adult or age-restricted products.
+    pub static AGE_GATE: LazyLock<Selector> = LazyLock::new(|| {
+        Selector::parse(
+            "#black-curtain-warning, \
+             #black-curtain-yes-button, \
+             a[href*='black-curtain'], \
+             form[action*='black-curtain']",
+        )
+        .unwrap()
+    });
+
     /// Dog page (Amazon's error page).
     pub static DOG_PAGE: LazyLock<Selector> = LazyLock::new(|| {
         Selector::parse(
@@ -359,6 +632,10 @@ mod tests {
         let _ = &*search::RATING_STARS;
         let _ = &*product::TITLE;
         let _ = &*product::PRICE;
+        let _ = &*product::CANONICAL_LINK;
+        let _ = &*bestsellers::ITEM;
+        let _ = &*bestsellers::TITLE;
+        let _ = &*bestsellers::PRICE;
         let _ = &*errors::CAPTCHA;
     }
 
@@ -0,0 +1,175 @@
+//! Best sellers command implementation.
+
+use crate::amazon::{AmazonClient, AmazonSearch, Parser, Product};
+use crate::config::Config;
+use crate::filters::FilterChainBuilder;
+use crate::format::Formatter;
+use anyhow::{Context, Result};
+use tracing::{debug, info};
+
+/// Lists Amazon's best sellers for a category.
+pub struct BestsellersCommand {
+    config: Config,
+}
+
+impl BestsellersCommand {
+    /// Creates a new best sellers command.
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Fetches the best seller list for `category` and returns formatted output.
+    pub async fn execute(&self, category: &str) -> Result<String> {
+        let client =
+            AmazonClient::new(&self.config).await.context("Failed to create HTTP client")?;
+
+        self.execute_with_client(&client, category).await
+    }
+
+    /// Fetches the best seller list with a provided client (for testing).
+    pub async fn execute_with_client(
+        &self,
+        client: &impl AmazonSearch,
+        category: &str,
+    ) -> Result<String> {
+        let products = self.bestsellers(client, category).await?;
+
+        let formatter = Formatter::from_config(&self.config);
+        Ok(formatter.format_products(&products))
+    }
+
+    /// Fetches and parses the list, then applies the configured filters and
+    /// `max_results`. Products keep their list rank after filtering.
+    pub async fn bestsellers(
+        &self,
+        client: &impl AmazonSearch,
+        category: &str,
+    ) -> Result<Vec<Product>> {
+        let category = category.trim().trim_matches('/');
+        if category.is_empty() {
+            anyhow::bail!("Category cannot be empty (e.g. \"electronics\")");
+        }
+
+        info!("Fetching best sellers: {}", category);
+        let html = client.bestsellers(category).await?;
+
+        let parser = Parser::new(client.region()).with_config(&self.config);
+        let products = parser.parse_bestsellers(&html)?;
+
+        let filters = FilterChainBuilder::from_config(&self.config).build();
+        if !filters.is_empty() {
+            debug!("Active filters: {}", filters.descriptions().join(", "));
+        }
+
+        let mut products = filters.apply(products);
+        products.truncate(self.config.max_results);
+
+        info!("Found {} best sellers matching criteria", products.len());
+        Ok(products)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amazon::Region;
+    use crate::config::OutputFormat;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Mock client serving one best seller page.
+    struct MockAmazonClient {
+        html: String,
+        categories: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl AmazonSearch for MockAmazonClient {
+        async fn search(&self, _query: &str, _page: u32) -> Result<String> {
+            Ok("<html></html>".to_string())
+        }
+
+        async fn search_url(&self, _url: &str) -> Result<String> {
+            Ok("<html></html>".to_string())
+        }
+
+        async fn product(&self, _asin: &str) -> Result<String> {
+            Ok("<html></html>".to_string())
+        }
+
+        async fn bestsellers(&self, category: &str) -> Result<String> {
+            self.categories.lock().unwrap().push(category.to_string());
+            Ok(self.html.clone())
+        }
+
+        fn region(&self) -> Region {
+            Region::Us
+        }
+    }
+
+    fn make_bestsellers_html(items: &[(&str, &str, f64)]) -> String {
+        let mut html = String::from("<html><body>");
+        for (i, (asin, title, price)) in items.iter().enumerate() {
+            html.push_str(&format!(
+                r#"<div id="gridItemRoot">
+                    <span class="zg-bdg-text">#{}</span>
+                    <div id="{}" class="p13n-sc-uncoverable-faceout">
+                        <a class="a-link-normal" href="/x/dp/{}/"><div class="p13n-sc-truncate">{}</div></a>
+                        <span class="p13n-sc-price">${:.2}</span>
+                    </div>
+                </div>"#,
+                i + 1,
+                asin,
+                asin,
+                title,
+                price
+            ));
+        }
+        html.push_str("</body></html>");
+        html
+    }
+
+    #[tokio::test]
+    async fn test_bestsellers_filters_and_keeps_rank() {
+        let client = MockAmazonClient {
+            html: make_bestsellers_html(&[
+                ("B0AAAAAAA1", "Echo Dot", 49.99),
+                ("B0AAAAAAA2", "Fire TV Stick", 19.99),
+                ("B0AAAAAAA3", "Kindle", 99.99),
+            ]),
+            categories: Mutex::new(Vec::new()),
+        };
+        let config = Config {
+            max_price: Some(60.0),
+            max_results: 1,
+            format: OutputFormat::Json,
+            ..Config::default()
+        };
+
+        let cmd = BestsellersCommand::new(config);
+        let output = cmd.execute_with_client(&client, "/electronics/").await.unwrap();
+        let products: Vec<Product> = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(products.len(), 1);
+        assert_eq!(products[0].asin, "B0AAAAAAA1");
+        assert_eq!(products[0].rank, Some(1));
+        assert_eq!(*client.categories.lock().unwrap(), vec!["electronics"]);
+
+        let products =
+            BestsellersCommand::new(Config { min_price: Some(20.0), ..Config::default() })
+                .bestsellers(&client, "electronics")
+                .await
+                .unwrap();
+        let ranks: Vec<_> = products.iter().map(|p| p.rank).collect();
+        assert_eq!(ranks, vec![Some(1), Some(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_bestsellers_empty_category() {
+        let client = MockAmazonClient { html: String::new(), categories: Mutex::new(Vec::new()) };
+        let cmd = BestsellersCommand::new(Config::default());
+
+        assert!(cmd.execute_with_client(&client, " / ").await.is_err());
+        assert!(client.categories.lock().unwrap().is_empty());
+    }
+}
@@ -1,9 +1,78 @@
 //! TropicalPrice comparison command implementation.
 
-use crate::config::OutputFormat;
-use crate::tropical::{PriceComparison, TropicalClient, TropicalProduct, TropicalSearch};
+use crate::amazon::Region;
+use crate::config::{Config, OutputFormat};
+use crate::tropical::{
+    Conversion, HttpRateProvider, PriceComparison, PriceMatrix, RateCache, TropicalClient,
+    TropicalProduct, TropicalSearch, DEFAULT_RATES_URL,
+};
 use anyhow::Result;
-use tracing::info;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tracing::{info, warn};
+
+/// Maximum number of TropicalPrice lookups in flight during a batch compare.
+const BATCH_CONCURRENCY: usize = 4;
+
+/// Minimum cross-border savings a comparison must offer to be kept in a batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SavingsThreshold {
+    /// Minimum `max_savings` in EUR
+    pub min_savings: Option<f64>,
+    /// Minimum `max_savings_percent`
+    pub min_savings_percent: Option<f64>,
+}
+
+impl SavingsThreshold {
+    /// Returns true if no threshold is set.
+    pub fn is_empty(&self) -> bool {
+        self.min_savings.is_none() && self.min_savings_percent.is_none()
+    }
+
+    /// Returns true if the comparison meets every threshold that is set.
+    pub fn matches(&self, comparison: &PriceComparison) -> bool {
+        let savings = comparison.max_savings().unwrap_or(0.0);
+        let percent = comparison.max_savings_percent().unwrap_or(0.0);
+
+        self.min_savings.is_none_or(|min| savings >= min)
+            && self.min_savings_percent.is_none_or(|min| percent >= min)
+    }
+}
+
+/// Which parts of a single-ASIN comparison to print.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LinksMode {
+    /// Prices followed by the store links block (default)
+    #[default]
+    Show,
+    /// Prices only, without the links block
+    Hide,
+    /// Only the store links block
+    Only,
+}
+
+/// Loads exchange rates (from the cache when fresh) for converting compare
+/// prices into `currency`.
+///
+/// Rates come from `rates_url` (ECB daily rates by default) and are cached as
+/// `rates.json` in `cache_dir`, or the user cache directory.
+pub async fn load_conversion(config: &Config, currency: &str) -> Result<Conversion> {
+    let url = config.rates_url.as_deref().unwrap_or(DEFAULT_RATES_URL);
+    let dir = config
+        .cache_dir
+        .clone()
+        .or_else(|| dirs::cache_dir().map(|dir| dir.join("amz-crawler")))
+        .unwrap_or_else(std::env::temp_dir);
+    let cache = RateCache::new(
+        dir.join("rates.json"),
+        Duration::from_secs(config.rates_ttl_hours.saturating_mul(3600)),
+    );
+
+    let rates = cache.rates(&HttpRateProvider::new(url)?).await?;
+    Conversion::new(&rates, currency)
+}
 
 /// Executes a TropicalPrice search.
 pub async fn search_tropical(
@@ -33,9 +102,29 @@ pub async fn search_tropical_with_client(
 }
 
 /// Executes a price comparison for an ASIN.
-pub async fn compare_prices(asin: &str, format: OutputFormat) -> Result<String> {
+///
+/// `locale_currency` maps country codes to the currency label shown for
+/// that store (e.g. "UK" to "GBP"); unmapped stores keep "EUR". A
+/// `conversion` converts every price instead and takes precedence.
+pub async fn compare_prices(
+    asin: &str,
+    format: OutputFormat,
+    price_decimals: usize,
+    links: LinksMode,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<String> {
     let client = TropicalClient::new()?;
-    compare_prices_with_client(&client, asin, format).await
+    compare_prices_with_client(
+        &client,
+        asin,
+        format,
+        price_decimals,
+        links,
+        locale_currency,
+        conversion,
+    )
+    .await
 }
 
 /// Executes a price comparison with a provided client (for testing).
@@ -43,15 +132,223 @@ pub async fn compare_prices_with_client(
     client: &impl TropicalSearch,
     asin: &str,
     format: OutputFormat,
+    price_decimals: usize,
+    links: LinksMode,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<String> {
+    let mut comparison = fetch_comparison(client, asin).await?;
+    match conversion {
+        Some(conversion) => conversion.apply(&mut comparison),
+        None => apply_locale_currency(&mut comparison, locale_currency),
+    }
+
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&comparison)?,
+        _ => format_comparison(&comparison, price_decimals, links),
+    })
+}
+
+/// Executes price comparisons for several ASINs concurrently.
+///
+/// Store currencies are labeled as in [`compare_prices`].
+pub async fn compare_batch(
+    asins: &[String],
+    threshold: SavingsThreshold,
+    format: OutputFormat,
+    price_decimals: usize,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<String> {
+    let client = Arc::new(TropicalClient::new()?);
+    compare_batch_with_client(
+        client,
+        asins,
+        threshold,
+        format,
+        price_decimals,
+        locale_currency,
+        conversion,
+    )
+    .await
+}
+
+/// Executes batch price comparisons with a provided client (for testing).
+///
+/// ASINs without price data are skipped and listed after the table;
+/// comparisons below `threshold` are dropped.
+pub async fn compare_batch_with_client<C: TropicalSearch + 'static>(
+    client: Arc<C>,
+    asins: &[String],
+    threshold: SavingsThreshold,
+    format: OutputFormat,
+    price_decimals: usize,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
 ) -> Result<String> {
+    let (comparisons, skipped, below_threshold) =
+        collect_comparisons(client, asins, threshold, locale_currency, conversion).await?;
+
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&comparisons)?,
+        _ => format_batch(&comparisons, &skipped, below_threshold, price_decimals),
+    })
+}
+
+/// Compares several ASINs and renders a price matrix: rows are ASINs,
+/// columns are countries. Store currencies are labeled as in [`compare_prices`].
+pub async fn compare_matrix(
+    asins: &[String],
+    threshold: SavingsThreshold,
+    format: OutputFormat,
+    price_decimals: usize,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<String> {
+    let client = Arc::new(TropicalClient::new()?);
+    compare_matrix_with_client(
+        client,
+        asins,
+        threshold,
+        format,
+        price_decimals,
+        locale_currency,
+        conversion,
+    )
+    .await
+}
+
+/// Renders a price matrix with a provided client (for testing).
+pub async fn compare_matrix_with_client<C: TropicalSearch + 'static>(
+    client: Arc<C>,
+    asins: &[String],
+    threshold: SavingsThreshold,
+    format: OutputFormat,
+    price_decimals: usize,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<String> {
+    let (comparisons, skipped, _) =
+        collect_comparisons(client, asins, threshold, locale_currency, conversion).await?;
+    let matrix = PriceMatrix::from_comparisons(&comparisons);
+
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&matrix)?,
+        _ => format_matrix(&matrix, &skipped, format, price_decimals),
+    })
+}
+
+/// Compares several ASINs and renders one shareable summary line per ASIN,
+/// e.g. for pasting into chat. Store currencies are labeled as in
+/// [`compare_prices`].
+pub async fn compare_summary(
+    asins: &[String],
+    threshold: SavingsThreshold,
+    price_decimals: usize,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<String> {
+    let client = Arc::new(TropicalClient::new()?);
+    compare_summary_with_client(
+        client,
+        asins,
+        threshold,
+        price_decimals,
+        locale_currency,
+        conversion,
+    )
+    .await
+}
+
+/// Renders summary lines with a provided client (for testing).
+pub async fn compare_summary_with_client<C: TropicalSearch + 'static>(
+    client: Arc<C>,
+    asins: &[String],
+    threshold: SavingsThreshold,
+    price_decimals: usize,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<String> {
+    let (comparisons, _, _) =
+        collect_comparisons(client, asins, threshold, locale_currency, conversion).await?;
+    Ok(comparisons.iter().map(|c| format_summary(c, price_decimals)).collect::<Vec<_>>().join("\n"))
+}
+
+/// Looks up every ASIN (at most `BATCH_CONCURRENCY` at once) and returns the
+/// comparisons meeting `threshold` (checked in EUR, before any `conversion`)
+/// in input order, the ASINs without price data, and how many comparisons
+/// fell below the threshold.
+///
+/// Prices are converted with `conversion`, or else labeled with
+/// `locale_currency`.
+async fn collect_comparisons<C: TropicalSearch + 'static>(
+    client: Arc<C>,
+    asins: &[String],
+    threshold: SavingsThreshold,
+    locale_currency: &HashMap<String, String>,
+    conversion: Option<&Conversion>,
+) -> Result<(Vec<PriceComparison>, Vec<String>, usize)> {
+    let mut tasks = JoinSet::new();
+    let mut results: Vec<(usize, Result<PriceComparison>)> = Vec::new();
+
+    for (i, asin) in asins.iter().enumerate() {
+        // Keep at most BATCH_CONCURRENCY lookups running
+        if tasks.len() >= BATCH_CONCURRENCY {
+            if let Some(done) = tasks.join_next().await {
+                results.push(done?);
+            }
+        }
+
+        let client = Arc::clone(&client);
+        let asin = asin.clone();
+        tasks.spawn(async move { (i, fetch_comparison(client.as_ref(), &asin).await) });
+    }
+    while let Some(done) = tasks.join_next().await {
+        results.push(done?);
+    }
+
+    // Restore input order
+    results.sort_by_key(|(i, _)| *i);
+
+    let mut comparisons = Vec::new();
+    let mut skipped = Vec::new();
+    for (i, result) in results {
+        match result {
+            Ok(comparison) => comparisons.push(comparison),
+            Err(e) => {
+                warn!("Skipping {}: {}", asins[i], e);
+                skipped.push(asins[i].clone());
+            }
+        }
+    }
+
+    if comparisons.is_empty() {
+        anyhow::bail!("No price data found on TropicalPrice for any of: {}", skipped.join(", "));
+    }
+
+    let found = comparisons.len();
+    comparisons.retain(|c| threshold.matches(c));
+    let below_threshold = found - comparisons.len();
+    if below_threshold > 0 {
+        info!("{} comparison(s) below the savings threshold", below_threshold);
+    }
+
+    for comparison in &mut comparisons {
+        match conversion {
+            Some(conversion) => conversion.apply(comparison),
+            None => apply_locale_currency(comparison, locale_currency),
+        }
+    }
+
+    Ok((comparisons, skipped, below_threshold))
+}
+
+/// Fetches the comparison for an ASIN, treating missing data as an error.
+async fn fetch_comparison(client: &impl TropicalSearch, asin: &str) -> Result<PriceComparison> {
     match client.compare(asin).await? {
         Some(comparison) => {
             info!("Found prices from {} stores for {}", comparison.total_stores, asin);
-
-            Ok(match format {
-                OutputFormat::Json => serde_json::to_string_pretty(&comparison)?,
-                _ => format_comparison(&comparison),
-            })
+            Ok(comparison)
         }
         None => {
             anyhow::bail!("No price data found for ASIN {} on TropicalPrice", asin);
@@ -89,8 +386,159 @@ fn format_search_results(products: &[TropicalProduct]) -> String {
     lines.join("\n")
 }
 
+/// Formats batch comparisons as one row per ASIN with its best price and max savings.
+fn format_batch(
+    comparisons: &[PriceComparison],
+    skipped: &[String],
+    below_threshold: usize,
+    decimals: usize,
+) -> String {
+    let mut lines = Vec::new();
+
+    lines.push(format!(
+        "{:<12} {:<12} {:<6} {:<18} {}",
+        "ASIN", "Best", "Store", "Max savings", "Title"
+    ));
+    lines.push("-".repeat(90));
+
+    for data in comparisons {
+        let (best, store) = match data.cheapest() {
+            Some(c) => (format_money(c.price, &c.currency, decimals), c.country.clone()),
+            None => ("N/A".to_string(), "-".to_string()),
+        };
+        let savings = match (data.max_savings(), data.max_savings_percent()) {
+            (Some(savings), Some(pct)) => {
+                format!("{} ({:.0}%)", format_money(savings, savings_currency(data), decimals), pct)
+            }
+            _ => "-".to_string(),
+        };
+        let title = if data.title.chars().count() > 40 {
+            format!("{}...", data.title.chars().take(37).collect::<String>())
+        } else {
+            data.title.clone()
+        };
+
+        lines
+            .push(format!("{:<12} {:<12} {:<6} {:<18} {}", data.asin, best, store, savings, title));
+    }
+
+    if below_threshold > 0 || !skipped.is_empty() {
+        lines.push(String::new());
+    }
+    if below_threshold > 0 {
+        lines.push(format!("{} below the savings threshold", below_threshold));
+    }
+    if !skipped.is_empty() {
+        lines.push(format!("No price data for: {}", skipped.join(", ")));
+    }
+
+    lines.join("\n")
+}
+
+/// Formats a price matrix as a table, markdown table or CSV, leaving cells
+/// blank where a store has no price.
+fn format_matrix(
+    matrix: &PriceMatrix,
+    skipped: &[String],
+    format: OutputFormat,
+    decimals: usize,
+) -> String {
+    let cell = |price: &Option<f64>| price.map(|p| format!("{:.*}", decimals, p));
+    let mut lines = Vec::new();
+
+    match format {
+        OutputFormat::Csv => {
+            lines.push(format!("asin,{}", matrix.countries.join(",")));
+            for row in &matrix.rows {
+                let cells: Vec<String> =
+                    row.prices.iter().map(|p| cell(p).unwrap_or_default()).collect();
+                lines.push(format!("{},{}", row.asin, cells.join(",")));
+            }
+        }
+        OutputFormat::Markdown => {
+            lines.push(format!("| ASIN | {} |", matrix.countries.join(" | ")));
+            lines.push(format!("|------|{}", "---:|".repeat(matrix.countries.len())));
+            for row in &matrix.rows {
+                let cells: Vec<String> = row
+                    .prices
+                    .iter()
+                    .zip(&matrix.currencies)
+                    .map(|(p, currency)| {
+                        p.map(|p| format_money(p, currency, decimals)).unwrap_or_default()
+                    })
+                    .collect();
+                lines.push(format!("| {} | {} |", row.asin, cells.join(" | ")));
+            }
+        }
+        _ => {
+            let header: String =
+                matrix.countries.iter().map(|country| format!(" {:>10}", country)).collect();
+            lines.push(format!("{:<12}{}", "ASIN", header));
+            lines.push("-".repeat(12 + 11 * matrix.countries.len()));
+            for row in &matrix.rows {
+                let cells: String = row
+                    .prices
+                    .iter()
+                    .map(|p| format!(" {:>10}", cell(p).unwrap_or_default()))
+                    .collect();
+                lines.push(format!("{:<12}{}", row.asin, cells));
+            }
+        }
+    }
+
+    // Keep CSV parseable; the table formats note what's missing
+    if !skipped.is_empty() && format != OutputFormat::Csv {
+        lines.push(String::new());
+        lines.push(format!("No price data for: {}", skipped.join(", ")));
+    }
+
+    lines.join("\n")
+}
+
+/// Relabels each store's currency using the country-to-currency mapping.
+///
+/// Only the label changes; TropicalPrice amounts are not converted.
+fn apply_locale_currency(comparison: &mut PriceComparison, mapping: &HashMap<String, String>) {
+    for price in &mut comparison.prices {
+        let currency = mapping
+            .iter()
+            .find(|(country, _)| country.eq_ignore_ascii_case(&price.country))
+            .map(|(_, currency)| currency.to_ascii_uppercase());
+        if let Some(currency) = currency {
+            price.currency = currency;
+        }
+    }
+}
+
+/// Currency that price differences are in: the stores' common currency,
+/// or EUR (TropicalPrice's own) when labels differ.
+fn savings_currency(data: &PriceComparison) -> &str {
+    match data.prices.first() {
+        Some(first) if data.prices.iter().all(|p| p.currency == first.currency) => &first.currency,
+        _ => "EUR",
+    }
+}
+
+/// Formats an amount with its currency symbol, e.g. "€49.99", "£49.99" or "549.00 kr".
+fn format_money(amount: f64, currency: &str, decimals: usize) -> String {
+    if currency.eq_ignore_ascii_case("EUR") {
+        return format!("€{:.*}", decimals, amount);
+    }
+    match Region::from_currency(currency) {
+        Some(region) if region.symbol_after_amount() => {
+            format!("{:.*} {}", decimals, amount, region.currency_symbol())
+        }
+        Some(region) => format!("{}{:.*}", region.currency_symbol(), decimals, amount),
+        None => format!("{:.*} {}", decimals, amount, currency),
+    }
+}
+
 /// Formats price comparison as a readable output.
-fn format_comparison(data: &PriceComparison) -> String {
+fn format_comparison(data: &PriceComparison, decimals: usize, links: LinksMode) -> String {
+    if links == LinksMode::Only {
+        return format_links(data);
+    }
+
     let mut lines = Vec::new();
 
     // Product title
@@ -101,10 +549,10 @@ fn format_comparison(data: &PriceComparison) -> String {
     if let Some(cheapest) = data.cheapest() {
         let marketplace = if cheapest.is_marketplace { " ⚠️" } else { "" };
         lines.push(format!(
-            "Best at {} {}: €{:.2}{}",
+            "Best at {} {}: {}{}",
             cheapest.flag(),
             cheapest.country,
-            cheapest.price,
+            format_money(cheapest.price, &cheapest.currency, decimals),
             marketplace
         ));
         lines.push(format!("🛒 {}", cheapest.amazon_url));
@@ -115,33 +563,33 @@ fn format_comparison(data: &PriceComparison) -> String {
     let cheapest_price = data.cheapest().map(|c| c.price).unwrap_or(0.0);
 
     for p in &data.prices {
-        let savings_eur = p.price - cheapest_price;
+        let savings = p.price - cheapest_price;
         let savings_pct = if cheapest_price > 0.0 {
             (p.price - cheapest_price) / cheapest_price * 100.0
         } else {
             0.0
         };
 
-        let marker = if savings_eur == 0.0 { "🏆" } else { "  " };
+        let marker = if savings == 0.0 { "🏆" } else { "  " };
         let marketplace = if p.is_marketplace { " ⚠️" } else { "" };
 
-        if savings_eur == 0.0 {
+        if savings == 0.0 {
             lines.push(format!(
-                "{}{} {}: €{:.2}{}",
+                "{}{} {}: {}{}",
                 marker,
                 p.flag(),
                 p.country,
-                p.price,
+                format_money(p.price, &p.currency, decimals),
                 marketplace
             ));
         } else {
             lines.push(format!(
-                "{}{} {}: €{:.2} (+€{:.0}, +{:.0}%){}",
+                "{}{} {}: {} (+{}, +{:.0}%){}",
                 marker,
                 p.flag(),
                 p.country,
-                p.price,
-                savings_eur,
+                format_money(p.price, &p.currency, decimals),
+                format_money(savings, savings_currency(data), 0),
                 savings_pct,
                 marketplace
             ));
@@ -152,13 +600,61 @@ fn format_comparison(data: &PriceComparison) -> String {
     if let (Some(savings), Some(pct)) = (data.max_savings(), data.max_savings_percent()) {
         if savings > 0.0 {
             lines.push(String::new());
-            lines.push(format!("💰 Max savings: €{:.2} ({:.0}%)", savings, pct));
+            lines.push(format!(
+                "💰 Max savings: {} ({:.0}%)",
+                format_money(savings, savings_currency(data), decimals),
+                pct
+            ));
         }
     }
 
     // All store links at the end
-    lines.push(String::new());
-    lines.push("🔗 Links:".to_string());
+    if links == LinksMode::Show {
+        lines.push(String::new());
+        lines.push(format_links(data));
+    }
+
+    lines.join("\n")
+}
+
+/// Formats a comparison as one line: "B08N5WRWNW cheapest in 🇩🇪 DE at
+/// €49.99, save up to €10 (17%) vs 🇫🇷 FR".
+fn format_summary(data: &PriceComparison, decimals: usize) -> String {
+    let Some(cheapest) = data.cheapest() else {
+        return format!("{} has no store prices", data.asin);
+    };
+    let price = format_money(cheapest.price, &cheapest.currency, decimals);
+
+    if data.prices.len() == 1 {
+        return format!(
+            "{} only listed in {} {} at {}",
+            data.asin,
+            cheapest.flag(),
+            cheapest.country,
+            price
+        );
+    }
+
+    let mut line =
+        format!("{} cheapest in {} {} at {}", data.asin, cheapest.flag(), cheapest.country, price);
+    match (data.max_savings(), data.max_savings_percent(), data.most_expensive()) {
+        (Some(savings), Some(pct), Some(expensive)) if savings > 0.0 => {
+            line.push_str(&format!(
+                ", save up to {} ({:.0}%) vs {} {}",
+                format_money(savings, savings_currency(data), 0),
+                pct,
+                expensive.flag(),
+                expensive.country
+            ));
+        }
+        _ => line.push_str(&format!(", same price in all {} stores", data.prices.len())),
+    }
+    line
+}
+
+/// Formats the "🔗 Links" block listing every store URL.
+fn format_links(data: &PriceComparison) -> String {
+    let mut lines = vec!["🔗 Links:".to_string()];
     for p in &data.prices {
         lines.push(format!("   {} {}: {}", p.flag(), p.country, p.amazon_url));
     }
@@ -176,28 +672,45 @@ mod tests {
     struct MockTropicalClient {
         search_results: Vec<TropicalProduct>,
         comparison_result: Option<PriceComparison>,
+        /// Per-ASIN comparisons, checked before `comparison_result`
+        comparisons: Vec<PriceComparison>,
         should_fail: bool,
     }
 
     impl MockTropicalClient {
         fn with_search_results(products: Vec<TropicalProduct>) -> Self {
-            Self { search_results: products, comparison_result: None, should_fail: false }
+            Self {
+                search_results: products,
+                comparison_result: None,
+                comparisons: Vec::new(),
+                should_fail: false,
+            }
         }
 
         fn with_comparison(comparison: PriceComparison) -> Self {
             Self {
                 search_results: Vec::new(),
                 comparison_result: Some(comparison),
+                comparisons: Vec::new(),
+                should_fail: false,
+            }
+        }
+
+        fn with_comparisons(comparisons: Vec<PriceComparison>) -> Self {
+            Self {
+                search_results: Vec::new(),
+                comparison_result: None,
+                comparisons,
                 should_fail: false,
             }
         }
 
         fn empty() -> Self {
-            Self { search_results: Vec::new(), comparison_result: None, should_fail: false }
+            Self::with_comparisons(Vec::new())
         }
 
         fn failing() -> Self {
-            Self { search_results: Vec::new(), comparison_result: None, should_fail: true }
+            Self { should_fail: true, ..Self::empty() }
         }
     }
 
@@ -211,9 +724,11 @@ mod tests {
             }
         }
 
-        async fn compare(&self, _asin: &str) -> Result<Option<PriceComparison>> {
+        async fn compare(&self, asin: &str) -> Result<Option<PriceComparison>> {
             if self.should_fail {
                 anyhow::bail!("Simulated network error")
+            } else if let Some(comparison) = self.comparisons.iter().find(|c| c.asin == asin) {
+                Ok(Some(comparison.clone()))
             } else {
                 Ok(self.comparison_result.clone())
             }
@@ -336,7 +851,16 @@ mod tests {
         let comparison = make_test_comparison();
         let client = MockTropicalClient::with_comparison(comparison);
 
-        let result = compare_prices_with_client(&client, "B08N5WRWNW", OutputFormat::Table).await;
+        let result = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Table,
+            2,
+            LinksMode::Show,
+            &HashMap::new(),
+            None,
+        )
+        .await;
         assert!(result.is_ok());
 
         let output = result.unwrap();
@@ -351,7 +875,16 @@ mod tests {
         let comparison = make_test_comparison();
         let client = MockTropicalClient::with_comparison(comparison);
 
-        let result = compare_prices_with_client(&client, "B08N5WRWNW", OutputFormat::Json).await;
+        let result = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Json,
+            2,
+            LinksMode::Show,
+            &HashMap::new(),
+            None,
+        )
+        .await;
         assert!(result.is_ok());
 
         let output = result.unwrap();
@@ -364,7 +897,16 @@ mod tests {
     async fn test_compare_prices_not_found() {
         let client = MockTropicalClient::empty();
 
-        let result = compare_prices_with_client(&client, "B08N5WRWNW", OutputFormat::Table).await;
+        let result = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Table,
+            2,
+            LinksMode::Show,
+            &HashMap::new(),
+            None,
+        )
+        .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No price data"));
     }
@@ -374,7 +916,16 @@ mod tests {
         let comparison = make_test_comparison();
         let client = MockTropicalClient::with_comparison(comparison);
 
-        let result = compare_prices_with_client(&client, "B08N5WRWNW", OutputFormat::Table).await;
+        let result = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Table,
+            2,
+            LinksMode::Show,
+            &HashMap::new(),
+            None,
+        )
+        .await;
         assert!(result.is_ok());
 
         let output = result.unwrap();
@@ -386,7 +937,16 @@ mod tests {
         let comparison = make_test_comparison();
         let client = MockTropicalClient::with_comparison(comparison);
 
-        let result = compare_prices_with_client(&client, "B08N5WRWNW", OutputFormat::Table).await;
+        let result = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Table,
+            2,
+            LinksMode::Show,
+            &HashMap::new(),
+            None,
+        )
+        .await;
         assert!(result.is_ok());
 
         let output = result.unwrap();
@@ -398,7 +958,16 @@ mod tests {
     async fn test_compare_prices_network_error() {
         let client = MockTropicalClient::failing();
 
-        let result = compare_prices_with_client(&client, "B08N5WRWNW", OutputFormat::Table).await;
+        let result = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Table,
+            2,
+            LinksMode::Show,
+            &HashMap::new(),
+            None,
+        )
+        .await;
         assert!(result.is_err());
     }
 
@@ -430,19 +999,380 @@ mod tests {
             total_stores: 1,
         };
 
-        let output = format_comparison(&comparison);
+        let output = format_comparison(&comparison, 2, LinksMode::Show);
         assert!(output.contains("DE"));
         assert!(output.contains("€50.00"));
         assert!(output.contains("🏆")); // Should be winner
     }
 
+    #[test]
+    fn test_format_comparison_price_decimals() {
+        let comparison = PriceComparison {
+            asin: "TEST".to_string(),
+            title: "Test".to_string(),
+            prices: vec![make_country_price("DE", 49.4567, false)],
+            total_stores: 1,
+        };
+
+        assert!(format_comparison(&comparison, 0, LinksMode::Show).contains("DE: €49"));
+        assert!(!format_comparison(&comparison, 0, LinksMode::Show).contains("€49."));
+        assert!(format_comparison(&comparison, 3, LinksMode::Show).contains("DE: €49.457"));
+    }
+
     #[test]
     fn test_format_comparison_with_savings() {
         let comparison = make_test_comparison();
-        let output = format_comparison(&comparison);
+        let output = format_comparison(&comparison, 2, LinksMode::Show);
 
         assert!(output.contains("DE")); // Cheapest
         assert!(output.contains("FR")); // More expensive
         assert!(output.contains("+")); // Savings indicator
     }
+
+    #[tokio::test]
+    async fn test_compare_prices_converted() {
+        let client = MockTropicalClient::with_comparison(make_test_comparison());
+        let rates = crate::tropical::ExchangeRates {
+            base: "EUR".to_string(),
+            rates: HashMap::from([("GBP".to_string(), 0.5)]),
+        };
+        let conversion = Conversion::new(&rates, "GBP").unwrap();
+
+        // Conversion wins over the locale relabeling
+        let mapping = HashMap::from([("DE".to_string(), "SEK".to_string())]);
+        let output = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Table,
+            2,
+            LinksMode::Hide,
+            &mapping,
+            Some(&conversion),
+        )
+        .await
+        .unwrap();
+        assert!(output.contains("Best at 🇩🇪 DE: £25.00"));
+        assert!(output.contains("FR: £27.50 (+£2, +10%)"));
+        assert!(output.contains("Max savings: £5.00"));
+    }
+
+    #[tokio::test]
+    async fn test_compare_prices_locale_currency() {
+        let comparison = PriceComparison {
+            asin: "B08N5WRWNW".to_string(),
+            title: "Test Product".to_string(),
+            prices: vec![
+                make_country_price("UK", 49.99, false),
+                make_country_price("DE", 54.99, false),
+            ],
+            total_stores: 2,
+        };
+        let client = MockTropicalClient::with_comparison(comparison);
+        let mapping = HashMap::from([("uk".to_string(), "gbp".to_string())]);
+
+        let output = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Table,
+            2,
+            LinksMode::Hide,
+            &mapping,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(output.contains("Best at 🇬🇧 UK: £49.99"));
+        assert!(output.contains("DE: €54.99"));
+
+        let json = compare_prices_with_client(
+            &client,
+            "B08N5WRWNW",
+            OutputFormat::Json,
+            2,
+            LinksMode::Show,
+            &mapping,
+            None,
+        )
+        .await
+        .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["prices"][0]["currency"], "GBP");
+        assert_eq!(parsed["prices"][1]["currency"], "EUR");
+    }
+
+    #[test]
+    fn test_format_money() {
+        assert_eq!(format_money(49.5, "EUR", 2), "€49.50");
+        assert_eq!(format_money(49.5, "GBP", 2), "£49.50");
+        assert_eq!(format_money(549.0, "SEK", 0), "549 kr");
+        assert_eq!(format_money(10.0, "CHF", 2), "10.00 CHF");
+    }
+
+    #[test]
+    fn test_format_comparison_links_modes() {
+        let comparison = make_test_comparison();
+
+        let shown = format_comparison(&comparison, 2, LinksMode::Show);
+        assert!(shown.contains("🔗 Links:"));
+        assert!(shown.contains("💰 Max savings"));
+
+        let hidden = format_comparison(&comparison, 2, LinksMode::Hide);
+        assert!(!hidden.contains("🔗 Links:"));
+        assert!(hidden.contains("💰 Max savings"));
+        assert_eq!(hidden, shown.split("\n\n🔗 Links:").next().unwrap());
+
+        let only = format_comparison(&comparison, 2, LinksMode::Only);
+        assert!(only.starts_with("🔗 Links:"));
+        assert!(!only.contains("📦"));
+        assert_eq!(only.lines().count(), comparison.prices.len() + 1);
+    }
+
+    // Batch compare tests
+
+    #[tokio::test]
+    async fn test_compare_batch_combined_table() {
+        let mut second = make_test_comparison();
+        second.asin = "B0C8PSMPTH".to_string();
+        second.title = "Second Product".to_string();
+        second.prices =
+            vec![make_country_price("ES", 20.0, false), make_country_price("DE", 25.0, false)];
+
+        let client =
+            Arc::new(MockTropicalClient::with_comparisons(vec![make_test_comparison(), second]));
+        let asins =
+            vec!["B08N5WRWNW".to_string(), "B0C8PSMPTH".to_string(), "B000MISSING".to_string()];
+
+        let output = compare_batch_with_client(
+            client,
+            &asins,
+            SavingsThreshold::default(),
+            OutputFormat::Table,
+            2,
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert!(lines[0].starts_with("ASIN"));
+        assert!(lines[2].starts_with("B08N5WRWNW"));
+        assert!(lines[2].contains("€49.99"));
+        assert!(lines[2].contains("€10.00 (17%)"));
+        assert!(lines[3].starts_with("B0C8PSMPTH"));
+        assert!(lines[3].contains("€20.00"));
+        assert!(lines[3].contains("ES"));
+        assert!(output.ends_with("No price data for: B000MISSING"));
+    }
+
+    #[tokio::test]
+    async fn test_compare_batch_no_data() {
+        let client = Arc::new(MockTropicalClient::empty());
+        let asins = vec!["B000MISSING".to_string()];
+
+        let result = compare_batch_with_client(
+            client,
+            &asins,
+            SavingsThreshold::default(),
+            OutputFormat::Table,
+            2,
+            &HashMap::new(),
+            None,
+        )
+        .await;
+        assert!(result.unwrap_err().to_string().contains("B000MISSING"));
+    }
+
+    #[tokio::test]
+    async fn test_compare_summary() {
+        let mut single = make_test_comparison();
+        single.asin = "B0C8PSMPTH".to_string();
+        single.prices = vec![make_country_price("ES", 20.0, false)];
+        single.total_stores = 1;
+        let client =
+            Arc::new(MockTropicalClient::with_comparisons(vec![make_test_comparison(), single]));
+        let asins = vec!["B08N5WRWNW".to_string(), "B0C8PSMPTH".to_string()];
+
+        let output = compare_summary_with_client(
+            client.clone(),
+            &asins,
+            SavingsThreshold::default(),
+            2,
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "B08N5WRWNW cheapest in 🇩🇪 DE at €49.99, save up to €10 (17%) vs 🇮🇹 IT"
+        );
+        assert_eq!(lines[1], "B0C8PSMPTH only listed in 🇪🇸 ES at €20.00");
+        assert_eq!(lines.len(), 2);
+
+        let mapping = HashMap::from([("es".to_string(), "GBP".to_string())]);
+        let output = compare_summary_with_client(
+            client,
+            &asins,
+            SavingsThreshold::default(),
+            2,
+            &mapping,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(output.lines().nth(1), Some("B0C8PSMPTH only listed in 🇪🇸 ES at £20.00"));
+    }
+
+    #[test]
+    fn test_format_summary_same_price() {
+        let mut comparison = make_test_comparison();
+        comparison.prices =
+            vec![make_country_price("DE", 30.0, false), make_country_price("FR", 30.0, false)];
+        assert_eq!(
+            format_summary(&comparison, 2),
+            "B08N5WRWNW cheapest in 🇩🇪 DE at €30.00, same price in all 2 stores"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compare_matrix_formats() {
+        let mut second = make_test_comparison();
+        second.asin = "B0C8PSMPTH".to_string();
+        second.prices =
+            vec![make_country_price("ES", 20.0, false), make_country_price("FR", 25.5, false)];
+        let comparisons = vec![make_test_comparison(), second];
+        let asins =
+            vec!["B08N5WRWNW".to_string(), "B0C8PSMPTH".to_string(), "B000MISSING".to_string()];
+        let mapping = HashMap::from([("it".to_string(), "GBP".to_string())]);
+        let matrix = |format| {
+            let client = Arc::new(MockTropicalClient::with_comparisons(comparisons.clone()));
+            let asins = asins.clone();
+            let mapping = mapping.clone();
+            async move {
+                compare_matrix_with_client(
+                    client,
+                    &asins,
+                    SavingsThreshold::default(),
+                    format,
+                    2,
+                    &mapping,
+                    None,
+                )
+                .await
+                .unwrap()
+            }
+        };
+
+        let csv = matrix(OutputFormat::Csv).await;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "asin,DE,ES,FR,IT");
+        assert_eq!(lines[1], "B08N5WRWNW,49.99,,54.99,59.99");
+        assert_eq!(lines[2], "B0C8PSMPTH,,20.00,25.50,");
+        assert_eq!(lines.len(), 3);
+
+        let markdown = matrix(OutputFormat::Markdown).await;
+        assert!(markdown.starts_with("| ASIN | DE | ES | FR | IT |\n|------|---:|---:|---:|---:|"));
+        assert!(markdown.contains("| B0C8PSMPTH |  | €20.00 | €25.50 |  |"));
+        assert!(markdown.contains("| B08N5WRWNW | €49.99 |  | €54.99 | £59.99 |"));
+        assert!(markdown.ends_with("No price data for: B000MISSING"));
+
+        let table = matrix(OutputFormat::Table).await;
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with("ASIN                 DE         ES"));
+        assert_eq!(lines[3].trim_end(), "B0C8PSMPTH                   20.00      25.50");
+
+        let json: PriceMatrix = serde_json::from_str(&matrix(OutputFormat::Json).await).unwrap();
+        assert_eq!(json.rows[1].prices, vec![None, Some(20.0), Some(25.5), None]);
+        assert_eq!(json.currencies, vec!["EUR", "EUR", "EUR", "GBP"]);
+        assert_eq!(json.currency, "EUR");
+    }
+
+    #[test]
+    fn test_savings_threshold_matches() {
+        // DE 49.99 vs IT 59.99: €10.00 / 17% savings
+        let comparison = make_test_comparison();
+
+        assert!(SavingsThreshold::default().matches(&comparison));
+        assert!(
+            SavingsThreshold { min_savings: Some(10.0), ..Default::default() }.matches(&comparison)
+        );
+        assert!(!SavingsThreshold { min_savings: Some(15.0), ..Default::default() }
+            .matches(&comparison));
+        assert!(!SavingsThreshold { min_savings_percent: Some(20.0), ..Default::default() }
+            .matches(&comparison));
+        assert!(!SavingsThreshold { min_savings: Some(5.0), min_savings_percent: Some(20.0) }
+            .matches(&comparison));
+    }
+
+    #[tokio::test]
+    async fn test_compare_batch_min_savings() {
+        let mut low = make_test_comparison();
+        low.asin = "B0C8PSMPTH".to_string();
+        low.prices =
+            vec![make_country_price("ES", 50.0, false), make_country_price("DE", 51.0, false)];
+
+        let client =
+            Arc::new(MockTropicalClient::with_comparisons(vec![make_test_comparison(), low]));
+        let asins = vec!["B08N5WRWNW".to_string(), "B0C8PSMPTH".to_string()];
+        let threshold =
+            SavingsThreshold { min_savings: Some(5.0), min_savings_percent: Some(10.0) };
+
+        let output = compare_batch_with_client(
+            client,
+            &asins,
+            threshold,
+            OutputFormat::Table,
+            2,
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains("B08N5WRWNW"));
+        assert!(!output.contains("B0C8PSMPTH"));
+        assert!(output.contains("1 below the savings threshold"));
+    }
+
+    #[tokio::test]
+    async fn test_compare_batch_locale_currency() {
+        let mut comparison = make_test_comparison();
+        comparison.prices =
+            vec![make_country_price("UK", 40.0, false), make_country_price("DE", 54.99, false)];
+        let client = Arc::new(MockTropicalClient::with_comparisons(vec![comparison]));
+        let asins = vec!["B08N5WRWNW".to_string()];
+        let mapping = HashMap::from([("uk".to_string(), "gbp".to_string())]);
+        let threshold = SavingsThreshold { min_savings: Some(5.0), ..Default::default() };
+
+        let json = compare_batch_with_client(
+            client.clone(),
+            &asins,
+            threshold,
+            OutputFormat::Json,
+            2,
+            &mapping,
+            None,
+        )
+        .await
+        .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["prices"][0]["currency"], "GBP");
+        assert_eq!(parsed[0]["prices"][1]["currency"], "EUR");
+
+        let table = compare_batch_with_client(
+            client,
+            &asins,
+            threshold,
+            OutputFormat::Table,
+            2,
+            &mapping,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(table.lines().nth(2).unwrap().contains("£40.00"));
+    }
 }
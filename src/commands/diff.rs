@@ -0,0 +1,333 @@
+//! Diff command implementation comparing two saved JSON scrapes.
+
+use crate::amazon::Product;
+use crate::commands::reformat::parse_products_json;
+use crate::config::{Config, OutputFormat, RatingScale};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::info;
+
+/// Price differences smaller than this are treated as unchanged.
+const PRICE_EPSILON: f64 = 0.005;
+
+/// Star rating differences smaller than this are treated as unchanged.
+const RATING_EPSILON: f32 = 0.05;
+
+/// A product identified by ASIN and title.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffEntry {
+    pub asin: String,
+    pub title: String,
+}
+
+/// A price change for a product present in both scrapes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PriceChange {
+    pub asin: String,
+    pub title: String,
+    /// Old price (None if hidden or missing)
+    pub old: Option<f64>,
+    /// New price (None if hidden or missing)
+    pub new: Option<f64>,
+    pub currency: String,
+}
+
+/// A star rating change for a product present in both scrapes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RatingChange {
+    pub asin: String,
+    pub title: String,
+    pub old: Option<f32>,
+    pub new: Option<f32>,
+}
+
+/// Differences between two scrapes, matched by ASIN.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ScrapeDiff {
+    /// ASINs only in the new scrape
+    pub added: Vec<DiffEntry>,
+    /// ASINs only in the old scrape
+    pub removed: Vec<DiffEntry>,
+    pub price_changes: Vec<PriceChange>,
+    pub rating_changes: Vec<RatingChange>,
+}
+
+impl ScrapeDiff {
+    /// Returns true if nothing changed between the scrapes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.price_changes.is_empty()
+            && self.rating_changes.is_empty()
+    }
+}
+
+/// Visible price of a product, ignoring "see price in cart" placeholders.
+fn visible_price(product: &Product) -> Option<f64> {
+    product.price.as_ref().filter(|p| !p.is_hidden).map(|p| p.current)
+}
+
+fn entry(product: &Product) -> DiffEntry {
+    DiffEntry { asin: product.asin.clone(), title: product.title.clone() }
+}
+
+/// Compares two scrapes by ASIN. Added and changed products keep the new
+/// scrape's order; removed products keep the old scrape's order.
+pub fn diff_products(old: &[Product], new: &[Product]) -> ScrapeDiff {
+    let old_by_asin: HashMap<&str, &Product> = old.iter().map(|p| (p.asin.as_str(), p)).collect();
+    let new_by_asin: HashMap<&str, &Product> = new.iter().map(|p| (p.asin.as_str(), p)).collect();
+
+    let mut diff = ScrapeDiff {
+        removed: old
+            .iter()
+            .filter(|p| !new_by_asin.contains_key(p.asin.as_str()))
+            .map(entry)
+            .collect(),
+        ..ScrapeDiff::default()
+    };
+
+    for product in new {
+        let Some(before) = old_by_asin.get(product.asin.as_str()) else {
+            diff.added.push(entry(product));
+            continue;
+        };
+
+        let (old_price, new_price) = (visible_price(before), visible_price(product));
+        let price_changed = match (old_price, new_price) {
+            (Some(a), Some(b)) => (a - b).abs() >= PRICE_EPSILON,
+            (a, b) => a.is_some() != b.is_some(),
+        };
+        if price_changed {
+            diff.price_changes.push(PriceChange {
+                asin: product.asin.clone(),
+                title: product.title.clone(),
+                old: old_price,
+                new: new_price,
+                currency: product
+                    .price
+                    .as_ref()
+                    .or(before.price.as_ref())
+                    .map(|p| p.currency.clone())
+                    .unwrap_or_default(),
+            });
+        }
+
+        let old_stars = before.rating.as_ref().map(|r| r.stars);
+        let new_stars = product.rating.as_ref().map(|r| r.stars);
+        let rating_changed = match (old_stars, new_stars) {
+            (Some(a), Some(b)) => (a - b).abs() >= RATING_EPSILON,
+            (a, b) => a.is_some() != b.is_some(),
+        };
+        if rating_changed {
+            diff.rating_changes.push(RatingChange {
+                asin: product.asin.clone(),
+                title: product.title.clone(),
+                old: old_stars,
+                new: new_stars,
+            });
+        }
+    }
+
+    diff
+}
+
+/// Reports what changed between two saved JSON scrapes, without fetching.
+pub struct DiffCommand {
+    config: Config,
+}
+
+impl DiffCommand {
+    /// Creates a new diff command.
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Reads both JSON files and returns the formatted diff.
+    pub fn execute(&self, old_path: &Path, new_path: &Path) -> Result<String> {
+        let read = |path: &Path| {
+            std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read input file: {}", path.display()))
+        };
+
+        self.execute_with_json(&read(old_path)?, &read(new_path)?)
+    }
+
+    /// Diffs two JSON strings (each an array of products or a single product).
+    pub fn execute_with_json(&self, old_json: &str, new_json: &str) -> Result<String> {
+        let old = parse_products_json(old_json).context("Failed to parse old JSON")?;
+        let new = parse_products_json(new_json).context("Failed to parse new JSON")?;
+        info!("Comparing {} old and {} new products", old.len(), new.len());
+
+        let diff = diff_products(&old, &new);
+
+        Ok(match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&diff)?,
+            _ => format_summary(&diff, self.config.rating_scale),
+        })
+    }
+}
+
+fn format_amount(amount: Option<f64>) -> String {
+    amount.map_or_else(|| "-".to_string(), |a| format!("{:.2}", a))
+}
+
+fn format_stars(stars: Option<f32>, scale: RatingScale) -> String {
+    stars.map_or_else(|| "-".to_string(), |s| scale.format(s))
+}
+
+/// Renders a diff as a readable summary, with ratings on `scale`.
+pub fn format_summary(diff: &ScrapeDiff, scale: RatingScale) -> String {
+    if diff.is_empty() {
+        return "No changes.".to_string();
+    }
+
+    let mut lines = Vec::new();
+
+    if !diff.added.is_empty() {
+        lines.push(format!("Added ({}):", diff.added.len()));
+        lines.extend(diff.added.iter().map(|e| format!("  + {}  {}", e.asin, e.title)));
+    }
+
+    if !diff.removed.is_empty() {
+        lines.push(format!("Removed ({}):", diff.removed.len()));
+        lines.extend(diff.removed.iter().map(|e| format!("  - {}  {}", e.asin, e.title)));
+    }
+
+    if !diff.price_changes.is_empty() {
+        lines.push(format!("Price changes ({}):", diff.price_changes.len()));
+        for change in &diff.price_changes {
+            let mut line = format!(
+                "  {}  {}: {} -> {} {}",
+                change.asin,
+                change.title,
+                format_amount(change.old),
+                format_amount(change.new),
+                change.currency
+            );
+            if let (Some(old), Some(new)) = (change.old, change.new) {
+                line.push_str(&format!(" ({:+.2}", new - old));
+                if old > 0.0 {
+                    line.push_str(&format!(", {:+.1}%", (new - old) / old * 100.0));
+                }
+                line.push(')');
+            }
+            lines.push(line);
+        }
+    }
+
+    if !diff.rating_changes.is_empty() {
+        lines.push(format!("Rating changes ({}):", diff.rating_changes.len()));
+        lines.extend(diff.rating_changes.iter().map(|c| {
+            let (old, new) = (format_stars(c.old, scale), format_stars(c.new, scale));
+            format!("  {}  {}: {} -> {}", c.asin, c.title, old, new)
+        }));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amazon::models::{Price, Rating};
+
+    fn make_product(asin: &str, price: f64, stars: f32) -> Product {
+        Product {
+            asin: asin.to_string(),
+            title: format!("Product {}", asin),
+            url: format!("https://www.amazon.com/dp/{}", asin),
+            image_url: None,
+            price: Some(Price::simple(price, "USD")),
+            rating: Some(Rating::new(stars, 100)),
+            is_sponsored: false,
+            is_prime: true,
+            is_amazon_choice: false,
+            in_stock: true,
+            brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_added_removed_and_price_drop() {
+        let old =
+            vec![make_product("B000000001", 29.99, 4.5), make_product("B000000002", 10.0, 4.0)];
+        let new =
+            vec![make_product("B000000001", 24.99, 4.5), make_product("B000000003", 5.0, 3.5)];
+
+        let diff = diff_products(&old, &new);
+
+        assert_eq!(diff.added, vec![entry(&new[1])]);
+        assert_eq!(diff.removed, vec![entry(&old[1])]);
+        assert_eq!(diff.price_changes.len(), 1);
+        assert_eq!(diff.price_changes[0].asin, "B000000001");
+        assert_eq!(diff.price_changes[0].old, Some(29.99));
+        assert_eq!(diff.price_changes[0].new, Some(24.99));
+        assert!(diff.rating_changes.is_empty());
+
+        let summary = format_summary(&diff, RatingScale::Five);
+        assert!(summary.contains("Added (1):\n  + B000000003  Product B000000003"));
+        assert!(summary.contains("Removed (1):\n  - B000000002  Product B000000002"));
+        assert!(
+            summary.contains("B000000001  Product B000000001: 29.99 -> 24.99 USD (-5.00, -16.7%)")
+        );
+    }
+
+    #[test]
+    fn test_diff_rating_change_and_no_changes() {
+        let old = vec![make_product("B000000001", 29.99, 4.5)];
+        let new = vec![make_product("B000000001", 29.99, 4.2)];
+
+        let diff = diff_products(&old, &new);
+        assert!(diff.price_changes.is_empty());
+        assert_eq!(diff.rating_changes[0].old, Some(4.5));
+        assert_eq!(diff.rating_changes[0].new, Some(4.2));
+        assert!(format_summary(&diff, RatingScale::Five)
+            .contains("Rating changes (1):\n  B000000001  Product B000000001: 4.5 -> 4.2"));
+        assert!(format_summary(&diff, RatingScale::Ten).contains(": 9.0 -> 8.4"));
+        assert!(format_summary(&diff, RatingScale::Hundred).contains(": 90 -> 84"));
+
+        assert!(diff_products(&old, &old).is_empty());
+        assert_eq!(format_summary(&diff_products(&old, &old), RatingScale::Five), "No changes.");
+    }
+
+    #[test]
+    fn test_diff_command_json_output() {
+        let old = serde_json::to_string(&vec![make_product("B000000001", 29.99, 4.5)]).unwrap();
+        let new = serde_json::to_string(&make_product("B000000002", 9.99, 4.0)).unwrap();
+
+        let config = Config { format: OutputFormat::Json, ..Config::default() };
+        let output = DiffCommand::new(config).execute_with_json(&old, &new).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(parsed["added"][0]["asin"], "B000000002");
+        assert_eq!(parsed["removed"][0]["asin"], "B000000001");
+        assert!(parsed["price_changes"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_command_invalid_json() {
+        let cmd = DiffCommand::new(Config::default());
+        let err = cmd.execute_with_json("[]", "not json").unwrap_err();
+        assert!(err.to_string().contains("Failed to parse new JSON"));
+    }
+}
@@ -0,0 +1,161 @@
+//! TLS fingerprint self-test against a JA3/JA4-reporting endpoint.
+
+use crate::amazon::AmazonClient;
+use crate::config::Config;
+use anyhow::{Context, Result};
+use tracing::info;
+
+/// Endpoint used when neither `--url` nor `fingerprint_url` is set.
+pub const DEFAULT_FINGERPRINT_URL: &str = "https://tls.peet.ws/api/all";
+
+/// Reported fields, as (label, JSON key). Keys are looked up anywhere in the
+/// response, so nested layouts such as `{"tls": {"ja3": ...}}` work too.
+const FINGERPRINT_FIELDS: &[(&str, &str)] = &[
+    ("JA3", "ja3"),
+    ("JA3 hash", "ja3_hash"),
+    ("JA4", "ja4"),
+    ("HTTP/2", "akamai_fingerprint"),
+    ("HTTP/2 hash", "akamai_fingerprint_hash"),
+    ("User-Agent", "user_agent"),
+];
+
+/// Fetches a fingerprint-reporting endpoint through the Amazon client and
+/// reports what the server observed, to compare against a real Chrome.
+pub struct FingerprintCommand {
+    config: Config,
+}
+
+impl FingerprintCommand {
+    /// Creates a new fingerprint test command.
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Endpoint to query: `url` if given, else the configured one, else the default.
+    pub fn endpoint(&self, url: Option<&str>) -> String {
+        url.or(self.config.fingerprint_url.as_deref())
+            .unwrap_or(DEFAULT_FINGERPRINT_URL)
+            .to_string()
+    }
+
+    /// Runs the test and returns the report.
+    pub async fn execute(&self, url: Option<&str>) -> Result<String> {
+        let client =
+            AmazonClient::new(&self.config).await.context("Failed to create HTTP client")?;
+
+        self.execute_with_client(&client, url).await
+    }
+
+    /// Runs the test with a provided client (for testing).
+    pub async fn execute_with_client(
+        &self,
+        client: &AmazonClient,
+        url: Option<&str>,
+    ) -> Result<String> {
+        let endpoint = self.endpoint(url);
+        info!("Checking TLS fingerprint against {}", endpoint);
+
+        let body = client
+            .get_raw(&endpoint)
+            .await
+            .with_context(|| format!("Fingerprint request to {} failed", endpoint))?;
+
+        Ok(fingerprint_report(&endpoint, &body))
+    }
+}
+
+/// Formats the fingerprint fields found in `body`; falls back to the raw
+/// body when the endpoint doesn't return recognizable JSON.
+fn fingerprint_report(endpoint: &str, body: &str) -> String {
+    let mut lines = vec![format!("Endpoint:     {}", endpoint)];
+
+    let json = serde_json::from_str::<serde_json::Value>(body).ok();
+    let fields: Vec<(&str, &str)> = FINGERPRINT_FIELDS
+        .iter()
+        .filter_map(|(label, key)| Some((*label, find_string(json.as_ref()?, key)?)))
+        .collect();
+
+    if fields.is_empty() {
+        lines.push("No JA3/JA4 fields found in the response:".to_string());
+        lines.push(body.trim().to_string());
+    } else {
+        for (label, value) in fields {
+            lines.push(format!("{:<13} {}", format!("{}:", label), value));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Returns the first string value stored under `key`, searching depth-first.
+fn find_string<'a>(value: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+    match value {
+        serde_json::Value::Object(map) => map
+            .get(key)
+            .and_then(|v| v.as_str())
+            .or_else(|| map.values().find_map(|v| find_string(v, key))),
+        serde_json::Value::Array(items) => items.iter().find_map(|v| find_string(v, key)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_reports_fingerprint_from_endpoint() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/all"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{
+                    "user_agent": "Mozilla/5.0 Chrome/131.0.0.0",
+                    "tls": {
+                        "ja3": "771,4865-4866-4867,0-23-65281,29-23-24,0",
+                        "ja3_hash": "cd08e31494f9531f560d64c695473da9",
+                        "ja4": "t13d1516h2_8daaf6152771_02713d6af862"
+                    },
+                    "http2": { "akamai_fingerprint": "1:65536;2:0;4:6291456|15663105|0|m,a,s,p" }
+                }"#,
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = Config {
+            fingerprint_url: Some(format!("{}/api/all", mock_server.uri())),
+            ..Config::default()
+        };
+        let client = AmazonClient::new(&config).await.unwrap();
+        let output =
+            FingerprintCommand::new(config).execute_with_client(&client, None).await.unwrap();
+
+        assert!(output.contains("JA3 hash:     cd08e31494f9531f560d64c695473da9"));
+        assert!(output.contains("JA4:          t13d1516h2_8daaf6152771_02713d6af862"));
+        assert!(output.contains("HTTP/2:       1:65536;2:0;4:6291456|15663105|0|m,a,s,p"));
+        assert!(output.contains("User-Agent:   Mozilla/5.0 Chrome/131.0.0.0"));
+    }
+
+    #[test]
+    fn test_report_falls_back_to_raw_body() {
+        let output = fingerprint_report("http://localhost/", "plain text");
+        assert!(output.contains("No JA3/JA4 fields found"));
+        assert!(output.ends_with("plain text"));
+    }
+
+    #[test]
+    fn test_endpoint_precedence() {
+        let cmd = FingerprintCommand::new(Config::default());
+        assert_eq!(cmd.endpoint(None), DEFAULT_FINGERPRINT_URL);
+
+        let config =
+            Config { fingerprint_url: Some("http://config".to_string()), ..Config::default() };
+        let cmd = FingerprintCommand::new(config);
+        assert_eq!(cmd.endpoint(None), "http://config");
+        assert_eq!(cmd.endpoint(Some("http://flag")), "http://flag");
+    }
+}
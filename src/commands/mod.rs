@@ -1,10 +1,24 @@
 //! CLI command implementations.
 
+pub mod bestsellers;
+pub mod diff;
+pub mod fingerprint;
 pub mod product;
+pub mod reformat;
+pub mod schema;
 pub mod search;
+pub mod selftest;
+pub mod validate;
 
 #[cfg(feature = "tropical")]
 pub mod compare;
 
+pub use bestsellers::BestsellersCommand;
+pub use diff::DiffCommand;
+pub use fingerprint::FingerprintCommand;
 pub use product::ProductCommand;
-pub use search::SearchCommand;
+pub use reformat::ReformatCommand;
+pub use schema::{SchemaCommand, SchemaKind};
+pub use search::{SearchCommand, SearchReport};
+pub use selftest::SelftestCommand;
+pub use validate::ValidateConfigCommand;
@@ -1,20 +1,45 @@
 //! Product lookup command implementation.
 
-use crate::amazon::{AmazonClient, AmazonSearch, Parser, Product};
+use crate::amazon::{AmazonClient, AmazonSearch, BlockedError, Parser, Product};
 use crate::config::Config;
+use crate::filters::{FilterChain, FilterChainBuilder};
 use crate::format::Formatter;
+use crate::progress::{NoProgress, Progress, ProgressSink};
 use anyhow::{Context, Result};
-use tracing::info;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tracing::{debug, info};
 
 /// Executes a product lookup by ASIN.
 pub struct ProductCommand {
     config: Config,
+    progress: Arc<dyn ProgressSink>,
+    /// File that batch failures are appended to, as `ASIN\treason` lines
+    error_log: Option<PathBuf>,
 }
 
 impl ProductCommand {
     /// Creates a new product command.
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self { config, progress: Arc::new(NoProgress), error_log: None }
+    }
+
+    /// Appends each failed or skipped batch ASIN to `path` as an
+    /// `ASIN<TAB>reason` line, so the failures can be fed back in for a retry.
+    pub fn with_error_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.error_log = Some(path.into());
+        self
+    }
+
+    /// Sets where per-ASIN progress updates for batch lookups are reported.
+    pub fn with_progress(mut self, progress: Arc<dyn ProgressSink>) -> Self {
+        self.progress = progress;
+        self
     }
 
     /// Fetches a product by ASIN and returns formatted output.
@@ -33,7 +58,7 @@ impl ProductCommand {
     ) -> Result<String> {
         // Validate ASIN format (10 alphanumeric characters)
         let asin = asin.trim().to_uppercase();
-        if asin.len() != 10 || !asin.chars().all(|c| c.is_ascii_alphanumeric()) {
+        if !is_valid_asin(&asin) {
             anyhow::bail!(
                 "Invalid ASIN format: '{}'. ASIN should be 10 alphanumeric characters.",
                 asin
@@ -42,15 +67,30 @@ impl ProductCommand {
 
         info!("Looking up product: {}", asin);
 
-        let parser = Parser::new(client.region());
+        let parser = Parser::new(client.region()).with_config(&self.config);
         let html = client.product(&asin).await?;
         let product = parser.parse_product_page(&html, &asin)?;
 
         // Format output
-        let formatter = Formatter::new(self.config.format);
+        let formatter = Formatter::from_config(&self.config);
+        if !self.filters().matches(&product) {
+            debug!("{} filtered out", asin);
+            return Ok(formatter.format_products(&[]));
+        }
         Ok(formatter.format_product(&product))
     }
 
+    /// Builds the configured filter chain. Product pages carry the fields
+    /// list cards lack (Subscribe & Save, return warnings), so every filter
+    /// is meaningful here.
+    fn filters(&self) -> FilterChain {
+        let filters = FilterChainBuilder::from_config(&self.config).build();
+        if !filters.is_empty() {
+            debug!("Active filters: {}", filters.descriptions().join(", "));
+        }
+        filters
+    }
+
     /// Fetches multiple products by ASIN.
     pub async fn execute_batch(&self, asins: &[String]) -> Result<String> {
         let client =
@@ -65,53 +105,349 @@ impl ProductCommand {
         client: &impl AmazonSearch,
         asins: &[String],
     ) -> Result<String> {
-        let parser = Parser::new(client.region());
-        let mut products: Vec<Product> = Vec::new();
+        let products = self.filters().apply(self.fetch_batch(client, asins).await?);
+
+        let formatter = Formatter::from_config(&self.config);
+        Ok(formatter.format_products(&products))
+    }
+
+    /// Fetches multiple products and writes each to `<dir>/<ASIN>.<ext>`.
+    pub async fn execute_batch_to_dir(&self, asins: &[String], dir: &Path) -> Result<Vec<PathBuf>> {
+        let client =
+            AmazonClient::new(&self.config).await.context("Failed to create HTTP client")?;
+
+        self.execute_batch_to_dir_with_client(Arc::new(client), asins, dir).await
+    }
+
+    /// Writes one file per product with a provided client (for testing).
+    ///
+    /// Up to `batch_concurrency` lookups run at once, and each file is written
+    /// as soon as its product arrives, so an interrupted run keeps what it
+    /// fetched. Products rejected by the filters are not written. Returns the
+    /// written paths in `asins` order.
+    pub async fn execute_batch_to_dir_with_client<C: AmazonSearch + 'static>(
+        &self,
+        client: Arc<C>,
+        asins: &[String],
+        dir: &Path,
+    ) -> Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create output directory: {}", dir.display()))?;
 
-        for asin in asins {
+        let parser = Parser::new(client.region()).with_config(&self.config);
+        let formatter = Formatter::from_config(&self.config);
+        let filters = self.filters();
+        let mut error_log = ErrorLog::open(self.error_log.as_deref())?;
+        let asin_delay = self.config.asin_delay_ms.map(Duration::from_millis);
+        let limit = self.config.batch_concurrency.max(1);
+        let total = asins.len();
+        let mut tasks = JoinSet::new();
+        let mut written = Vec::with_capacity(total);
+        let mut done = 0;
+
+        // Skip invalid ASINs up front so only real lookups are queued
+        let mut queue = Vec::with_capacity(total);
+        for (index, asin) in asins.iter().enumerate() {
             let asin = asin.trim().to_uppercase();
-            if asin.len() != 10 || !asin.chars().all(|c| c.is_ascii_alphanumeric()) {
+            if is_valid_asin(&asin) {
+                queue.push((index, asin));
+            } else {
                 eprintln!("Skipping invalid ASIN: {}", asin);
-                continue;
+                error_log.record(&asin, "invalid ASIN")?;
+                done += 1;
+                self.progress.report(&Progress::Asin { done, total });
+            }
+        }
+
+        // Lookups not finished yet, in input order; logged if the batch aborts
+        let mut pending: BTreeMap<usize, String> = queue.iter().cloned().collect();
+
+        // Parses, writes and logs one finished lookup
+        let mut finish = |index: usize, asin: String, html: Result<String>| -> Result<()> {
+            pending.remove(&index);
+            match parse_lookup(&parser, &asin, html) {
+                Ok(product) if !filters.matches(&product) => {
+                    debug!("{} filtered out", product.asin);
+                }
+                Ok(product) => {
+                    let ext = self.config.format.extension();
+                    let path = dir.join(format!("{}.{}", product.asin, ext));
+                    fs::write(&path, formatter.format_product(&product))
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    written.push((index, path));
+                }
+                Err(failure) => {
+                    let unattempted: Vec<String> = pending.values().cloned().collect();
+                    self.handle_failure(&mut error_log, &asin, failure, &unattempted, done, total)?;
+                }
             }
 
+            done += 1;
+            self.progress.report(&Progress::Asin { done, total });
+            Ok(())
+        };
+
+        for (i, (index, asin)) in queue.into_iter().enumerate() {
+            // Keep at most `limit` lookups in flight
+            if tasks.len() >= limit {
+                if let Some(joined) = tasks.join_next().await {
+                    let (index, asin, html) = joined.context("Product lookup task failed")?;
+                    finish(index, asin, html).inspect_err(|_| self.progress.finish())?;
+                }
+            }
+
+            if let Some(delay) = asin_delay.filter(|_| i > 0) {
+                debug!("Waiting {}ms before the next product", delay.as_millis());
+                tokio::time::sleep(delay).await;
+            }
             info!("Looking up product: {}", asin);
 
-            match client.product(&asin).await {
-                Ok(html) => match parser.parse_product_page(&html, &asin) {
+            let client = Arc::clone(&client);
+            tasks.spawn(async move {
+                let html = client.product(&asin).await;
+                (index, asin, html)
+            });
+        }
+
+        while let Some(joined) = tasks.join_next().await {
+            let (index, asin, html) = joined.context("Product lookup task failed")?;
+            finish(index, asin, html).inspect_err(|_| self.progress.finish())?;
+        }
+
+        self.progress.finish();
+
+        // Lookups finish in any order; report paths in the requested order
+        written.sort_by_key(|(index, _)| *index);
+        Ok(written.into_iter().map(|(_, path)| path).collect())
+    }
+
+    /// Re-runs the lookups listed in an `--error-log` file, replacing its
+    /// contents with the ASINs that still fail.
+    pub async fn execute_retry(self, error_log: &Path) -> Result<String> {
+        let client =
+            AmazonClient::new(&self.config).await.context("Failed to create HTTP client")?;
+
+        self.execute_retry_with_client(&client, error_log).await
+    }
+
+    /// Retries an error log with a provided client (for testing).
+    pub async fn execute_retry_with_client(
+        self,
+        client: &impl AmazonSearch,
+        error_log: &Path,
+    ) -> Result<String> {
+        let asins = read_error_log(error_log)?;
+        if asins.is_empty() {
+            anyhow::bail!("Nothing to retry: {} lists no ASINs", error_log.display());
+        }
+        info!("Retrying {} ASINs from {}", asins.len(), error_log.display());
+
+        // Collect what fails again in a fresh log next to the original, which
+        // is only replaced once the batch is over: an interrupted retry keeps
+        // the full list
+        let mut name = error_log.file_name().unwrap_or_default().to_os_string();
+        name.push(".retry");
+        let new_log = error_log.with_file_name(name);
+        fs::write(&new_log, "")
+            .with_context(|| format!("Failed to create error log: {}", new_log.display()))?;
+
+        let result = self.with_error_log(&new_log).execute_batch_with_client(client, &asins).await;
+
+        // A fail-fast abort still logs every ASIN it didn't get to
+        let complete = match &result {
+            Ok(_) => true,
+            Err(e) => BlockedError::find(e).is_some(),
+        };
+        if complete {
+            fs::rename(&new_log, error_log)
+                .with_context(|| format!("Failed to replace error log: {}", error_log.display()))?;
+        } else {
+            let _ = fs::remove_file(&new_log);
+        }
+        result
+    }
+
+    /// Fetches and parses each valid ASIN, reporting failures to stderr.
+    ///
+    /// With `fail_fast`, the first CAPTCHA or block aborts the batch with an
+    /// error instead of burning through the remaining ASINs.
+    async fn fetch_batch(
+        &self,
+        client: &impl AmazonSearch,
+        asins: &[String],
+    ) -> Result<Vec<Product>> {
+        let parser = Parser::new(client.region()).with_config(&self.config);
+        let mut products: Vec<Product> = Vec::new();
+        let mut error_log = ErrorLog::open(self.error_log.as_deref())?;
+
+        let asin_delay = self.config.asin_delay_ms.map(Duration::from_millis);
+        let mut fetched_any = false;
+
+        for (i, asin) in asins.iter().enumerate() {
+            let asin = asin.trim().to_uppercase();
+            if !is_valid_asin(&asin) {
+                eprintln!("Skipping invalid ASIN: {}", asin);
+                error_log.record(&asin, "invalid ASIN")?;
+            } else {
+                // Space out product-page hits; skipped ASINs cost no request
+                if let Some(delay) = asin_delay.filter(|_| fetched_any) {
+                    debug!("Waiting {}ms before the next product", delay.as_millis());
+                    tokio::time::sleep(delay).await;
+                }
+                fetched_any = true;
+                info!("Looking up product: {}", asin);
+
+                let html = client.product(&asin).await;
+                match parse_lookup(&parser, &asin, html) {
                     Ok(product) => products.push(product),
-                    Err(e) => eprintln!("Failed to parse {}: {}", asin, e),
-                },
-                Err(e) => eprintln!("Failed to fetch {}: {}", asin, e),
+                    Err(failure) => {
+                        let unattempted = &asins[i + 1..];
+                        self.handle_failure(
+                            &mut error_log,
+                            &asin,
+                            failure,
+                            unattempted,
+                            i,
+                            asins.len(),
+                        )
+                        .inspect_err(|_| self.progress.finish())?;
+                    }
+                }
             }
+
+            self.progress.report(&Progress::Asin { done: i + 1, total: asins.len() });
         }
 
-        let formatter = Formatter::new(self.config.format);
-        Ok(formatter.format_products(&products))
+        self.progress.finish();
+        Ok(products)
+    }
+
+    /// Handles a failed lookup for every batch mode: with `fail_fast`, a
+    /// CAPTCHA or block aborts the batch with an error (`done` lookups in);
+    /// anything else is reported to stderr and the error log.
+    ///
+    /// On abort the failed ASIN and every `unattempted` one are still
+    /// logged, so `retry` can pick the batch up again.
+    fn handle_failure(
+        &self,
+        error_log: &mut ErrorLog,
+        asin: &str,
+        failure: LookupFailure,
+        unattempted: &[String],
+        done: usize,
+        total: usize,
+    ) -> Result<()> {
+        let LookupFailure { action, error } = failure;
+        let reason = format!("{} failed: {:#}", action, error);
+
+        if self.config.fail_fast && BlockedError::find(&error).is_some() {
+            error_log.record(asin, &reason)?;
+            for asin in unattempted {
+                error_log.record(&asin.trim().to_uppercase(), "not attempted (batch aborted)")?;
+            }
+            return Err(error
+                .context(format!("Aborted batch at {} ({} of {} ASINs done)", asin, done, total)));
+        }
+
+        eprintln!("Failed to {} {}: {}", action, asin, error);
+        error_log.record(asin, &reason)
     }
 }
 
+/// A batch lookup that failed while fetching or parsing.
+struct LookupFailure {
+    /// "fetch" or "parse"
+    action: &'static str,
+    error: anyhow::Error,
+}
+
+/// Parses a fetched product page, tagging a failure with the step it came from.
+fn parse_lookup(
+    parser: &Parser,
+    asin: &str,
+    html: Result<String>,
+) -> Result<Product, LookupFailure> {
+    let html = html.map_err(|error| LookupFailure { action: "fetch", error })?;
+    parser.parse_product_page(&html, asin).map_err(|error| LookupFailure { action: "parse", error })
+}
+
+/// ASINs are 10 alphanumeric characters.
+fn is_valid_asin(asin: &str) -> bool {
+    asin.len() == 10 && asin.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Appends batch failures to the `--error-log` file, if one is set.
+struct ErrorLog(Option<fs::File>);
+
+impl ErrorLog {
+    fn open(path: Option<&Path>) -> Result<Self> {
+        let file = match path {
+            Some(path) => Some(
+                fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .with_context(|| format!("Failed to open error log: {}", path.display()))?,
+            ),
+            None => None,
+        };
+        Ok(Self(file))
+    }
+
+    fn record(&mut self, asin: &str, reason: &str) -> Result<()> {
+        if let Some(file) = self.0.as_mut() {
+            // Keep one failure per line however the error is worded
+            let reason = reason.split_whitespace().collect::<Vec<_>>().join(" ");
+            writeln!(file, "{}\t{}", asin, reason).context("Failed to write error log")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the ASINs (first column) of an `--error-log` file, without duplicates.
+pub fn read_error_log(path: &Path) -> Result<Vec<String>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read error log: {}", path.display()))?;
+
+    let mut asins: Vec<String> = Vec::new();
+    for line in contents.lines() {
+        let asin = line.split('\t').next().unwrap_or("").trim();
+        if !asin.is_empty() && !asins.iter().any(|seen| seen == asin) {
+            asins.push(asin.to_string());
+        }
+    }
+    Ok(asins)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::amazon::Region;
-    use crate::config::OutputFormat;
+    use crate::config::{DedupBy, OutputFormat, PageCapAction, SortKey};
     use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
 
     /// Mock Amazon client for testing.
     struct MockAmazonClient {
         product_html: String,
         should_fail: bool,
         region: Region,
+        product_calls: AtomicUsize,
     }
 
     impl MockAmazonClient {
         fn new(product_html: String) -> Self {
-            Self { product_html, should_fail: false, region: Region::Us }
+            Self { product_html, should_fail: false, region: Region::Us, product_calls: 0.into() }
         }
 
         fn failing() -> Self {
-            Self { product_html: String::new(), should_fail: true, region: Region::Us }
+            Self {
+                product_html: String::new(),
+                should_fail: true,
+                region: Region::Us,
+                product_calls: 0.into(),
+            }
         }
     }
 
@@ -121,7 +457,12 @@ mod tests {
             Ok("<html></html>".to_string())
         }
 
+        async fn search_url(&self, _url: &str) -> Result<String> {
+            Ok("<html></html>".to_string())
+        }
+
         async fn product(&self, _asin: &str) -> Result<String> {
+            self.product_calls.fetch_add(1, Ordering::SeqCst);
             if self.should_fail {
                 anyhow::bail!("Simulated network error")
             } else {
@@ -134,21 +475,92 @@ mod tests {
         }
     }
 
+    /// Progress sink that records every update.
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<Progress>>);
+
+    impl ProgressSink for RecordingSink {
+        fn report(&self, progress: &Progress) {
+            self.0.lock().unwrap().push(progress.clone());
+        }
+    }
+
     fn make_test_config() -> Config {
         Config {
             region: Region::Us,
             proxy: None,
+            proxy_user: None,
+            proxy_pass: None,
+            proxies: Vec::new(),
+            retry_captcha_with_proxy: false,
             delay_ms: 0,
             delay_jitter_ms: 0,
+            page_delay_ms: None,
+            asin_delay_ms: None,
+            rng_seed: None,
+            retry_base_ms: 5000,
+            retry_multiplier: 2.0,
+            retry_max_ms: 60000,
+            retry_jitter_ms: 0,
+            max_retries: 1,
+            cache_dir: None,
+            cache_compress: true,
+            cache_ttl_hours: 24,
+            fingerprint_url: None,
             max_results: 20,
             format: OutputFormat::Table,
             min_price: None,
             max_price: None,
             min_rating: None,
+            min_reviews: None,
+            quality: None,
+            min_energy_class: None,
+            min_subscribe_discount: None,
+            max_stock_remaining: None,
             prime_only: false,
             no_sponsored: false,
             keywords: Vec::new(),
             exclude_keywords: Vec::new(),
+            keyword_match_mode: Default::default(),
+            exclude_digital: false,
+            exclude_frequently_returned: false,
+            drop_zero_price: false,
+            include_out_of_stock_price: false,
+            strict_prime: false,
+            strict_parse: false,
+            debug_raw: false,
+            show_url: false,
+            block_phrases: Vec::new(),
+            block_selectors: Vec::new(),
+            first_match: false,
+            dedup_by: DedupBy::Asin,
+            fail_fast: false,
+            sort: SortKey::Featured,
+            on_page_cap: PageCapAction::Warn,
+            follow_next_link: false,
+            show_total_results: false,
+            popular_query_min: 10000,
+            max_concurrent_regions: 3,
+            batch_concurrency: 1,
+            confirm_threshold: 50,
+            price_decimals: None,
+            csv_bom: false,
+            canonical_urls: false,
+            md_table_images: false,
+            md_tasklist: false,
+            minor_units: false,
+            rating_scale: Default::default(),
+            null_value: String::new(),
+            trailing_newline: false,
+            table_sep: None,
+            table_width: None,
+            hyperlinks: false,
+            decimal: None,
+            currency: None,
+            locale_currency: Default::default(),
+            rates_url: None,
+            rates_ttl_hours: 24,
+            presets: Default::default(),
         }
     }
 
@@ -295,6 +707,21 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_batch_waits_between_asins() {
+        let client = MockAmazonClient::new(make_product_html("Test Product", 19.99));
+        let mut config = make_test_config();
+        config.asin_delay_ms = Some(100);
+        let cmd = ProductCommand::new(config);
+
+        let asins = vec!["B08N5WRWNW".to_string(), "B08N5WRWNX".to_string()];
+        let start = std::time::Instant::now();
+        cmd.execute_batch_with_client(&client, &asins).await.unwrap();
+
+        assert_eq!(client.product_calls.load(Ordering::SeqCst), 2);
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
     #[tokio::test]
     async fn test_product_command_batch_skips_invalid() {
         let html = make_product_html("Test Product", 19.99);
@@ -312,6 +739,122 @@ mod tests {
         // Invalid ASIN should be skipped, others processed
     }
 
+    #[tokio::test]
+    async fn test_product_command_batch_reports_progress() {
+        let html = make_product_html("Test Product", 19.99);
+        let client = MockAmazonClient::new(html);
+        let sink = Arc::new(RecordingSink::default());
+        let cmd = ProductCommand::new(make_test_config()).with_progress(sink.clone());
+
+        let asins = vec!["B08N5WRWNW".to_string(), "SHORT".to_string(), "B08N5WRWNX".to_string()];
+        cmd.execute_batch_with_client(&client, &asins).await.unwrap();
+
+        assert_eq!(
+            *sink.0.lock().unwrap(),
+            vec![
+                Progress::Asin { done: 1, total: 3 },
+                Progress::Asin { done: 2, total: 3 },
+                Progress::Asin { done: 3, total: 3 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_product_filters_frequently_returned() {
+        let html = make_product_html("Test Product", 19.99).replace(
+            "</body>",
+            r#"<div id="frequentlyReturnedBadge_feature_div">
+                <span class="a-text-bold">Frequently returned item</span>
+            </div></body>"#,
+        );
+        let client = Arc::new(MockAmazonClient::new(html));
+        let config = Config { exclude_frequently_returned: true, ..make_test_config() };
+        let cmd = ProductCommand::new(config);
+        let asins = vec!["B08N5WRWNW".to_string(), "B08N5WRWNX".to_string()];
+
+        let output = cmd.execute_with_client(client.as_ref(), "B08N5WRWNW").await.unwrap();
+        assert_eq!(output.trim(), "No products found.");
+        let output = cmd.execute_batch_with_client(client.as_ref(), &asins).await.unwrap();
+        assert_eq!(output.trim(), "No products found.");
+
+        let temp = tempfile::tempdir().unwrap();
+        let written = cmd.execute_batch_to_dir_with_client(client, &asins, temp.path()).await;
+        assert!(written.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_product_filters_subscribe_discount() {
+        // $17.00 with Subscribe & Save is 15% off
+        let html = make_product_html("Coffee Pods", 20.0).replace(
+            "</body>",
+            r#"<div id="snsAccordionRowMiddle">
+                <span class="a-price"><span class="a-offscreen">$17.00</span></span>
+            </div></body>"#,
+        );
+        let client = MockAmazonClient::new(html);
+        let asins = vec!["B08N5WRWNW".to_string()];
+
+        let config = Config { min_subscribe_discount: Some(10), ..make_test_config() };
+        let output = ProductCommand::new(config).execute_batch_with_client(&client, &asins).await;
+        assert!(output.unwrap().contains("Coffee Pods"));
+
+        let config = Config { min_subscribe_discount: Some(20), ..make_test_config() };
+        let output = ProductCommand::new(config).execute_batch_with_client(&client, &asins).await;
+        assert_eq!(output.unwrap().trim(), "No products found.");
+    }
+
+    #[tokio::test]
+    async fn test_product_command_batch_to_dir() {
+        let html = make_product_html("Test Product", 19.99);
+        let client = MockAmazonClient::new(html);
+        let mut config = make_test_config();
+        config.format = OutputFormat::Json;
+        let cmd = ProductCommand::new(config);
+
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("products");
+        let asins = vec!["B08N5WRWNW".to_string(), "b08n5wrwnx".to_string()];
+
+        let written =
+            cmd.execute_batch_to_dir_with_client(Arc::new(client), &asins, &dir).await.unwrap();
+        assert_eq!(written, vec![dir.join("B08N5WRWNW.json"), dir.join("B08N5WRWNX.json")]);
+
+        for path in &written {
+            let content = fs::read_to_string(path).unwrap();
+            assert!(content.starts_with('{'));
+            assert!(content.contains("Test Product"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_to_dir_concurrent() {
+        let client = Arc::new(MockAmazonClient::new(make_product_html("Test Product", 19.99)));
+        let mut config = make_test_config();
+        config.format = OutputFormat::Json;
+        config.batch_concurrency = 3;
+        let cmd = ProductCommand::new(config);
+
+        let temp = tempfile::tempdir().unwrap();
+        let asins: Vec<String> = (1..=5).map(|i| format!("B00000000{}", i)).collect();
+        let mut all = asins.clone();
+        all.insert(2, "bad".to_string());
+
+        let written = cmd
+            .execute_batch_to_dir_with_client(Arc::clone(&client), &all, temp.path())
+            .await
+            .unwrap();
+
+        let expected: Vec<_> =
+            asins.iter().map(|a| temp.path().join(format!("{}.json", a))).collect();
+        assert_eq!(written, expected);
+        assert_eq!(client.product_calls.load(Ordering::SeqCst), 5);
+        for path in &written {
+            let product: Product =
+                serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+            assert_eq!(product.title, "Test Product");
+        }
+    }
+
     #[tokio::test]
     async fn test_product_command_network_error() {
         let client = MockAmazonClient::failing();
@@ -322,4 +865,185 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("network error"));
     }
+
+    #[tokio::test]
+    async fn test_batch_fail_fast_on_captcha() {
+        let captcha = r#"<html><body><form action="/errors/validateCaptcha"></form></body></html>"#;
+        let asins: Vec<String> =
+            vec!["B000000001".into(), "B000000002".into(), "B000000003".into()];
+
+        // Default: log and keep going
+        let client = MockAmazonClient::new(captcha.to_string());
+        let cmd = ProductCommand::new(make_test_config());
+        let output = cmd.execute_batch_with_client(&client, &asins).await.unwrap();
+        assert!(output.contains("No products found"));
+        assert_eq!(client.product_calls.load(Ordering::SeqCst), 3);
+
+        // Fail fast: abort on the first CAPTCHA
+        let client = MockAmazonClient::new(captcha.to_string());
+        let mut config = make_test_config();
+        config.fail_fast = true;
+        let cmd = ProductCommand::new(config);
+        let err = cmd.execute_batch_with_client(&client, &asins).await.unwrap_err();
+        assert_eq!(BlockedError::find(&err), Some(BlockedError::Captcha));
+        assert!(err.to_string().contains("Aborted batch at B000000001 (0 of 3 ASINs done)"));
+        assert_eq!(client.product_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_fail_fast_logs_unattempted() {
+        let captcha = r#"<html><body><form action="/errors/validateCaptcha"></form></body></html>"#;
+        let asins: Vec<String> =
+            vec!["B000000001".into(), "b000000002".into(), "B000000003".into()];
+        let config = Config { fail_fast: true, ..make_test_config() };
+        let dir = tempfile::tempdir().unwrap();
+
+        let log = dir.path().join("failed.tsv");
+        let client = MockAmazonClient::new(captcha.to_string());
+        let cmd = ProductCommand::new(config.clone()).with_error_log(&log);
+        assert!(cmd.execute_batch_with_client(&client, &asins).await.is_err());
+
+        let contents = std::fs::read_to_string(&log).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("B000000001\tparse failed:"));
+        assert_eq!(lines[1], "B000000002\tnot attempted (batch aborted)");
+        assert_eq!(lines[2], "B000000003\tnot attempted (batch aborted)");
+
+        // Same with --output-dir, where lookups can be in flight on abort
+        let log = dir.path().join("failed-dir.tsv");
+        let client = Arc::new(MockAmazonClient::new(captcha.to_string()));
+        let cmd = ProductCommand::new(config).with_error_log(&log);
+        let out = dir.path().join("out");
+        assert!(cmd.execute_batch_to_dir_with_client(client, &asins, &out).await.is_err());
+        assert_eq!(read_error_log(&log).unwrap(), vec!["B000000001", "B000000002", "B000000003"]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_fail_fast_ignores_other_errors() {
+        let client = MockAmazonClient::failing();
+        let mut config = make_test_config();
+        config.fail_fast = true;
+        let cmd = ProductCommand::new(config);
+
+        let asins: Vec<String> = vec!["B000000001".into(), "B000000002".into()];
+        let output = cmd.execute_batch_with_client(&client, &asins).await.unwrap();
+        assert!(output.contains("No products found"));
+        assert_eq!(client.product_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_error_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("failed.tsv");
+        std::fs::write(&log, "B0EARLIER1\tfetch failed: earlier run\n").unwrap();
+
+        let client = MockAmazonClient::failing();
+        let cmd = ProductCommand::new(make_test_config()).with_error_log(&log);
+        let asins: Vec<String> = vec!["B000000001".into(), "bad".into()];
+        cmd.execute_batch_with_client(&client, &asins).await.unwrap();
+
+        // Appended after the earlier run's entries
+        let contents = std::fs::read_to_string(&log).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "B0EARLIER1\tfetch failed: earlier run",
+                "B000000001\tfetch failed: Simulated network error",
+                "BAD\tinvalid ASIN",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_clears_recovered_asins() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("failed.tsv");
+        let asins: Vec<String> = vec!["B000000001".into()];
+
+        // First run fails and logs the ASIN
+        let cmd = ProductCommand::new(make_test_config()).with_error_log(&log);
+        cmd.execute_batch_with_client(&MockAmazonClient::failing(), &asins).await.unwrap();
+        assert_eq!(read_error_log(&log).unwrap(), asins);
+
+        // Retry succeeds, so the log is left empty
+        let client = MockAmazonClient::new(make_product_html("Recovered Widget", 19.99));
+        let output = ProductCommand::new(make_test_config())
+            .execute_retry_with_client(&client, &log)
+            .await
+            .unwrap();
+
+        assert!(output.contains("Recovered Widget"));
+        assert_eq!(std::fs::read_to_string(&log).unwrap(), "");
+        assert!(ProductCommand::new(make_test_config())
+            .execute_retry_with_client(&client, &log)
+            .await
+            .is_err());
+    }
+
+    /// Client that checks the error log still lists its ASIN while retrying.
+    struct LogCheckingClient {
+        log: PathBuf,
+    }
+
+    #[async_trait]
+    impl AmazonSearch for LogCheckingClient {
+        async fn search(&self, _query: &str, _page: u32) -> Result<String> {
+            Ok("<html></html>".to_string())
+        }
+
+        async fn search_url(&self, _url: &str) -> Result<String> {
+            Ok("<html></html>".to_string())
+        }
+
+        async fn product(&self, asin: &str) -> Result<String> {
+            assert!(read_error_log(&self.log).unwrap().contains(&asin.to_string()));
+            anyhow::bail!("Simulated network error")
+        }
+
+        fn region(&self) -> Region {
+            Region::Us
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_keeps_log_until_done() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("failed.tsv");
+        std::fs::write(&log, "B000000001\tfetch failed\nB000000002\tfetch failed\n").unwrap();
+
+        let client = LogCheckingClient { log: log.clone() };
+        ProductCommand::new(make_test_config())
+            .execute_retry_with_client(&client, &log)
+            .await
+            .unwrap();
+
+        assert_eq!(read_error_log(&log).unwrap(), vec!["B000000001", "B000000002"]);
+        assert!(!dir.path().join("failed.tsv.retry").exists());
+
+        // A fail-fast abort replaces the log with the aborted and unattempted ASINs
+        let captcha = r#"<html><body><form action="/errors/validateCaptcha"></form></body></html>"#;
+        let client = MockAmazonClient::new(captcha.to_string());
+        let config = Config { fail_fast: true, ..make_test_config() };
+        assert!(ProductCommand::new(config)
+            .execute_retry_with_client(&client, &log)
+            .await
+            .is_err());
+        assert_eq!(read_error_log(&log).unwrap(), vec!["B000000001", "B000000002"]);
+        assert!(std::fs::read_to_string(&log).unwrap().contains("not attempted"));
+    }
+
+    #[test]
+    fn test_read_error_log_dedupes() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("failed.tsv");
+        std::fs::write(
+            &log,
+            "B000000001\tfetch failed\n\nB000000002\tparse failed\nB000000001\tagain\n",
+        )
+        .unwrap();
+
+        assert_eq!(read_error_log(&log).unwrap(), vec!["B000000001", "B000000002"]);
+    }
 }
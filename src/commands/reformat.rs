@@ -0,0 +1,162 @@
+//! Re-format command implementation for previously saved JSON output.
+
+use crate::amazon::Product;
+use crate::config::Config;
+use crate::filters::FilterChainBuilder;
+use crate::format::Formatter;
+use anyhow::{Context, Result};
+use std::path::Path;
+use tracing::{debug, info};
+
+/// Parses saved JSON output: an array of products or a single product.
+pub(crate) fn parse_products_json(json: &str) -> Result<Vec<Product>> {
+    match serde_json::from_str::<Vec<Product>>(json) {
+        Ok(products) => Ok(products),
+        Err(_) => Ok(vec![serde_json::from_str::<Product>(json)
+            .context("Input is not a JSON product or array of products")?]),
+    }
+}
+
+/// Re-renders products from a JSON scrape without fetching anything.
+pub struct ReformatCommand {
+    config: Config,
+}
+
+impl ReformatCommand {
+    /// Creates a new re-format command.
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Reads products from a JSON file and returns formatted output.
+    pub fn execute(&self, path: &Path) -> Result<String> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read input file: {}", path.display()))?;
+
+        self.execute_with_json(&json)
+            .with_context(|| format!("Failed to re-format {}", path.display()))
+    }
+
+    /// Formats products from a JSON string (an array or a single product).
+    pub fn execute_with_json(&self, json: &str) -> Result<String> {
+        let products = parse_products_json(json)?;
+
+        info!("Loaded {} products from JSON", products.len());
+
+        let filters = FilterChainBuilder::from_config(&self.config).build();
+        if !filters.is_empty() {
+            debug!("Active filters: {}", filters.descriptions().join(", "));
+        }
+
+        let products = filters.apply(products);
+
+        let formatter = Formatter::from_config(&self.config);
+        Ok(formatter.format_products(&products))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amazon::models::{Price, Rating};
+    use crate::config::OutputFormat;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn make_product(asin: &str, title: &str, price: f64) -> Product {
+        Product {
+            asin: asin.to_string(),
+            title: title.to_string(),
+            url: format!("https://www.amazon.com/dp/{}", asin),
+            image_url: None,
+            price: Some(Price::simple(price, "USD")),
+            rating: Some(Rating::new(4.5, 100)),
+            is_sponsored: false,
+            is_prime: true,
+            is_amazon_choice: false,
+            in_stock: true,
+            brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
+        }
+    }
+
+    fn make_config(format: OutputFormat) -> Config {
+        Config { format, ..Config::default() }
+    }
+
+    #[test]
+    fn test_reformat_file_to_csv() {
+        let products = vec![
+            make_product("B000000001", "First Product", 19.99),
+            make_product("B000000002", "Second, Product", 29.99),
+        ];
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", serde_json::to_string(&products).unwrap()).unwrap();
+
+        let cmd = ReformatCommand::new(make_config(OutputFormat::Csv));
+        let output = cmd.execute(file.path()).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("asin,title,price"));
+        assert!(lines[1].starts_with("B000000001,First Product,19.99,"));
+        assert!(lines[2].starts_with("B000000002,\"Second, Product\",29.99,"));
+    }
+
+    #[test]
+    fn test_reformat_applies_filters() {
+        let products = vec![
+            make_product("B000000001", "Cheap", 5.0),
+            make_product("B000000002", "Pricey", 50.0),
+        ];
+        let json = serde_json::to_string(&products).unwrap();
+
+        let mut config = make_config(OutputFormat::Csv);
+        config.min_price = Some(10.0);
+
+        let output = ReformatCommand::new(config).execute_with_json(&json).unwrap();
+        assert!(!output.contains("B000000001"));
+        assert!(output.contains("B000000002"));
+    }
+
+    #[test]
+    fn test_reformat_single_product_object() {
+        let json = serde_json::to_string(&make_product("B000000003", "Solo", 9.99)).unwrap();
+
+        let cmd = ReformatCommand::new(make_config(OutputFormat::Table));
+        let output = cmd.execute_with_json(&json).unwrap();
+        assert!(output.contains("B000000003"));
+        assert!(output.contains("Total: 1 products"));
+    }
+
+    #[test]
+    fn test_reformat_invalid_json() {
+        let cmd = ReformatCommand::new(make_config(OutputFormat::Json));
+        let result = cmd.execute_with_json("not json");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reformat_missing_file() {
+        let cmd = ReformatCommand::new(make_config(OutputFormat::Json));
+        let result = cmd.execute(Path::new("/nonexistent/products.json"));
+        assert!(result.unwrap_err().to_string().contains("Failed to read input file"));
+    }
+}
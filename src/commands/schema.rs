@@ -0,0 +1,130 @@
+//! JSON Schema export for `--format json` output.
+
+use crate::amazon::Product;
+use anyhow::Result;
+use std::fmt;
+use std::str::FromStr;
+
+/// Which JSON output shape to describe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchemaKind {
+    /// A product, as emitted (one per array element) by search, product,
+    /// bestsellers and format
+    #[default]
+    Product,
+    /// A price comparison, as emitted by compare
+    #[cfg(feature = "tropical")]
+    Comparison,
+}
+
+impl FromStr for SchemaKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "product" | "products" => Ok(SchemaKind::Product),
+            #[cfg(feature = "tropical")]
+            "comparison" | "compare" => Ok(SchemaKind::Comparison),
+            _ => Err(format!("Unknown schema: {}. Use: {}", s, SchemaKind::NAMES)),
+        }
+    }
+}
+
+impl fmt::Display for SchemaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaKind::Product => write!(f, "product"),
+            #[cfg(feature = "tropical")]
+            SchemaKind::Comparison => write!(f, "comparison"),
+        }
+    }
+}
+
+impl SchemaKind {
+    #[cfg(not(feature = "tropical"))]
+    const NAMES: &'static str = "product";
+    #[cfg(feature = "tropical")]
+    const NAMES: &'static str = "product, comparison";
+}
+
+/// Prints the JSON Schema generated from the output models.
+pub struct SchemaCommand {
+    kind: SchemaKind,
+}
+
+impl SchemaCommand {
+    /// Creates a schema command for `kind`.
+    pub fn new(kind: SchemaKind) -> Self {
+        Self { kind }
+    }
+
+    /// Returns the schema as pretty-printed JSON.
+    pub fn execute(&self) -> Result<String> {
+        let schema = match self.kind {
+            SchemaKind::Product => schemars::schema_for!(Product),
+            #[cfg(feature = "tropical")]
+            SchemaKind::Comparison => schemars::schema_for!(crate::tropical::PriceComparison),
+        };
+        Ok(serde_json::to_string_pretty(&schema)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product_schema() -> serde_json::Value {
+        let output = SchemaCommand::new(SchemaKind::Product).execute().unwrap();
+        serde_json::from_str(&output).unwrap()
+    }
+
+    #[test]
+    fn test_product_schema_required_and_optional_fields() {
+        let schema = product_schema();
+        let required: Vec<&str> =
+            schema["required"].as_array().unwrap().iter().filter_map(|v| v.as_str()).collect();
+
+        assert!(required.contains(&"asin"));
+        assert!(required.contains(&"title"));
+        for optional in ["price", "rating", "brand", "coupon_code", "rank", "related_asins"] {
+            assert!(schema["properties"].get(optional).is_some(), "missing {}", optional);
+            assert!(!required.contains(&optional), "{} should be optional", optional);
+        }
+    }
+
+    #[test]
+    fn test_product_schema_covers_serialized_fields() {
+        let product: Product = serde_json::from_value(serde_json::json!({
+            "asin": "B08N5WRWNW",
+            "title": "Echo Dot",
+            "url": "https://www.amazon.com/dp/B08N5WRWNW",
+            "is_sponsored": false,
+            "is_prime": true,
+            "is_amazon_choice": false,
+            "in_stock": true,
+        }))
+        .unwrap();
+        let schema = product_schema();
+
+        let serialized = serde_json::to_value(&product).unwrap();
+        for field in serialized.as_object().unwrap().keys() {
+            assert!(schema["properties"].get(field).is_some(), "schema lacks {}", field);
+        }
+    }
+
+    #[cfg(feature = "tropical")]
+    #[test]
+    fn test_comparison_schema() {
+        let output = SchemaCommand::new("compare".parse().unwrap()).execute().unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert!(schema["properties"].get("prices").is_some());
+        assert!(schema["required"].as_array().unwrap().contains(&"asin".into()));
+    }
+
+    #[test]
+    fn test_schema_kind_from_str() {
+        assert_eq!("Products".parse::<SchemaKind>(), Ok(SchemaKind::Product));
+        assert!("nope".parse::<SchemaKind>().is_err());
+    }
+}
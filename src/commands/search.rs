@@ -1,21 +1,82 @@
 //! Search command implementation.
 
-use crate::amazon::{AmazonClient, AmazonSearch, Parser, Product};
-use crate::config::Config;
-use crate::filters::FilterChainBuilder;
-use crate::format::Formatter;
+use crate::amazon::models::RESULTS_PER_PAGE;
+use crate::amazon::{AmazonClient, AmazonSearch, Parser, Product, Region};
+use crate::config::{Config, DedupBy, OutputFormat, PageCapAction, SortKey};
+use crate::filters::{Filter, FilterChainBuilder};
+use crate::format::{format_number, Formatter};
+use crate::progress::{NoProgress, Progress, ProgressSink};
 use anyhow::{Context, Result};
-use tracing::{debug, info};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{debug, info, warn};
+
+/// Safety limit on result pages fetched per search.
+pub const MAX_SEARCH_PAGES: u32 = 10;
+
+/// Statistics on how a search arrived at its results.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SearchReport {
+    /// Number of result pages requested
+    pub pages_fetched: u32,
+    /// Products parsed across all pages, before filtering
+    pub products_seen: usize,
+    /// Products removed by the filter chain
+    pub products_filtered_out: usize,
+    /// Whether the page safety limit stopped the search short of `max_results`
+    pub hit_page_cap: bool,
+    /// Page on which a CAPTCHA stopped the search, if any
+    pub captcha_page: Option<u32>,
+    /// Times each ASIN appeared across fetched pages, before filtering, in
+    /// first-seen order
+    pub asin_counts: Vec<(String, usize)>,
+    /// Total result count reported on the first page, if shown
+    pub total_results: Option<u32>,
+}
+
+impl SearchReport {
+    /// Returns the ASIN counts, most frequent first (ties keep first-seen order).
+    pub fn asin_frequencies(&self) -> Vec<(String, usize)> {
+        let mut counts = self.asin_counts.clone();
+        counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        counts
+    }
+
+    fn count_asin(&mut self, asin: &str) {
+        match self.asin_counts.iter_mut().find(|(seen, _)| seen == asin) {
+            Some((_, count)) => *count += 1,
+            None => self.asin_counts.push((asin.to_string(), 1)),
+        }
+    }
+}
 
 /// Executes a product search.
 pub struct SearchCommand {
     config: Config,
+    progress: Arc<dyn ProgressSink>,
+    /// Filters appended to the configured chain (shared with region searches)
+    extra_filters: Vec<Arc<dyn Filter>>,
 }
 
 impl SearchCommand {
     /// Creates a new search command.
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self { config, progress: Arc::new(NoProgress), extra_filters: Vec::new() }
+    }
+
+    /// Adds a filter on top of the ones configured in `config`, e.g.
+    /// `.with_filter(|p: &Product| p.brand.is_some())`.
+    pub fn with_filter(mut self, filter: impl Filter + 'static) -> Self {
+        self.extra_filters.push(Arc::new(filter));
+        self
+    }
+
+    /// Sets where per-page progress updates are reported.
+    pub fn with_progress(mut self, progress: Arc<dyn ProgressSink>) -> Self {
+        self.progress = progress;
+        self
     }
 
     /// Executes the search and returns formatted output.
@@ -32,18 +93,169 @@ impl SearchCommand {
         client: &impl AmazonSearch,
         query: &str,
     ) -> Result<String> {
+        let (products, report) = self.search_with_report(client, query).await?;
+
+        if let Some(page) = report.captcha_page {
+            warn!("CAPTCHA on page {}, returning {} products", page, products.len());
+        }
+
+        // Format output
+        let formatter = Formatter::from_config(&self.config);
+        let output = formatter.format_products(&products);
+        match report.total_results {
+            Some(total) if self.config.show_total_results => self.with_total_results(output, total),
+            _ => Ok(output),
+        }
+    }
+
+    /// Adds the total result count to formatted output: a header line for
+    /// table/markdown, or an object wrapping the product array for JSON. CSV
+    /// is left as is so its first line stays the column header.
+    fn with_total_results(&self, output: String, total: u32) -> Result<String> {
+        let popular = total >= self.config.popular_query_min;
+        match self.config.format {
+            OutputFormat::Json => {
+                let newline = if output.ends_with('\n') { "\n" } else { "" };
+                let products: serde_json::Value = serde_json::from_str(&output)?;
+                let wrapped = serde_json::json!({
+                    "total_results": total,
+                    "popular": popular,
+                    "products": products,
+                });
+                Ok(format!("{}{}", serde_json::to_string_pretty(&wrapped)?, newline))
+            }
+            OutputFormat::Csv => {
+                info!("About {} results", total);
+                Ok(output)
+            }
+            OutputFormat::Table | OutputFormat::Markdown => {
+                let mut header = format!("About {} results", format_number(total as f64, 0, false));
+                if popular {
+                    header.push_str(" (popular query)");
+                }
+                Ok(format!("{}\n\n{}", header, output))
+            }
+        }
+    }
+
+    /// Runs the search and returns how often each ASIN appeared across the
+    /// fetched pages, most frequent first, instead of the products.
+    pub async fn execute_asin_report(&self, query: &str) -> Result<String> {
+        let client =
+            AmazonClient::new(&self.config).await.context("Failed to create HTTP client")?;
+
+        self.asin_report_with_client(&client, query).await
+    }
+
+    /// Builds the ASIN frequency report with a provided client (for testing).
+    pub async fn asin_report_with_client(
+        &self,
+        client: &impl AmazonSearch,
+        query: &str,
+    ) -> Result<String> {
+        let (_, report) = self.search_with_report(client, query).await?;
+        Ok(format_asin_report(&report.asin_frequencies(), self.config.format))
+    }
+
+    /// Searches several regions concurrently and returns their products
+    /// concatenated in the order the regions were listed.
+    pub async fn execute_regions(&self, regions: &[Region], query: &str) -> Result<String> {
+        let mut clients = Vec::with_capacity(regions.len());
+        for &region in regions {
+            let config = self.region_config(region);
+            let client = AmazonClient::new(&config)
+                .await
+                .with_context(|| format!("Failed to create HTTP client for {}", region))?;
+            clients.push(Arc::new(client));
+        }
+
+        let results = self.search_regions_with_clients(clients, query).await?;
+        let products: Vec<Product> =
+            results.into_iter().flat_map(|(_, products)| products).collect();
+
+        let formatter = Formatter::from_config(&self.config);
+        Ok(formatter.format_products(&products))
+    }
+
+    /// Searches each client's region concurrently (for testing with mock clients).
+    ///
+    /// At most `max_concurrent_regions` regions are searched at once. Results
+    /// are returned in the order of `clients`, however the requests finish. A
+    /// region that fails is skipped with a warning; it is an error only if
+    /// every region fails.
+    pub async fn search_regions_with_clients<C: AmazonSearch + 'static>(
+        &self,
+        clients: Vec<Arc<C>>,
+        query: &str,
+    ) -> Result<Vec<(Region, Vec<Product>)>> {
+        let total = clients.len();
+        let limit = self.config.max_concurrent_regions.max(1);
+        let mut tasks = JoinSet::new();
+        let mut results = Vec::with_capacity(total);
+
+        for (index, client) in clients.into_iter().enumerate() {
+            // Keep at most `limit` regions in flight
+            if tasks.len() >= limit {
+                if let Some(joined) = tasks.join_next().await {
+                    collect_region(joined.context("Region search task failed")?, &mut results);
+                }
+            }
+
+            let region = client.region();
+            let cmd = SearchCommand {
+                extra_filters: self.extra_filters.clone(),
+                ..SearchCommand::new(self.region_config(region))
+            };
+            let query = query.to_string();
+            tasks.spawn(async move {
+                let result = cmd.search_with_report(&*client, &query).await;
+                (index, region, result)
+            });
+        }
+
+        while let Some(joined) = tasks.join_next().await {
+            collect_region(joined.context("Region search task failed")?, &mut results);
+        }
+
+        if results.is_empty() && total > 0 {
+            anyhow::bail!("Search failed in every region");
+        }
+
+        // Tasks complete in any order; restore the requested region order
+        results.sort_by_key(|(index, _, _)| *index);
+        Ok(results.into_iter().map(|(_, region, products)| (region, products)).collect())
+    }
+
+    /// Config for one region of a multi-region search.
+    ///
+    /// The `currency` and `decimal` overrides describe a single custom
+    /// storefront, so they're dropped: each region parses prices in its own
+    /// currency, and every product (and CSV row) keeps that currency.
+    fn region_config(&self, region: Region) -> Config {
+        Config { region, currency: None, decimal: None, ..self.config.clone() }
+    }
+
+    /// Runs the search and returns the matching products along with a report
+    /// of how they were collected.
+    ///
+    /// A CAPTCHA on the first page is an error; on a later page the search
+    /// stops and returns what was collected so far.
+    pub async fn search_with_report(
+        &self,
+        client: &impl AmazonSearch,
+        query: &str,
+    ) -> Result<(Vec<Product>, SearchReport)> {
         info!("Searching for: {}", query);
 
-        let parser = Parser::new(client.region());
+        let parser = Parser::new(client.region()).with_config(&self.config);
 
         // Build filter chain
-        let filters = FilterChainBuilder::new()
-            .price_range(self.config.min_price, self.config.max_price)
-            .min_rating(self.config.min_rating)
-            .prime_only(self.config.prime_only)
-            .no_sponsored(self.config.no_sponsored)
-            .keywords(self.config.keywords.clone())
-            .exclude_keywords(self.config.exclude_keywords.clone())
+        let filters = self
+            .extra_filters
+            .iter()
+            .fold(FilterChainBuilder::from_config(&self.config), |builder, filter| {
+                builder.custom(SharedFilter(Arc::clone(filter)))
+            })
             .build();
 
         if !filters.is_empty() {
@@ -51,23 +263,54 @@ impl SearchCommand {
         }
 
         let mut all_products: Vec<Product> = Vec::new();
+        let mut seen_keys = HashSet::new();
+        let mut report = SearchReport::default();
         let mut page = 1;
-        let max_pages = 10; // Safety limit
+        let max_pages = MAX_SEARCH_PAGES;
+        let mut next_url: Option<String> = None;
+        let mut last_page: Option<u32> = None;
 
         // Fetch pages until we have enough results
         while all_products.len() < self.config.max_results && page <= max_pages {
             debug!("Fetching page {}", page);
 
-            let html = client.search(query, page).await?;
-            let results = parser.parse_search(&html, query, page)?;
+            let html = match next_url.take() {
+                Some(url) => client.search_url(&url).await?,
+                None => client.search(query, page).await?,
+            };
+            report.pages_fetched += 1;
+
+            let results = match parser.parse_search(&html, query, page) {
+                Ok(results) => results,
+                Err(_) if page > 1 && parser.is_captcha(&html) => {
+                    report.captcha_page = Some(page);
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
 
             if results.is_empty() {
                 debug!("No results on page {}, stopping", page);
                 break;
             }
 
+            // Amazon keeps a "Next" link on some last pages; the result count
+            // tells how many pages there really are
+            if page == 1 {
+                report.total_results = results.total_results;
+                let organic = results.products.iter().filter(|p| !p.is_sponsored).count() as u32;
+                last_page = results.estimated_pages(organic.clamp(1, RESULTS_PER_PAGE));
+            }
+
+            for product in &results.products {
+                report.count_asin(&product.asin);
+            }
+
             // Apply filters
+            let seen = results.products.len();
             let filtered = filters.apply(results.products);
+            report.products_seen += seen;
+            report.products_filtered_out += seen - filtered.len();
             debug!(
                 "Page {} returned {} products ({} after filtering)",
                 page,
@@ -75,24 +318,183 @@ impl SearchCommand {
                 filtered.len()
             );
 
-            all_products.extend(filtered);
+            // Drop listings already collected (sponsored cards repeat across
+            // pages; variants repeat under new ASINs with --dedup-by title)
+            let fresh = filtered
+                .into_iter()
+                .filter(|product| seen_keys.insert(dedup_key(product, self.config.dedup_by)));
+            if self.config.first_match {
+                all_products.extend(fresh.take(1));
+            } else {
+                all_products.extend(fresh);
+            }
+
+            self.progress.report(&Progress::Page {
+                page,
+                max_pages,
+                collected: all_products.len().min(self.config.max_results),
+            });
+
+            if self.config.first_match && !all_products.is_empty() {
+                info!("First match found on page {}: {}", page, all_products[0].asin);
+                break;
+            }
 
             if !results.has_more {
                 debug!("No more pages available");
                 break;
             }
 
+            if let Some(last) = last_page.filter(|&last| page >= last) {
+                debug!("Fetched all {} pages of results", last);
+                break;
+            }
+
+            if self.config.follow_next_link {
+                next_url = results.next_url;
+            }
+
             page += 1;
         }
 
+        self.progress.finish();
+
+        report.hit_page_cap = page > max_pages && all_products.len() < self.config.max_results;
+        if report.hit_page_cap {
+            match self.config.on_page_cap {
+                PageCapAction::Warn => warn!(
+                    "Stopped at the {}-page limit with {} of {} results",
+                    max_pages,
+                    all_products.len(),
+                    self.config.max_results
+                ),
+                PageCapAction::Error => anyhow::bail!(
+                    "Reached the {}-page limit with only {} of {} results",
+                    max_pages,
+                    all_products.len(),
+                    self.config.max_results
+                ),
+                PageCapAction::Ignore => {}
+            }
+        }
+
+        // Sort before truncating so the best matches are kept, and so every
+        // output format sees the same order
+        sort_products(&mut all_products, self.config.sort, query);
+
         // Truncate to max_results
         all_products.truncate(self.config.max_results);
 
         info!("Found {} products matching criteria", all_products.len());
 
-        // Format output
-        let formatter = Formatter::new(self.config.format);
-        Ok(formatter.format_products(&all_products))
+        Ok((all_products, report))
+    }
+}
+
+/// Outcome of one region's search task: (client index, region, result).
+type RegionOutcome = (usize, Region, Result<(Vec<Product>, SearchReport)>);
+
+/// Keeps a finished region's products, or warns and skips it if it failed.
+fn collect_region(outcome: RegionOutcome, results: &mut Vec<(usize, Region, Vec<Product>)>) {
+    let (index, region, result) = outcome;
+    match result {
+        Ok((products, _)) => results.push((index, region, products)),
+        Err(e) => warn!("Search in {} failed: {:#}", region, e),
+    }
+}
+
+/// Renders ASIN counts as JSON, CSV, or an aligned two-column table.
+fn format_asin_report(counts: &[(String, usize)], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => {
+            let rows: Vec<_> = counts
+                .iter()
+                .map(|(asin, count)| serde_json::json!({ "asin": asin, "count": count }))
+                .collect();
+            serde_json::to_string_pretty(&rows).unwrap_or_else(|_| "[]".to_string())
+        }
+        OutputFormat::Csv => std::iter::once("asin,count".to_string())
+            .chain(counts.iter().map(|(asin, count)| format!("{},{}", asin, count)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Table | OutputFormat::Markdown => {
+            let mut lines =
+                vec![format!("{:>5}  {}", "Count", "ASIN"), format!("{:->5}  {:-<10}", "", "")];
+            lines.extend(counts.iter().map(|(asin, count)| format!("{:>5}  {}", count, asin)));
+            lines.push(String::new());
+            lines.push(format!("Total: {} distinct ASINs", counts.len()));
+            lines.join("\n")
+        }
+    }
+}
+
+/// Lets one filter instance sit in the chain of every region's search.
+struct SharedFilter(Arc<dyn Filter>);
+
+impl Filter for SharedFilter {
+    fn matches(&self, product: &Product) -> bool {
+        self.0.matches(product)
+    }
+
+    fn description(&self) -> String {
+        self.0.description()
+    }
+}
+
+/// Key under which `product` counts as a repeat of an earlier result.
+fn dedup_key(product: &Product, by: DedupBy) -> String {
+    match by {
+        DedupBy::Asin => product.asin.clone(),
+        DedupBy::Title => normalize_title(&product.title),
+    }
+}
+
+/// Lowercases a title, collapses whitespace and strips trailing variant
+/// parentheticals, so "Mug (Blue, 12 oz)" and "mug  (Red)" compare equal.
+fn normalize_title(title: &str) -> String {
+    let mut title = title.trim();
+    while let Some(stripped) = title.strip_suffix(')').or_else(|| title.strip_suffix(']')) {
+        let open = if title.ends_with(')') { '(' } else { '[' };
+        match stripped.rfind(open) {
+            Some(start) => title = stripped[..start].trim_end_matches([' ', ',', '-']),
+            None => break,
+        }
+    }
+    title.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Orders products by `sort`, stably so ties keep Amazon's order.
+///
+/// Amazon's sorted results still interleave sponsored and out-of-order
+/// listings across pages, so price and rating sorts are re-applied locally.
+/// Products without a visible price (or rating) go last. `Featured` and
+/// `Newest` keep Amazon's order, since the page has no listing dates.
+fn sort_products(products: &mut [Product], sort: SortKey, query: &str) {
+    let by_price =
+        |a: &Product, b: &Product, desc: bool| match (a.current_price(), b.current_price()) {
+            (Some(x), Some(y)) if desc => y.total_cmp(&x),
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        };
+
+    match sort {
+        SortKey::Featured | SortKey::Newest => {}
+        SortKey::Relevance => {
+            products.sort_by(|a, b| b.relevance(query).total_cmp(&a.relevance(query)))
+        }
+        SortKey::PriceAsc => products.sort_by(|a, b| by_price(a, b, false)),
+        SortKey::PriceDesc => products.sort_by(|a, b| by_price(a, b, true)),
+        SortKey::Quality => products.sort_by(|a, b| match (a.quality_score(), b.quality_score()) {
+            (Some(x), Some(y)) => y.total_cmp(&x),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        }),
+        SortKey::Rating => products.sort_by(|a, b| {
+            let key = |p: &Product| p.rating.as_ref().map(|r| (r.stars, r.review_count));
+            match (key(a), key(b)) {
+                (Some((sa, ca)), Some((sb, cb))) => sb.total_cmp(&sa).then(cb.cmp(&ca)),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            }
+        }),
     }
 }
 
@@ -102,15 +504,26 @@ mod tests {
     use crate::amazon::Region;
     use crate::config::OutputFormat;
     use async_trait::async_trait;
-    use std::sync::atomic::{AtomicU32, Ordering};
-    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
 
     /// Mock Amazon client for testing.
     struct MockAmazonClient {
         search_responses: Vec<String>,
         product_responses: Vec<String>,
         search_call_count: Arc<AtomicU32>,
+        followed_urls: Mutex<Vec<String>>,
         region: Region,
+        latency: Duration,
+        in_flight: Option<Arc<InFlight>>,
+    }
+
+    /// Counts searches running at once across several mock clients.
+    #[derive(Default)]
+    struct InFlight {
+        current: AtomicUsize,
+        peak: AtomicUsize,
     }
 
     impl MockAmazonClient {
@@ -119,18 +532,46 @@ mod tests {
                 search_responses,
                 product_responses: Vec::new(),
                 search_call_count: Arc::new(AtomicU32::new(0)),
+                followed_urls: Mutex::new(Vec::new()),
                 region: Region::Us,
+                latency: Duration::ZERO,
+                in_flight: None,
             }
         }
 
+        /// Records this client's searches in the shared `in_flight` counter.
+        fn tracking(mut self, in_flight: Arc<InFlight>) -> Self {
+            self.in_flight = Some(in_flight);
+            self
+        }
+
+        /// Serves from `region`, taking `latency` to answer each search.
+        fn in_region(mut self, region: Region, latency: Duration) -> Self {
+            self.region = region;
+            self.latency = latency;
+            self
+        }
+
         fn call_count(&self) -> u32 {
             self.search_call_count.load(Ordering::SeqCst)
         }
+
+        fn followed_urls(&self) -> Vec<String> {
+            self.followed_urls.lock().unwrap().clone()
+        }
     }
 
     #[async_trait]
     impl AmazonSearch for MockAmazonClient {
         async fn search(&self, _query: &str, page: u32) -> Result<String> {
+            if let Some(in_flight) = &self.in_flight {
+                let now = in_flight.current.fetch_add(1, Ordering::SeqCst) + 1;
+                in_flight.peak.fetch_max(now, Ordering::SeqCst);
+            }
+            tokio::time::sleep(self.latency).await;
+            if let Some(in_flight) = &self.in_flight {
+                in_flight.current.fetch_sub(1, Ordering::SeqCst);
+            }
             self.search_call_count.fetch_add(1, Ordering::SeqCst);
             let idx = (page - 1) as usize;
             if idx < self.search_responses.len() {
@@ -140,6 +581,13 @@ mod tests {
             }
         }
 
+        async fn search_url(&self, url: &str) -> Result<String> {
+            // Serve the page after the last one fetched
+            let idx = self.search_call_count.fetch_add(1, Ordering::SeqCst) as usize;
+            self.followed_urls.lock().unwrap().push(url.to_string());
+            Ok(self.search_responses.get(idx).cloned().unwrap_or_else(|| "<html></html>".into()))
+        }
+
         async fn product(&self, _asin: &str) -> Result<String> {
             if !self.product_responses.is_empty() {
                 Ok(self.product_responses[0].clone())
@@ -153,21 +601,92 @@ mod tests {
         }
     }
 
+    /// Progress sink that records every update.
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<Progress>>);
+
+    impl ProgressSink for RecordingSink {
+        fn report(&self, progress: &Progress) {
+            self.0.lock().unwrap().push(progress.clone());
+        }
+    }
+
     fn make_test_config() -> Config {
         Config {
             region: Region::Us,
             proxy: None,
+            proxy_user: None,
+            proxy_pass: None,
+            proxies: Vec::new(),
+            retry_captcha_with_proxy: false,
             delay_ms: 0,
             delay_jitter_ms: 0,
+            page_delay_ms: None,
+            asin_delay_ms: None,
+            rng_seed: None,
+            retry_base_ms: 5000,
+            retry_multiplier: 2.0,
+            retry_max_ms: 60000,
+            retry_jitter_ms: 0,
+            max_retries: 1,
+            cache_dir: None,
+            cache_compress: true,
+            cache_ttl_hours: 24,
+            fingerprint_url: None,
             max_results: 5,
             format: OutputFormat::Table,
             min_price: None,
             max_price: None,
             min_rating: None,
+            min_reviews: None,
+            quality: None,
+            min_energy_class: None,
+            min_subscribe_discount: None,
+            max_stock_remaining: None,
             prime_only: false,
             no_sponsored: false,
             keywords: Vec::new(),
             exclude_keywords: Vec::new(),
+            keyword_match_mode: Default::default(),
+            exclude_digital: false,
+            exclude_frequently_returned: false,
+            drop_zero_price: false,
+            include_out_of_stock_price: false,
+            strict_prime: false,
+            strict_parse: false,
+            debug_raw: false,
+            show_url: false,
+            block_phrases: Vec::new(),
+            block_selectors: Vec::new(),
+            first_match: false,
+            dedup_by: DedupBy::Asin,
+            fail_fast: false,
+            sort: SortKey::Featured,
+            on_page_cap: PageCapAction::Warn,
+            follow_next_link: false,
+            show_total_results: false,
+            popular_query_min: 10000,
+            max_concurrent_regions: 3,
+            batch_concurrency: 1,
+            confirm_threshold: 50,
+            price_decimals: None,
+            csv_bom: false,
+            canonical_urls: false,
+            md_table_images: false,
+            md_tasklist: false,
+            minor_units: false,
+            rating_scale: Default::default(),
+            null_value: String::new(),
+            trailing_newline: false,
+            table_sep: None,
+            table_width: None,
+            hyperlinks: false,
+            decimal: None,
+            currency: None,
+            locale_currency: Default::default(),
+            rates_url: None,
+            rates_ttl_hours: 24,
+            presets: Default::default(),
         }
     }
 
@@ -204,6 +723,38 @@ mod tests {
         assert!(output.contains("Product One"));
     }
 
+    #[tokio::test]
+    async fn test_search_total_results_output() {
+        let html = make_search_html(&[("B001", "Product One", 19.99)]).replace(
+            "<body>",
+            r#"<body><div class="a-section a-spacing-small"><span>1-48 of over 12,000 results for "mug"</span></div>"#,
+        );
+        let config = Config { show_total_results: true, ..make_test_config() };
+
+        let client = MockAmazonClient::new(vec![html.clone()]);
+        let output =
+            SearchCommand::new(config.clone()).execute_with_client(&client, "mug").await.unwrap();
+        assert!(output.starts_with("About 12,000 results (popular query)\n\n"));
+        assert!(output.contains("B001"));
+
+        let client = MockAmazonClient::new(vec![html]);
+        let json_config =
+            Config { format: OutputFormat::Json, popular_query_min: 50000, ..config.clone() };
+        let output =
+            SearchCommand::new(json_config).execute_with_client(&client, "mug").await.unwrap();
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value["total_results"], 12000);
+        assert_eq!(value["popular"], false);
+        assert_eq!(value["products"][0]["asin"], "B001");
+
+        // The page doesn't report a count: output is unchanged
+        let client =
+            MockAmazonClient::new(vec![make_search_html(&[("B001", "Product One", 19.99)])]);
+        let output = SearchCommand::new(config).execute_with_client(&client, "mug").await.unwrap();
+        assert!(!output.contains("results"));
+        assert!(output.contains("B001"));
+    }
+
     #[tokio::test]
     async fn test_search_command_empty_results() {
         let client = MockAmazonClient::new(vec!["<html></html>".to_string()]);
@@ -239,6 +790,59 @@ mod tests {
         assert!(!output.contains("B003")); // Too expensive
     }
 
+    #[tokio::test]
+    async fn test_search_command_with_custom_filter() {
+        let html = make_search_html(&[
+            ("B001", "Cheap Product", 9.99),
+            ("B002", "Mid Product", 25.00),
+            ("B003", "Expensive Product", 100.00),
+        ]);
+        let client = MockAmazonClient::new(vec![html]);
+        let config = Config { max_price: Some(50.0), ..make_test_config() };
+
+        let cmd = SearchCommand::new(config).with_filter(|p: &Product| p.asin != "B001");
+        let output = cmd.execute_with_client(&client, "test").await.unwrap();
+
+        // Applied together with the configured price filter
+        assert!(output.contains("B002"));
+        assert!(!output.contains("B001"));
+        assert!(!output.contains("B003"));
+    }
+
+    #[tokio::test]
+    async fn test_search_dedup_by_asin_and_title() {
+        let html = make_search_html(&[
+            ("B001", "Coffee Mug (Blue)", 10.0),
+            ("B002", "coffee  mug (Red, 12 oz)", 11.0),
+            ("B003", "Tea Kettle", 30.0),
+            ("B001", "Coffee Mug (Blue)", 10.0),
+        ]);
+
+        let client = MockAmazonClient::new(vec![html.clone()]);
+        let (products, _) = SearchCommand::new(make_test_config())
+            .search_with_report(&client, "mug")
+            .await
+            .unwrap();
+        let asins: Vec<_> = products.iter().map(|p| p.asin.as_str()).collect();
+        assert_eq!(asins, vec!["B001", "B002", "B003"]);
+
+        let client = MockAmazonClient::new(vec![html]);
+        let mut config = make_test_config();
+        config.dedup_by = DedupBy::Title;
+        let (products, _) =
+            SearchCommand::new(config).search_with_report(&client, "mug").await.unwrap();
+        let asins: Vec<_> = products.iter().map(|p| p.asin.as_str()).collect();
+        assert_eq!(asins, vec!["B001", "B003"]);
+    }
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(normalize_title("  Coffee   Mug (Blue, 12 oz) [2-Pack] "), "coffee mug");
+        assert_eq!(normalize_title("USB-C Cable - (6ft)"), "usb-c cable");
+        assert_eq!(normalize_title("Mug (Blue) Set"), "mug (blue) set");
+        assert_eq!(normalize_title("Broken)"), "broken)");
+    }
+
     #[tokio::test]
     async fn test_search_command_max_results() {
         let html = make_search_html(&[
@@ -305,6 +909,368 @@ mod tests {
         assert!(client.call_count() >= 2);
     }
 
+    #[tokio::test]
+    async fn test_search_stops_after_estimated_last_page() {
+        let next = r#"<a class="s-pagination-next">Next</a></body>"#;
+        let total = r#"<div class="a-section a-spacing-small"><span>1-2 of 4 results</span></div>"#;
+        let page1 = make_search_html(&[("B001", "Product 1", 10.0), ("B002", "Product 2", 20.0)])
+            .replace("</body>", &format!("{}{}", total, next));
+        let page2 = make_search_html(&[("B003", "Product 3", 30.0), ("B004", "Product 4", 40.0)])
+            .replace("</body>", next);
+        let page3 = make_search_html(&[("B005", "Product 5", 50.0)]).replace("</body>", next);
+
+        let client = MockAmazonClient::new(vec![page1, page2, page3]);
+        let mut config = make_test_config();
+        config.max_results = 10;
+
+        let (products, report) =
+            SearchCommand::new(config).search_with_report(&client, "test").await.unwrap();
+        assert_eq!(products.len(), 4);
+        assert_eq!(report.pages_fetched, 2);
+        assert_eq!(client.call_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_search_command_reports_progress_per_page() {
+        let next = r#"<a class="s-pagination-next">Next</a></body>"#;
+        let page1 = make_search_html(&[("B001", "Product 1", 10.0), ("B002", "Product 2", 20.0)])
+            .replace("</body>", next);
+        let page2 = make_search_html(&[("B003", "Product 3", 30.0)]);
+
+        let client = MockAmazonClient::new(vec![page1, page2]);
+        let mut config = make_test_config();
+        config.max_results = 10;
+
+        let sink = Arc::new(RecordingSink::default());
+        let cmd = SearchCommand::new(config).with_progress(sink.clone());
+        cmd.execute_with_client(&client, "test").await.unwrap();
+
+        assert_eq!(
+            *sink.0.lock().unwrap(),
+            vec![
+                Progress::Page { page: 1, max_pages: 10, collected: 2 },
+                Progress::Page { page: 2, max_pages: 10, collected: 3 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_command_follows_next_link() {
+        let next = r#"<a class="s-pagination-next" href="/s?k=test&amp;page=2&amp;qid=1700000000">Next</a></body>"#;
+        let page1 = make_search_html(&[("B001", "Product 1", 10.0)]).replace("</body>", next);
+        let page2 = make_search_html(&[("B002", "Product 2", 20.0)]);
+
+        let mut config = make_test_config();
+        config.max_results = 10;
+        config.follow_next_link = true;
+
+        let client = MockAmazonClient::new(vec![page1.clone(), page2.clone()]);
+        let output = SearchCommand::new(config.clone()).execute_with_client(&client, "test").await;
+
+        assert!(output.unwrap().contains("B002"));
+        assert_eq!(client.followed_urls(), vec!["/s?k=test&page=2&qid=1700000000"]);
+
+        // Without the option, page numbers are used
+        config.follow_next_link = false;
+        let client = MockAmazonClient::new(vec![page1, page2]);
+        SearchCommand::new(config).execute_with_client(&client, "test").await.unwrap();
+        assert!(client.followed_urls().is_empty());
+        assert_eq!(client.call_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_search_command_relevance_sort() {
+        let html = make_search_html(&[
+            ("B001", "Gaming Keyboard", 10.0),
+            ("B002", "Wired Mouse", 20.0),
+            ("B003", "Wireless Gaming Mouse", 30.0),
+        ]);
+
+        let client = MockAmazonClient::new(vec![html]);
+        let mut config = make_test_config();
+        config.max_results = 2;
+        config.sort = SortKey::Relevance;
+        config.format = OutputFormat::Json;
+
+        let output =
+            SearchCommand::new(config).execute_with_client(&client, "wireless mouse").await;
+        let products: Vec<Product> = serde_json::from_str(&output.unwrap()).unwrap();
+
+        let asins: Vec<_> = products.iter().map(|p| p.asin.as_str()).collect();
+        assert_eq!(asins, vec!["B003", "B002"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_command_price_sort_applies_to_json() {
+        let html = make_search_html(&[
+            ("B001", "Mouse A", 30.0),
+            ("B002", "Mouse B", 10.0),
+            ("B003", "Mouse C", 20.0),
+        ]);
+
+        let mut config = make_test_config();
+        config.sort = "price-asc".parse().unwrap();
+        config.format = OutputFormat::Json;
+
+        let client = MockAmazonClient::new(vec![html.clone()]);
+        let output = SearchCommand::new(config.clone()).execute_with_client(&client, "mouse").await;
+        let products: Vec<Product> = serde_json::from_str(&output.unwrap()).unwrap();
+        let asins: Vec<_> = products.iter().map(|p| p.asin.as_str()).collect();
+        assert_eq!(asins, vec!["B002", "B003", "B001"]);
+
+        // Table output lists the same order
+        config.format = OutputFormat::Table;
+        let client = MockAmazonClient::new(vec![html]);
+        let table = SearchCommand::new(config).execute_with_client(&client, "mouse").await.unwrap();
+        let b002 = table.find("B002").unwrap();
+        assert!(
+            b002 < table.find("B003").unwrap()
+                && table.find("B003").unwrap() < table.find("B001").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sort_products_missing_values_last() {
+        use crate::amazon::Rating;
+
+        let html = make_search_html(&[
+            ("A", "Mouse A", 1.0),
+            ("B", "Mouse B", 5.0),
+            ("C", "Mouse C", 9.0),
+        ]);
+        let mut products =
+            Parser::new(Region::Us).parse_search(&html, "mouse", 1).unwrap().products;
+        products[0].price = None;
+        products[0].rating = Some(Rating::new(4.0, 10));
+        products[2].rating = Some(Rating::new(4.0, 99));
+        let asins =
+            |products: &[Product]| products.iter().map(|p| p.asin.clone()).collect::<Vec<_>>();
+
+        sort_products(&mut products, SortKey::PriceDesc, "");
+        assert_eq!(asins(&products), ["C", "B", "A"]);
+        sort_products(&mut products, SortKey::Rating, "");
+        assert_eq!(asins(&products), ["C", "A", "B"]);
+        sort_products(&mut products, SortKey::PriceAsc, "");
+        assert_eq!(asins(&products), ["B", "C", "A"]);
+        sort_products(&mut products, SortKey::Quality, "");
+        assert_eq!(asins(&products), ["C", "A", "B"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_custom_base_url_with_comma_decimals() {
+        use crate::config::DecimalSeparator;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let html = r#"<html><body>
+            <div data-component-type="s-search-result" data-asin="B001">
+                <h2><a href="/dp/B001"><span>Mirror Product</span></a></h2>
+                <span class="a-price"><span class="a-offscreen">1.299,90</span></span>
+            </div>
+        </body></html>"#;
+        Mock::given(method("GET"))
+            .and(path("/s"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(html))
+            .mount(&server)
+            .await;
+
+        let mut config = make_test_config();
+        config.format = OutputFormat::Json;
+        config.decimal = Some(DecimalSeparator::Comma);
+        config.currency = Some("EUR".to_string());
+
+        let client = AmazonClient::with_base_url(&config, Some(server.uri())).await.unwrap();
+        let output = SearchCommand::new(config).execute_with_client(&client, "test").await;
+        let products: Vec<Product> = serde_json::from_str(&output.unwrap()).unwrap();
+
+        let price = products[0].price.as_ref().unwrap();
+        assert_eq!(price.current, 1299.90);
+        assert_eq!(price.currency, "EUR");
+    }
+
+    #[tokio::test]
+    async fn test_search_with_report_counts() {
+        let next = r#"<a class="s-pagination-next">Next</a></body>"#;
+        let page1 = make_search_html(&[
+            ("B001", "Product 1", 5.0),
+            ("B002", "Product 2", 25.0),
+            ("B003", "Product 3", 8.0),
+        ])
+        .replace("</body>", next);
+        let page2 = make_search_html(&[("B004", "Product 4", 30.0), ("B005", "Product 5", 9.0)]);
+
+        let client = MockAmazonClient::new(vec![page1, page2]);
+        let mut config = make_test_config();
+        config.max_results = 10;
+        config.min_price = Some(20.0);
+
+        let cmd = SearchCommand::new(config);
+        let (products, report) = cmd.search_with_report(&client, "test").await.unwrap();
+
+        assert_eq!(products.len(), 2);
+        assert_eq!(
+            report,
+            SearchReport {
+                pages_fetched: 2,
+                products_seen: 5,
+                products_filtered_out: 3,
+                hit_page_cap: false,
+                captcha_page: None,
+                asin_counts: ["B001", "B002", "B003", "B004", "B005"]
+                    .iter()
+                    .map(|asin| (asin.to_string(), 1))
+                    .collect(),
+                total_results: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_asin_report_counts_repeats_across_pages() {
+        let next = r#"<a class="s-pagination-next">Next</a></body>"#;
+        let page1 = make_search_html(&[
+            ("B001", "Sponsored Pick", 5.0),
+            ("B002", "Product 2", 25.0),
+            ("B001", "Sponsored Pick", 5.0),
+        ])
+        .replace("</body>", next);
+        let page2 = make_search_html(&[
+            ("B003", "Product 3", 30.0),
+            ("B002", "Product 2", 25.0),
+            ("B001", "Sponsored Pick", 5.0),
+        ]);
+
+        let client = MockAmazonClient::new(vec![page1, page2]);
+        let config = Config {
+            max_results: 50,
+            min_price: Some(20.0),
+            format: OutputFormat::Json,
+            ..make_test_config()
+        };
+
+        let output = SearchCommand::new(config).asin_report_with_client(&client, "test").await;
+        let rows: serde_json::Value = serde_json::from_str(&output.unwrap()).unwrap();
+
+        // Counted before filtering, so the filtered-out B001 is still reported
+        assert_eq!(
+            rows,
+            serde_json::json!([
+                { "asin": "B001", "count": 3 },
+                { "asin": "B002", "count": 2 },
+                { "asin": "B003", "count": 1 },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_format_asin_report_table() {
+        let counts = vec![("B001".to_string(), 3), ("B002".to_string(), 1)];
+        let output = format_asin_report(&counts, OutputFormat::Table);
+
+        assert!(output.contains("    3  B001\n    1  B002"));
+        assert!(output.ends_with("Total: 2 distinct ASINs"));
+        assert_eq!(format_asin_report(&counts, OutputFormat::Csv), "asin,count\nB001,3\nB002,1");
+    }
+
+    #[tokio::test]
+    async fn test_search_with_report_captcha_on_later_page() {
+        let next = r#"<a class="s-pagination-next">Next</a></body>"#;
+        let page1 = make_search_html(&[("B001", "Product 1", 10.0)]).replace("</body>", next);
+        let captcha = r#"<html><body><form action="/errors/validateCaptcha"></form></body></html>"#;
+
+        let client = MockAmazonClient::new(vec![page1, captcha.to_string()]);
+        let mut config = make_test_config();
+        config.max_results = 10;
+
+        let cmd = SearchCommand::new(config);
+        let (products, report) = cmd.search_with_report(&client, "test").await.unwrap();
+
+        assert_eq!(products.len(), 1);
+        assert_eq!(report.pages_fetched, 2);
+        assert_eq!(report.captcha_page, Some(2));
+
+        // A CAPTCHA on the first page is still an error
+        let client = MockAmazonClient::new(vec![captcha.to_string()]);
+        assert!(cmd.search_with_report(&client, "test").await.is_err());
+    }
+
+    fn capped_pages() -> Vec<String> {
+        let next = r#"<a class="s-pagination-next">Next</a></body>"#;
+        (0..12)
+            .map(|i| {
+                make_search_html(&[(&format!("B{:03}", i), "Product", 10.0)])
+                    .replace("</body>", next)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_search_with_report_hit_page_cap() {
+        let client = MockAmazonClient::new(capped_pages());
+        let mut config = make_test_config();
+        config.max_results = 50;
+
+        let cmd = SearchCommand::new(config);
+        let (products, report) = cmd.search_with_report(&client, "test").await.unwrap();
+
+        assert_eq!(products.len(), 10);
+        assert_eq!(report.pages_fetched, 10);
+        assert!(report.hit_page_cap);
+    }
+
+    #[tokio::test]
+    async fn test_search_on_page_cap_modes() {
+        let mut config = make_test_config();
+        config.max_results = 50;
+
+        for action in [PageCapAction::Warn, PageCapAction::Ignore] {
+            config.on_page_cap = action;
+            let client = MockAmazonClient::new(capped_pages());
+            let cmd = SearchCommand::new(config.clone());
+            let (products, report) = cmd.search_with_report(&client, "test").await.unwrap();
+            assert_eq!(products.len(), 10, "{}", action);
+            assert!(report.hit_page_cap, "{}", action);
+        }
+
+        config.on_page_cap = PageCapAction::Error;
+        let client = MockAmazonClient::new(capped_pages());
+        let err = SearchCommand::new(config.clone()).execute_with_client(&client, "test").await;
+        assert!(err.unwrap_err().to_string().contains("10-page limit with only 10 of 50"));
+
+        // Filling max_results on the last page isn't a cap hit
+        config.max_results = 10;
+        let client = MockAmazonClient::new(capped_pages());
+        let cmd = SearchCommand::new(config);
+        let (_, report) = cmd.search_with_report(&client, "test").await.unwrap();
+        assert!(!report.hit_page_cap);
+    }
+
+    #[tokio::test]
+    async fn test_search_command_first_match_stops_early() {
+        let next = r#"<a class="s-pagination-next">Next</a></body>"#;
+        let page1 = make_search_html(&[("B001", "Product 1", 5.0), ("B002", "Product 2", 8.0)])
+            .replace("</body>", next);
+        let page2 = make_search_html(&[("B003", "Product 3", 9.0), ("B004", "Product 4", 25.0)])
+            .replace("</body>", next);
+        let page3 = make_search_html(&[("B005", "Product 5", 30.0)]);
+
+        let client = MockAmazonClient::new(vec![page1, page2, page3]);
+        let mut config = make_test_config();
+        config.max_results = 10;
+        config.min_price = Some(20.0);
+        config.first_match = true;
+
+        let cmd = SearchCommand::new(config);
+        let output = cmd.execute_with_client(&client, "test").await.unwrap();
+
+        // Match is on page 2, so page 3 is never requested
+        assert_eq!(client.call_count(), 2);
+        assert!(output.contains("B004"));
+        assert!(!output.contains("B005"));
+        assert!(output.contains("Total: 1 products"));
+    }
+
     #[tokio::test]
     async fn test_search_command_keyword_filter() {
         let html = make_search_html(&[
@@ -348,4 +1314,97 @@ mod tests {
         assert!(!output.contains("B002")); // Refurbished
         assert!(!output.contains("B003")); // Used
     }
+
+    #[tokio::test]
+    async fn test_search_regions_bounded_concurrency() {
+        let in_flight = Arc::new(InFlight::default());
+        let regions = [Region::Us, Region::Uk, Region::De, Region::Fr, Region::Es];
+        let clients: Vec<_> = regions
+            .iter()
+            .enumerate()
+            .map(|(i, &region)| {
+                let asin = format!("B00{}", i);
+                let html = make_search_html(&[(asin.as_str(), "Product", 10.0)]);
+                Arc::new(
+                    MockAmazonClient::new(vec![html])
+                        .in_region(region, Duration::from_millis(30))
+                        .tracking(Arc::clone(&in_flight)),
+                )
+            })
+            .collect();
+
+        let config = Config { max_concurrent_regions: 2, ..make_test_config() };
+        let results =
+            SearchCommand::new(config).search_regions_with_clients(clients, "test").await.unwrap();
+
+        assert_eq!(results.len(), 5);
+        assert_eq!(in_flight.peak.load(Ordering::SeqCst), 2);
+        let found: Vec<Region> = results.iter().map(|(region, _)| *region).collect();
+        assert_eq!(found, regions);
+    }
+
+    #[tokio::test]
+    async fn test_search_regions_keeps_requested_order() {
+        // The first-listed region is the slowest to respond
+        let clients = vec![
+            Arc::new(
+                MockAmazonClient::new(vec![make_search_html(&[("B0US", "US Product", 10.0)])])
+                    .in_region(Region::Us, Duration::from_millis(100)),
+            ),
+            Arc::new(
+                MockAmazonClient::new(vec![make_search_html(&[("B0DE", "DE Product", 10.0)])])
+                    .in_region(Region::De, Duration::from_millis(50)),
+            ),
+            Arc::new(
+                MockAmazonClient::new(vec![make_search_html(&[("B0UK", "UK Product", 10.0)])])
+                    .in_region(Region::Uk, Duration::ZERO),
+            ),
+        ];
+
+        let cmd = SearchCommand::new(make_test_config());
+        let results = cmd.search_regions_with_clients(clients, "test").await.unwrap();
+
+        let regions: Vec<Region> = results.iter().map(|(region, _)| *region).collect();
+        assert_eq!(regions, vec![Region::Us, Region::De, Region::Uk]);
+
+        let asins: Vec<&str> = results
+            .iter()
+            .flat_map(|(_, products)| products.iter().map(|p| p.asin.as_str()))
+            .collect();
+        assert_eq!(asins, vec!["B0US", "B0DE", "B0UK"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_regions_keep_own_currency_in_csv() {
+        let clients = vec![
+            Arc::new(
+                MockAmazonClient::new(vec![make_search_html(&[("B0US", "US Product", 10.0)])])
+                    .in_region(Region::Us, Duration::ZERO),
+            ),
+            Arc::new(
+                MockAmazonClient::new(vec![make_search_html(&[("B0UK", "UK Product", 8.0)])])
+                    .in_region(Region::Uk, Duration::ZERO),
+            ),
+        ];
+
+        // A single-storefront currency override must not leak into every region
+        let config = Config {
+            currency: Some("EUR".to_string()),
+            format: OutputFormat::Csv,
+            ..make_test_config()
+        };
+        let cmd = SearchCommand::new(config.clone());
+        let products: Vec<Product> = cmd
+            .search_regions_with_clients(clients, "test")
+            .await
+            .unwrap()
+            .into_iter()
+            .flat_map(|(_, products)| products)
+            .collect();
+
+        let csv = Formatter::from_config(&config).format_products(&products);
+        let rows: Vec<&str> = csv.lines().skip(1).collect();
+        assert!(rows[0].starts_with("B0US,US Product,10,,USD,"), "{}", rows[0]);
+        assert!(rows[1].starts_with("B0UK,UK Product,8,,GBP,"), "{}", rows[1]);
+    }
 }
@@ -0,0 +1,263 @@
+//! Selector self-test against bundled golden HTML fixtures.
+
+use crate::amazon::{Parser, Product, Region};
+
+const SEARCH_FIXTURE: &str = include_str!("../../tests/fixtures/search_result.html");
+const PRODUCT_RELATED_FIXTURE: &str = include_str!("../../tests/fixtures/product_related.html");
+
+/// Named check that a field was extracted from a parsed product.
+type FieldProbe = (&'static str, fn(&Product) -> bool);
+
+/// Fields reported for every fixture, in matrix row order.
+const FIELDS: &[FieldProbe] = &[
+    ("title", |p| !p.title.is_empty()),
+    ("image", |p| p.image_url.is_some()),
+    ("price", |p| p.price.is_some()),
+    ("original_price", |p| p.price.as_ref().is_some_and(|price| price.original.is_some())),
+    ("rating", |p| p.rating.is_some()),
+    ("review_count", |p| p.rating.as_ref().is_some_and(|r| r.review_count > 0)),
+    ("brand", |p| p.brand.is_some()),
+    ("prime", |p| p.is_prime),
+    ("sponsored", |p| p.is_sponsored),
+    ("in_stock", |p| p.in_stock),
+    ("related_asins", |p| !p.related_asins.is_empty()),
+];
+
+/// Which parser a fixture exercises.
+enum PageKind {
+    Search,
+    Product { asin: &'static str },
+}
+
+/// A bundled HTML page and the fields it is known to contain.
+struct Fixture {
+    name: &'static str,
+    kind: PageKind,
+    html: &'static str,
+    expected: &'static [&'static str],
+}
+
+const FIXTURES: &[Fixture] = &[
+    Fixture {
+        name: "search_result",
+        kind: PageKind::Search,
+        html: SEARCH_FIXTURE,
+        expected: &[
+            "title",
+            "image",
+            "price",
+            "original_price",
+            "rating",
+            "review_count",
+            "brand",
+            "prime",
+            "sponsored",
+            "in_stock",
+        ],
+    },
+    Fixture {
+        name: "product_related",
+        kind: PageKind::Product { asin: "B08N5WRWNW" },
+        html: PRODUCT_RELATED_FIXTURE,
+        expected: &["title", "price", "in_stock", "related_asins"],
+    },
+];
+
+/// Outcome of a single field check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldStatus {
+    /// Field was extracted
+    Pass,
+    /// Field is expected in the fixture but came back empty
+    Fail,
+    /// Field is empty and the fixture doesn't contain it
+    Empty,
+}
+
+impl FieldStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            FieldStatus::Pass => "PASS",
+            FieldStatus::Fail => "FAIL",
+            FieldStatus::Empty => "-",
+        }
+    }
+}
+
+/// Self-test results for one fixture.
+#[derive(Debug, Clone)]
+pub struct FixtureReport {
+    /// Fixture name
+    pub fixture: &'static str,
+    /// Parser error, if the fixture failed to parse at all
+    pub error: Option<String>,
+    /// Status of each field, in `FIELDS` order
+    pub fields: Vec<(&'static str, FieldStatus)>,
+}
+
+impl FixtureReport {
+    /// Returns true if parsing succeeded and no expected field came back empty.
+    pub fn passed(&self) -> bool {
+        self.error.is_none() && self.fields.iter().all(|(_, s)| *s != FieldStatus::Fail)
+    }
+
+    /// Returns the names of fields that were extracted.
+    pub fn extracted(&self) -> Vec<&'static str> {
+        self.fields.iter().filter(|(_, s)| *s == FieldStatus::Pass).map(|(f, _)| *f).collect()
+    }
+}
+
+/// Runs every parser over the bundled fixtures and reports extracted fields.
+pub struct SelftestCommand;
+
+impl SelftestCommand {
+    /// Creates a new self-test command.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Runs the self-test over all bundled fixtures.
+    pub fn run(&self) -> Vec<FixtureReport> {
+        let parser = Parser::new(Region::Us);
+        FIXTURES.iter().map(|fixture| Self::check(&parser, fixture)).collect()
+    }
+
+    /// Runs the self-test and renders a field x fixture pass/fail matrix.
+    pub fn execute(&self) -> (String, bool) {
+        let reports = self.run();
+        let passed = reports.iter().all(FixtureReport::passed);
+        (Self::render(&reports), passed)
+    }
+
+    fn check(parser: &Parser, fixture: &Fixture) -> FixtureReport {
+        let parsed = match fixture.kind {
+            PageKind::Search => {
+                parser.parse_search(fixture.html, "selftest", 1).map(|r| r.products)
+            }
+            PageKind::Product { asin } => {
+                parser.parse_product_page(fixture.html, asin).map(|p| vec![p])
+            }
+        };
+
+        let (products, error) = match parsed {
+            Ok(products) if products.is_empty() => (products, Some("no products parsed".into())),
+            Ok(products) => (products, None),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+
+        let fields = FIELDS
+            .iter()
+            .map(|(name, probe)| {
+                let status = if products.iter().any(probe) {
+                    FieldStatus::Pass
+                } else if fixture.expected.contains(name) {
+                    FieldStatus::Fail
+                } else {
+                    FieldStatus::Empty
+                };
+                (*name, status)
+            })
+            .collect();
+
+        FixtureReport { fixture: fixture.name, error, fields }
+    }
+
+    fn render(reports: &[FixtureReport]) -> String {
+        let field_width = 16;
+        let col_width = reports.iter().map(|r| r.fixture.len()).max().unwrap_or(0).max(6);
+
+        let mut lines = Vec::new();
+
+        let mut header = format!("{:<field_width$}", "Field");
+        let mut divider = format!("{:-<field_width$}", "");
+        for report in reports {
+            header.push_str(&format!("  {:<col_width$}", report.fixture));
+            divider.push_str(&format!("  {:-<col_width$}", ""));
+        }
+        lines.push(header.trim_end().to_string());
+        lines.push(divider);
+
+        for (i, (name, _)) in FIELDS.iter().enumerate() {
+            let mut row = format!("{:<field_width$}", name);
+            for report in reports {
+                let label = report.fields.get(i).map_or("-", |(_, s)| s.label());
+                row.push_str(&format!("  {:<col_width$}", label));
+            }
+            lines.push(row.trim_end().to_string());
+        }
+
+        lines.push(String::new());
+        for report in reports {
+            if let Some(error) = &report.error {
+                lines.push(format!("{}: parse error: {}", report.fixture, error));
+            }
+        }
+
+        let passed = reports.iter().filter(|r| r.passed()).count();
+        lines.push(format!("{}/{} fixtures passed", passed, reports.len()));
+
+        lines.join("\n")
+    }
+}
+
+impl Default for SelftestCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selftest_search_fixture_fields() {
+        let reports = SelftestCommand::new().run();
+        let search = reports.iter().find(|r| r.fixture == "search_result").unwrap();
+
+        assert!(search.passed());
+        assert_eq!(
+            search.extracted(),
+            vec![
+                "title",
+                "image",
+                "price",
+                "original_price",
+                "rating",
+                "review_count",
+                "brand",
+                "prime",
+                "sponsored",
+                "in_stock",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selftest_all_fixtures_pass() {
+        let (output, passed) = SelftestCommand::new().execute();
+
+        assert!(passed);
+        assert!(output.starts_with("Field"));
+        assert!(output.contains("search_result"));
+        assert!(output.contains("product_related"));
+        assert!(output.ends_with("2/2 fixtures passed"));
+    }
+
+    #[test]
+    fn test_selftest_reports_missing_expected_field() {
+        let parser = Parser::new(Region::Us);
+        let fixture = Fixture {
+            name: "broken",
+            kind: PageKind::Product { asin: "B08N5WRWNW" },
+            html: r#"<html><body><span id="productTitle">Only a title</span></body></html>"#,
+            expected: &["title", "price"],
+        };
+
+        let report = SelftestCommand::check(&parser, &fixture);
+        assert!(!report.passed());
+        assert!(report.fields.contains(&("title", FieldStatus::Pass)));
+        assert!(report.fields.contains(&("price", FieldStatus::Fail)));
+        assert!(report.fields.contains(&("rating", FieldStatus::Empty)));
+    }
+}
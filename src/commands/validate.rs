@@ -0,0 +1,97 @@
+//! Config file validation without running a command.
+
+use crate::config::Config;
+use std::path::{Path, PathBuf};
+
+/// Parses a config file and checks its values.
+pub struct ValidateConfigCommand {
+    path: PathBuf,
+}
+
+impl ValidateConfigCommand {
+    /// Creates a validate command for the config file at `path`.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self { path: path.as_ref().to_path_buf() }
+    }
+
+    /// Validates the file, returning the report and whether it passed.
+    pub fn execute(&self) -> (String, bool) {
+        let config = match Config::from_file(&self.path) {
+            Ok(config) => config,
+            // Include the TOML error (line, column, unknown key) after the context
+            Err(e) => return (format!("{:#}", e), false),
+        };
+
+        let errors = config.validate();
+        if !errors.is_empty() {
+            let mut lines = vec![format!("{}: {} error(s)", self.path.display(), errors.len())];
+            lines.extend(errors.iter().map(|e| format!("  - {}", e)));
+            return (lines.join("\n"), false);
+        }
+
+        (Self::summary(&self.path, &config), true)
+    }
+
+    fn summary(path: &Path, config: &Config) -> String {
+        let mut lines = vec![
+            format!("OK: {}", path.display()),
+            format!("  region:  {}", config.region),
+            format!("  format:  {}", config.format),
+            format!("  delay:   {}ms (+0-{}ms jitter)", config.delay_ms, config.delay_jitter_ms),
+            format!("  max:     {} results", config.max_results),
+        ];
+        if let Some(proxy) = &config.proxy {
+            lines.push(format!("  proxy:   {} (+{} fallback)", proxy, config.proxies.len()));
+        }
+        if !config.presets.is_empty() {
+            let mut names: Vec<&str> = config.presets.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            lines.push(format!("  presets: {}", names.join(", ")));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn write_config(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_validate_valid_config() {
+        let file = write_config("region = \"de\"\nmin_price = 10.0\nmax_price = 50.0\n");
+        let (output, passed) = ValidateConfigCommand::new(file.path()).execute();
+
+        assert!(passed);
+        assert!(output.starts_with("OK: "));
+        assert!(output.contains("region:  de"));
+    }
+
+    #[test]
+    fn test_validate_inverted_price_range() {
+        let file = write_config("min_price = 50.0\nmax_price = 10.0\n");
+        let (output, passed) = ValidateConfigCommand::new(file.path()).execute();
+
+        assert!(!passed);
+        assert!(output.contains("1 error(s)"));
+        assert!(output.contains("  - min_price (50) is greater than max_price (10)"));
+    }
+
+    #[test]
+    fn test_validate_parse_error() {
+        let file = write_config("region = \"atlantis\"\n");
+        let (output, passed) = ValidateConfigCommand::new(file.path()).execute();
+
+        assert!(!passed);
+        assert!(output.contains("Failed to parse config file"));
+        assert!(output.contains("atlantis"));
+    }
+}
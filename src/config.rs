@@ -3,11 +3,13 @@
 use crate::amazon::regions::Region;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use tracing::debug;
 
 /// Application configuration with layered loading.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)] // Catch typos like `max_result` instead of ignoring them
 pub struct Config {
     /// Amazon region
     #[serde(default)]
@@ -17,6 +19,22 @@ pub struct Config {
     #[serde(default)]
     pub proxy: Option<String>,
 
+    /// Proxy username, combined with a credential-less `proxy` URL
+    #[serde(default)]
+    pub proxy_user: Option<String>,
+
+    /// Proxy password, combined with a credential-less `proxy` URL
+    #[serde(default)]
+    pub proxy_pass: Option<String>,
+
+    /// Extra proxies tried in order after `proxy` when a CAPTCHA is retried
+    #[serde(default)]
+    pub proxies: Vec<String>,
+
+    /// On a CAPTCHA, retry the request through the next proxy in the pool
+    #[serde(default)]
+    pub retry_captcha_with_proxy: bool,
+
     /// Base delay between requests in milliseconds
     #[serde(default = "default_delay_ms")]
     pub delay_ms: u64,
@@ -25,6 +43,57 @@ pub struct Config {
     #[serde(default = "default_delay_jitter_ms")]
     pub delay_jitter_ms: u64,
 
+    /// Base delay before each search page after the first (None = `delay_ms`)
+    #[serde(default)]
+    pub page_delay_ms: Option<u64>,
+
+    /// Extra pause between product lookups in a batch, on top of the
+    /// client's per-request delay
+    #[serde(default)]
+    pub asin_delay_ms: Option<u64>,
+
+    /// Seed for the jitter RNG, making request delays reproducible
+    #[serde(default)]
+    pub rng_seed: Option<u64>,
+
+    /// Wait before the first retry of a rate-limited (429) request without a
+    /// `Retry-After` header
+    #[serde(default = "default_retry_base_ms")]
+    pub retry_base_ms: u64,
+
+    /// Factor the retry wait grows by after each attempt
+    #[serde(default = "default_retry_multiplier")]
+    pub retry_multiplier: f64,
+
+    /// Upper bound on a single retry wait, before jitter
+    #[serde(default = "default_retry_max_ms")]
+    pub retry_max_ms: u64,
+
+    /// Random jitter added to each retry wait (0 to this value)
+    #[serde(default)]
+    pub retry_jitter_ms: u64,
+
+    /// Retries of a rate-limited request before giving up
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Directory for caching fetched pages (no caching when unset; a leading
+    /// `~` is expanded to the home directory)
+    #[serde(default)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Gzip-compress cached pages on disk
+    #[serde(default = "default_cache_compress")]
+    pub cache_compress: bool,
+
+    /// How long cached pages are reused, in hours
+    #[serde(default = "default_cache_ttl_hours")]
+    pub cache_ttl_hours: u64,
+
+    /// JA3/JA4-reporting endpoint used by `fingerprint-test` (None = tls.peet.ws)
+    #[serde(default)]
+    pub fingerprint_url: Option<String>,
+
     /// Maximum number of results to fetch
     #[serde(default = "default_max_results")]
     pub max_results: usize,
@@ -45,6 +114,26 @@ pub struct Config {
     #[serde(default)]
     pub min_rating: Option<f32>,
 
+    /// Filter: minimum number of reviews
+    #[serde(default)]
+    pub min_reviews: Option<u32>,
+
+    /// Filter: combined rating and review-count gate
+    #[serde(default)]
+    pub quality: Option<Quality>,
+
+    /// Minimum EU energy efficiency class ('A' is best)
+    #[serde(default)]
+    pub min_energy_class: Option<char>,
+
+    /// Minimum Subscribe & Save discount in percent
+    #[serde(default)]
+    pub min_subscribe_discount: Option<u8>,
+
+    /// Keep only products showing at most this many units left in stock
+    #[serde(default)]
+    pub max_stock_remaining: Option<u32>,
+
     /// Filter: Prime-only products
     #[serde(default)]
     pub prime_only: bool,
@@ -60,6 +149,229 @@ pub struct Config {
     /// Filter: keywords that must NOT appear in title
     #[serde(default)]
     pub exclude_keywords: Vec<String>,
+
+    /// Whether all or any of `keywords` must appear in the title
+    #[serde(default)]
+    pub keyword_match_mode: KeywordMatchMode,
+
+    /// Filter: exclude digital products (ebooks, gift cards)
+    #[serde(default)]
+    pub exclude_digital: bool,
+
+    /// Filter: exclude products flagged "Frequently returned item"
+    #[serde(default)]
+    pub exclude_frequently_returned: bool,
+
+    /// Filter: drop products whose visible price parsed as 0.00 (usually a
+    /// parse glitch, not a free item)
+    #[serde(default)]
+    pub drop_zero_price: bool,
+
+    /// Keep the last-known price on out-of-stock listings
+    #[serde(default)]
+    pub include_out_of_stock_price: bool,
+
+    /// Only count a Prime badge inside the product page buy box
+    #[serde(default)]
+    pub strict_prime: bool,
+
+    /// Fail when a product is missing a title or an expected price
+    #[serde(default)]
+    pub strict_parse: bool,
+
+    /// Keep the raw title/price/rating text on each product (`_raw` in JSON)
+    #[serde(default)]
+    pub debug_raw: bool,
+
+    /// Print each Amazon URL to stderr before it is requested
+    #[serde(default)]
+    pub show_url: bool,
+
+    /// Extra phrases marking a page as a soft block, on top of the built-in
+    /// ones (case-insensitive)
+    #[serde(default)]
+    pub block_phrases: Vec<String>,
+
+    /// CSS selectors whose presence marks a page as a soft block
+    #[serde(default)]
+    pub block_selectors: Vec<String>,
+
+    /// Stop searching once the first product passes all filters
+    #[serde(default)]
+    pub first_match: bool,
+
+    /// How repeated search results are collapsed (by ASIN, or by normalized title)
+    #[serde(default)]
+    pub dedup_by: DedupBy,
+
+    /// Abort a batch product lookup on the first CAPTCHA or block
+    #[serde(default)]
+    pub fail_fast: bool,
+
+    /// Result ordering requested from Amazon
+    #[serde(default)]
+    pub sort: SortKey,
+
+    /// What to do when the page safety limit stops a search short of `max_results`
+    #[serde(default)]
+    pub on_page_cap: PageCapAction,
+
+    /// Paginate by following the "Next" link instead of `&page=N`
+    #[serde(default)]
+    pub follow_next_link: bool,
+
+    /// Report Amazon's total result count with search output ("About 10,000
+    /// results" header, or a JSON object with `total_results`)
+    #[serde(default)]
+    pub show_total_results: bool,
+
+    /// Total result count from which a query is reported as popular (broad)
+    #[serde(default = "default_popular_query_min")]
+    pub popular_query_min: u32,
+
+    /// Most regions searched at once by multi-region commands (`--regions`)
+    #[serde(default = "default_max_concurrent_regions")]
+    pub max_concurrent_regions: usize,
+
+    /// Most product lookups in flight at once when a batch writes to
+    /// `--output-dir` (1 = one at a time)
+    #[serde(default = "default_batch_concurrency")]
+    pub batch_concurrency: usize,
+
+    /// Ask for confirmation (on a terminal) before a search or batch
+    /// estimated to send more requests than this (0 = only with `--confirm`)
+    #[serde(default = "default_confirm_threshold")]
+    pub confirm_threshold: usize,
+
+    /// Decimal places for prices in table/markdown/compare output (default: 2)
+    #[serde(default)]
+    pub price_decimals: Option<usize>,
+
+    /// Prefix CSV output with a UTF-8 byte-order mark (for Excel)
+    #[serde(default)]
+    pub csv_bom: bool,
+
+    /// Rewrite product URLs to `https://www.<domain>/dp/<asin>` in output
+    #[serde(default)]
+    pub canonical_urls: bool,
+
+    /// Add an image thumbnail column to markdown tables
+    #[serde(default)]
+    pub md_table_images: bool,
+
+    /// Render markdown product lists as a `- [ ]` task list
+    #[serde(default)]
+    pub md_tasklist: bool,
+
+    /// Emit CSV/JSON prices as integer minor units (e.g. cents)
+    #[serde(default)]
+    pub minor_units: bool,
+
+    /// Scale ratings are shown on in every output format (5, 10 or 100)
+    #[serde(default)]
+    pub rating_scale: RatingScale,
+
+    /// Written in CSV for absent optional fields (price, rating, brand, ...)
+    #[serde(default)]
+    pub null_value: String,
+
+    /// End formatted output (stdout and `--output-dir` files) with a newline
+    #[serde(default = "default_trailing_newline")]
+    pub trailing_newline: bool,
+
+    /// Column separator for table product lists (None = two spaces)
+    #[serde(default)]
+    pub table_sep: Option<String>,
+
+    /// Width to fit table product lists into (None = the terminal's, if any)
+    #[serde(default)]
+    pub table_width: Option<usize>,
+
+    /// Render table titles as OSC 8 hyperlinks (on by default when stdout is
+    /// a terminal)
+    #[serde(default)]
+    pub hyperlinks: bool,
+
+    /// Decimal separator override, for base URLs that don't match the region
+    #[serde(default)]
+    pub decimal: Option<DecimalSeparator>,
+
+    /// Currency code override (e.g. "EUR"), for base URLs that don't match the region
+    #[serde(default)]
+    pub currency: Option<String>,
+
+    /// Currency label per country in compare output (e.g. UK = "GBP")
+    #[serde(default)]
+    pub locale_currency: HashMap<String, String>,
+
+    /// Exchange rate feed for `compare --convert` (ECB XML or rate JSON;
+    /// None = ECB daily rates)
+    #[serde(default)]
+    pub rates_url: Option<String>,
+
+    /// How long fetched exchange rates are reused, in hours
+    #[serde(default = "default_rates_ttl_hours")]
+    pub rates_ttl_hours: u64,
+
+    /// Named filter sets applied with `--preset <name>`
+    #[serde(default)]
+    pub presets: HashMap<String, FilterPreset>,
+}
+
+/// A reusable set of product filters, loaded from `[presets.<name>]`.
+///
+/// Unset fields leave the current config alone; `true` switches turn the
+/// matching filter on.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterPreset {
+    #[serde(default)]
+    pub min_price: Option<f64>,
+
+    #[serde(default)]
+    pub max_price: Option<f64>,
+
+    #[serde(default)]
+    pub min_rating: Option<f32>,
+
+    #[serde(default)]
+    pub min_reviews: Option<u32>,
+
+    #[serde(default)]
+    pub quality: Option<Quality>,
+
+    #[serde(default)]
+    pub min_energy_class: Option<char>,
+
+    #[serde(default)]
+    pub min_subscribe_discount: Option<u8>,
+
+    #[serde(default)]
+    pub max_stock_remaining: Option<u32>,
+
+    #[serde(default)]
+    pub prime_only: bool,
+
+    #[serde(default)]
+    pub no_sponsored: bool,
+
+    #[serde(default)]
+    pub keywords: Option<Vec<String>>,
+
+    #[serde(default)]
+    pub keyword_match_mode: Option<KeywordMatchMode>,
+
+    #[serde(default)]
+    pub exclude_keywords: Option<Vec<String>>,
+
+    #[serde(default)]
+    pub exclude_digital: bool,
+
+    #[serde(default)]
+    pub exclude_frequently_returned: bool,
+
+    #[serde(default)]
+    pub drop_zero_price: bool,
 }
 
 fn default_delay_ms() -> u64 {
@@ -70,26 +382,143 @@ fn default_delay_jitter_ms() -> u64 {
     3000
 }
 
+fn default_retry_base_ms() -> u64 {
+    5000
+}
+
+fn default_retry_multiplier() -> f64 {
+    2.0
+}
+
+fn default_retry_max_ms() -> u64 {
+    60000
+}
+
+fn default_max_retries() -> u32 {
+    1
+}
+
 fn default_max_results() -> usize {
     20
 }
 
+fn default_popular_query_min() -> u32 {
+    10000
+}
+
+fn default_max_concurrent_regions() -> usize {
+    3
+}
+
+fn default_batch_concurrency() -> usize {
+    1
+}
+
+/// Expands a leading `~` to the home directory; other paths are unchanged.
+fn expand_home(path: PathBuf) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path,
+    }
+}
+
+fn default_confirm_threshold() -> usize {
+    50
+}
+
+fn default_rates_ttl_hours() -> u64 {
+    24
+}
+
+fn default_cache_compress() -> bool {
+    true
+}
+
+fn default_cache_ttl_hours() -> u64 {
+    24
+}
+
+fn default_trailing_newline() -> bool {
+    true
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             region: Region::Us,
             proxy: None,
+            proxy_user: None,
+            proxy_pass: None,
+            proxies: Vec::new(),
+            retry_captcha_with_proxy: false,
             delay_ms: default_delay_ms(),
             delay_jitter_ms: default_delay_jitter_ms(),
+            page_delay_ms: None,
+            asin_delay_ms: None,
+            rng_seed: None,
+            retry_base_ms: default_retry_base_ms(),
+            retry_multiplier: default_retry_multiplier(),
+            retry_max_ms: default_retry_max_ms(),
+            retry_jitter_ms: 0,
+            max_retries: default_max_retries(),
+            cache_dir: None,
+            cache_compress: default_cache_compress(),
+            cache_ttl_hours: default_cache_ttl_hours(),
+            fingerprint_url: None,
             max_results: default_max_results(),
             format: OutputFormat::Table,
             min_price: None,
             max_price: None,
             min_rating: None,
+            min_reviews: None,
+            quality: None,
+            min_energy_class: None,
+            min_subscribe_discount: None,
+            max_stock_remaining: None,
             prime_only: false,
             no_sponsored: false,
             keywords: Vec::new(),
             exclude_keywords: Vec::new(),
+            keyword_match_mode: KeywordMatchMode::All,
+            exclude_digital: false,
+            exclude_frequently_returned: false,
+            drop_zero_price: false,
+            include_out_of_stock_price: false,
+            strict_prime: false,
+            strict_parse: false,
+            debug_raw: false,
+            show_url: false,
+            block_phrases: Vec::new(),
+            block_selectors: Vec::new(),
+            first_match: false,
+            dedup_by: DedupBy::Asin,
+            fail_fast: false,
+            sort: SortKey::Featured,
+            on_page_cap: PageCapAction::Warn,
+            follow_next_link: false,
+            show_total_results: false,
+            popular_query_min: default_popular_query_min(),
+            max_concurrent_regions: default_max_concurrent_regions(),
+            batch_concurrency: default_batch_concurrency(),
+            confirm_threshold: default_confirm_threshold(),
+            price_decimals: None,
+            csv_bom: false,
+            canonical_urls: false,
+            md_table_images: false,
+            md_tasklist: false,
+            minor_units: false,
+            rating_scale: RatingScale::Five,
+            null_value: String::new(),
+            trailing_newline: default_trailing_newline(),
+            table_sep: None,
+            table_width: None,
+            hyperlinks: false,
+            decimal: None,
+            currency: None,
+            locale_currency: HashMap::new(),
+            rates_url: None,
+            rates_ttl_hours: default_rates_ttl_hours(),
+            presets: HashMap::new(),
         }
     }
 }
@@ -108,8 +537,10 @@ impl Config {
         let content = std::fs::read_to_string(path)
             .with_context(|| format!("Failed to read config file: {}", path.display()))?;
 
-        toml::from_str(&content)
-            .with_context(|| format!("Failed to parse config file: {}", path.display()))
+        let mut config: Self = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+        config.cache_dir = config.cache_dir.map(expand_home);
+        Ok(config)
     }
 
     /// Loads configuration with fallback to default locations.
@@ -152,6 +583,14 @@ impl Config {
             self.proxy = Some(proxy);
         }
 
+        if let Ok(user) = std::env::var("AMZ_PROXY_USER") {
+            self.proxy_user = Some(user);
+        }
+
+        if let Ok(pass) = std::env::var("AMZ_PROXY_PASS") {
+            self.proxy_pass = Some(pass);
+        }
+
         if let Ok(delay) = std::env::var("AMZ_DELAY") {
             if let Ok(d) = delay.parse() {
                 self.delay_ms = d;
@@ -160,6 +599,139 @@ impl Config {
 
         self
     }
+
+    /// Checks values that parse but can't work, returning one message per
+    /// problem (empty when the config is usable).
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if self.delay_ms == 0 {
+            errors.push("delay_ms must be greater than 0 (undelayed requests get blocked)".into());
+        }
+        if self.retry_multiplier.is_nan() || self.retry_multiplier < 1.0 {
+            errors.push("retry_multiplier must be at least 1".into());
+        }
+        for (name, value) in [
+            ("max_results", self.max_results),
+            ("batch_concurrency", self.batch_concurrency),
+            ("max_concurrent_regions", self.max_concurrent_regions),
+        ] {
+            if value == 0 {
+                errors.push(format!("{} must be at least 1", name));
+            }
+        }
+
+        let filters = FilterPreset {
+            min_price: self.min_price,
+            max_price: self.max_price,
+            min_rating: self.min_rating,
+            min_energy_class: self.min_energy_class,
+            min_subscribe_discount: self.min_subscribe_discount,
+            ..Default::default()
+        };
+        errors.extend(filters.validate());
+
+        let mut names: Vec<&String> = self.presets.keys().collect();
+        names.sort();
+        for name in names {
+            let prefix = format!("presets.{}.", name);
+            errors.extend(self.presets[name].validate().into_iter().map(|e| prefix.clone() + &e));
+        }
+
+        errors
+    }
+
+    /// Merges the filters of the named preset into this config.
+    pub fn apply_preset(&mut self, name: &str) -> Result<()> {
+        let preset = self.presets.get(name).cloned().with_context(|| {
+            let mut known: Vec<&str> = self.presets.keys().map(String::as_str).collect();
+            known.sort_unstable();
+            if known.is_empty() {
+                format!("Unknown preset: {} (no [presets] defined in config)", name)
+            } else {
+                format!("Unknown preset: {}. Available: {}", name, known.join(", "))
+            }
+        })?;
+        debug!("Applying filter preset: {}", name);
+        self.apply_filters(preset);
+        Ok(())
+    }
+
+    /// Overlays a set of filters, keeping current values for unset fields.
+    pub fn apply_filters(&mut self, filters: FilterPreset) {
+        if filters.min_price.is_some() {
+            self.min_price = filters.min_price;
+        }
+        if filters.max_price.is_some() {
+            self.max_price = filters.max_price;
+        }
+        if filters.min_rating.is_some() {
+            self.min_rating = filters.min_rating;
+        }
+        if filters.min_reviews.is_some() {
+            self.min_reviews = filters.min_reviews;
+        }
+        if filters.quality.is_some() {
+            self.quality = filters.quality;
+        }
+        if filters.min_energy_class.is_some() {
+            self.min_energy_class = filters.min_energy_class;
+        }
+        if filters.min_subscribe_discount.is_some() {
+            self.min_subscribe_discount = filters.min_subscribe_discount;
+        }
+        if filters.max_stock_remaining.is_some() {
+            self.max_stock_remaining = filters.max_stock_remaining;
+        }
+        self.prime_only |= filters.prime_only;
+        self.no_sponsored |= filters.no_sponsored;
+        self.exclude_digital |= filters.exclude_digital;
+        self.exclude_frequently_returned |= filters.exclude_frequently_returned;
+        self.drop_zero_price |= filters.drop_zero_price;
+
+        if let Some(keywords) = filters.keywords {
+            self.keywords = keywords;
+        }
+        if let Some(mode) = filters.keyword_match_mode {
+            self.keyword_match_mode = mode;
+        }
+        if let Some(exclude) = filters.exclude_keywords {
+            self.exclude_keywords = exclude;
+        }
+    }
+}
+
+impl FilterPreset {
+    /// Checks the filter values for ranges no product could satisfy.
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        for (name, price) in [("min_price", self.min_price), ("max_price", self.max_price)] {
+            if price.is_some_and(|p| p < 0.0) {
+                errors.push(format!("{} must not be negative", name));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_price, self.max_price) {
+            if min > max {
+                errors.push(format!("min_price ({}) is greater than max_price ({})", min, max));
+            }
+        }
+        if let Some(rating) = self.min_rating {
+            if !(0.0..=5.0).contains(&rating) {
+                errors.push(format!("min_rating ({}) must be between 0 and 5", rating));
+            }
+        }
+        if let Some(class) = self.min_energy_class {
+            if !('A'..='G').contains(&class.to_ascii_uppercase()) {
+                errors.push(format!("min_energy_class ({}) must be a letter from A to G", class));
+            }
+        }
+        if self.min_subscribe_discount.is_some_and(|d| d > 100) {
+            errors.push("min_subscribe_discount must be at most 100".into());
+        }
+
+        errors
+    }
 }
 
 /// Output format for results.
@@ -173,27 +745,381 @@ pub enum OutputFormat {
     Csv,
 }
 
+impl OutputFormat {
+    /// Every output format, in listing order.
+    pub fn all() -> &'static [OutputFormat] {
+        &[OutputFormat::Table, OutputFormat::Json, OutputFormat::Markdown, OutputFormat::Csv]
+    }
+
+    /// Canonical name, as accepted by `--format` and printed by `Display`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Table => "table",
+            OutputFormat::Json => "json",
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Csv => "csv",
+        }
+    }
+
+    /// Alternative names `--format` also accepts.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            OutputFormat::Markdown => &["md"],
+            _ => &[],
+        }
+    }
+
+    /// Returns every accepted format name and alias, one per line (for shell
+    /// completions and scripts).
+    pub fn names() -> String {
+        Self::all()
+            .iter()
+            .flat_map(|format| {
+                std::iter::once(format.name()).chain(format.aliases().iter().copied())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// File extension used when writing this format to disk.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Table => "txt",
+            OutputFormat::Json => "json",
+            OutputFormat::Markdown => "md",
+            OutputFormat::Csv => "csv",
+        }
+    }
+}
+
 impl std::str::FromStr for OutputFormat {
     type Err = String;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_lowercase();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|format| format.name() == name || format.aliases().contains(&name.as_str()))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::all().iter().map(OutputFormat::name).collect();
+                format!("Unknown format: {}. Use: {}", s, names.join(", "))
+            })
+    }
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Sort order for search results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortKey {
+    /// Amazon's default ordering
+    #[default]
+    Featured,
+    /// Re-ranked locally by title match against the query
+    Relevance,
+    PriceAsc,
+    PriceDesc,
+    Rating,
+    Newest,
+    /// Re-ranked locally by [`Product::quality_score`](crate::amazon::Product::quality_score)
+    Quality,
+}
+
+impl SortKey {
+    /// Returns Amazon's `s=` search URL value, or None for the default ordering.
+    pub fn amazon_param(&self) -> Option<&'static str> {
+        match self {
+            SortKey::Featured | SortKey::Relevance | SortKey::Quality => None,
+            SortKey::PriceAsc => Some("price-asc-rank"),
+            SortKey::PriceDesc => Some("price-desc-rank"),
+            SortKey::Rating => Some("review-rank"),
+            SortKey::Newest => Some("date-desc-rank"),
+        }
+    }
+}
+
+impl std::str::FromStr for SortKey {
+    type Err = String;
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "table" => Ok(OutputFormat::Table),
-            "json" => Ok(OutputFormat::Json),
-            "markdown" | "md" => Ok(OutputFormat::Markdown),
-            "csv" => Ok(OutputFormat::Csv),
-            _ => Err(format!("Unknown format: {}. Use: table, json, markdown, csv", s)),
+            "featured" => Ok(SortKey::Featured),
+            "relevance" => Ok(SortKey::Relevance),
+            "price-asc" => Ok(SortKey::PriceAsc),
+            "price-desc" => Ok(SortKey::PriceDesc),
+            "rating" => Ok(SortKey::Rating),
+            "newest" => Ok(SortKey::Newest),
+            "quality" => Ok(SortKey::Quality),
+            _ => Err(format!(
+                "Unknown sort: {}. Use: featured, relevance, price-asc, price-desc, rating, newest, quality",
+                s
+            )),
         }
     }
 }
 
-impl std::fmt::Display for OutputFormat {
+impl std::fmt::Display for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortKey::Featured => write!(f, "featured"),
+            SortKey::Relevance => write!(f, "relevance"),
+            SortKey::PriceAsc => write!(f, "price-asc"),
+            SortKey::PriceDesc => write!(f, "price-desc"),
+            SortKey::Rating => write!(f, "rating"),
+            SortKey::Newest => write!(f, "newest"),
+            SortKey::Quality => write!(f, "quality"),
+        }
+    }
+}
+
+/// Action taken when a search hits the page safety limit before `max_results`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PageCapAction {
+    /// Log a warning and return what was collected
+    #[default]
+    Warn,
+    /// Fail the search
+    Error,
+    /// Silently return what was collected
+    Ignore,
+}
+
+impl std::str::FromStr for PageCapAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "warn" => Ok(PageCapAction::Warn),
+            "error" => Ok(PageCapAction::Error),
+            "ignore" => Ok(PageCapAction::Ignore),
+            _ => Err(format!("Unknown page cap action: {}. Use: warn, error, ignore", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for PageCapAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PageCapAction::Warn => write!(f, "warn"),
+            PageCapAction::Error => write!(f, "error"),
+            PageCapAction::Ignore => write!(f, "ignore"),
+        }
+    }
+}
+
+/// How the required keywords are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeywordMatchMode {
+    /// Every keyword must appear in the title
+    #[default]
+    All,
+    /// At least one keyword must appear in the title
+    Any,
+}
+
+impl std::str::FromStr for KeywordMatchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" | "and" => Ok(KeywordMatchMode::All),
+            "any" | "or" => Ok(KeywordMatchMode::Any),
+            _ => Err(format!("Unknown keyword match mode: {}. Use: all, any", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for KeywordMatchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeywordMatchMode::All => write!(f, "all"),
+            KeywordMatchMode::Any => write!(f, "any"),
+        }
+    }
+}
+
+/// Combined (rating, review count) quality gate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Quality {
+    /// 4.5+ stars with 100+ reviews
+    High,
+    /// 4.0+ stars with 25+ reviews
+    Medium,
+}
+
+impl Quality {
+    /// Minimum stars and review count this level requires.
+    pub fn thresholds(&self) -> (f32, u32) {
+        match self {
+            Quality::High => (4.5, 100),
+            Quality::Medium => (4.0, 25),
+        }
+    }
+}
+
+impl std::str::FromStr for Quality {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "high" => Ok(Quality::High),
+            "medium" | "med" => Ok(Quality::Medium),
+            _ => Err(format!("Unknown quality: {}. Use: high, medium", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for Quality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Quality::High => write!(f, "high"),
+            Quality::Medium => write!(f, "medium"),
+        }
+    }
+}
+
+/// Key used to drop repeated search results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupBy {
+    /// Keep the first listing of each ASIN
+    #[default]
+    Asin,
+    /// Keep the first listing of each normalized title, collapsing variants
+    /// (colors, sizes) that are listed under different ASINs
+    Title,
+}
+
+impl std::str::FromStr for DedupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "asin" => Ok(DedupBy::Asin),
+            "title" => Ok(DedupBy::Title),
+            _ => Err(format!("Unknown dedup key: {}. Use: asin, title", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for DedupBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DedupBy::Asin => write!(f, "asin"),
+            DedupBy::Title => write!(f, "title"),
+        }
+    }
+}
+
+/// Scale that star ratings are shown on. Ratings are parsed and filtered
+/// as 0-5 stars; only the output is rescaled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum RatingScale {
+    /// 0-5 stars, as shown on Amazon
+    #[default]
+    Five,
+    /// 0-10 points
+    Ten,
+    /// 0-100 percent
+    Hundred,
+}
+
+impl RatingScale {
+    /// Highest value on the scale.
+    pub fn max(&self) -> u8 {
+        match self {
+            RatingScale::Five => 5,
+            RatingScale::Ten => 10,
+            RatingScale::Hundred => 100,
+        }
+    }
+
+    /// Converts a 0-5 star rating to this scale, rounded to one decimal.
+    pub fn rescale(&self, stars: f32) -> f64 {
+        let value = f64::from(stars) * f64::from(self.max()) / 5.0;
+        (value * 10.0).round() / 10.0
+    }
+
+    /// Renders a 0-5 star rating on this scale: "4.5", "9.0" or "90".
+    pub fn format(&self, stars: f32) -> String {
+        let decimals = if *self == RatingScale::Hundred { 0 } else { 1 };
+        format!("{:.*}", decimals, self.rescale(stars))
+    }
+}
+
+impl TryFrom<u8> for RatingScale {
+    type Error = String;
+
+    fn try_from(max: u8) -> Result<Self, Self::Error> {
+        match max {
+            5 => Ok(RatingScale::Five),
+            10 => Ok(RatingScale::Ten),
+            100 => Ok(RatingScale::Hundred),
+            _ => Err(format!("Unknown rating scale: {}. Use: 5, 10, 100", max)),
+        }
+    }
+}
+
+impl From<RatingScale> for u8 {
+    fn from(scale: RatingScale) -> Self {
+        scale.max()
+    }
+}
+
+impl std::str::FromStr for RatingScale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Unknown rating scale: {}. Use: 5, 10, 100", s))
+            .and_then(RatingScale::try_from)
+    }
+}
+
+impl std::fmt::Display for RatingScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.max())
+    }
+}
+
+/// Decimal separator used in prices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DecimalSeparator {
+    /// "1.234,56"
+    Comma,
+    /// "1,234.56"
+    Period,
+}
+
+impl std::str::FromStr for DecimalSeparator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "comma" => Ok(DecimalSeparator::Comma),
+            "period" | "dot" => Ok(DecimalSeparator::Period),
+            _ => Err(format!("Unknown decimal separator: {}. Use: comma, period", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for DecimalSeparator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            OutputFormat::Table => write!(f, "table"),
-            OutputFormat::Json => write!(f, "json"),
-            OutputFormat::Markdown => write!(f, "markdown"),
-            OutputFormat::Csv => write!(f, "csv"),
+            DecimalSeparator::Comma => write!(f, "comma"),
+            DecimalSeparator::Period => write!(f, "period"),
         }
     }
 }
@@ -210,9 +1136,17 @@ mod tests {
         assert_eq!(config.region, Region::Us);
         assert_eq!(config.delay_ms, 2000);
         assert_eq!(config.delay_jitter_ms, 3000);
+        assert_eq!(config.retry_base_ms, 5000);
+        assert_eq!(config.retry_multiplier, 2.0);
+        assert_eq!(config.retry_max_ms, 60000);
+        assert_eq!(config.max_retries, 1);
         assert_eq!(config.max_results, 20);
         assert_eq!(config.format, OutputFormat::Table);
         assert!(config.proxy.is_none());
+        assert!(config.proxy_user.is_none());
+        assert!(config.proxy_pass.is_none());
+        assert!(config.proxies.is_empty());
+        assert!(!config.retry_captcha_with_proxy);
         assert!(config.min_price.is_none());
         assert!(config.max_price.is_none());
         assert!(config.min_rating.is_none());
@@ -220,6 +1154,21 @@ mod tests {
         assert!(!config.no_sponsored);
         assert!(config.keywords.is_empty());
         assert!(config.exclude_keywords.is_empty());
+        assert!(!config.exclude_digital);
+        assert!(!config.first_match);
+        assert_eq!(config.sort, SortKey::Featured);
+        assert!(!config.follow_next_link);
+        assert!(!config.show_total_results);
+        assert_eq!(config.popular_query_min, 10000);
+        assert_eq!(config.max_concurrent_regions, 3);
+        assert_eq!(config.batch_concurrency, 1);
+        assert_eq!(config.confirm_threshold, 50);
+        assert!(config.price_decimals.is_none());
+        assert!(!config.csv_bom);
+        assert!(!config.include_out_of_stock_price);
+        assert!(config.decimal.is_none());
+        assert!(config.currency.is_none());
+        assert_eq!(config.rates_ttl_hours, 24);
     }
 
     #[test]
@@ -246,6 +1195,20 @@ mod tests {
         assert!(err.contains("table, json, markdown, csv"));
     }
 
+    #[test]
+    fn test_output_format_names() {
+        assert_eq!(OutputFormat::names(), "table\njson\nmarkdown\nmd\ncsv");
+
+        // Every listed name parses back to its format
+        for format in OutputFormat::all() {
+            assert_eq!(format.name().parse::<OutputFormat>().unwrap(), *format);
+            assert_eq!(format.to_string(), format.name());
+            for alias in format.aliases() {
+                assert_eq!(alias.parse::<OutputFormat>().unwrap(), *format);
+            }
+        }
+    }
+
     #[test]
     fn test_output_format_display() {
         assert_eq!(OutputFormat::Table.to_string(), "table");
@@ -254,6 +1217,14 @@ mod tests {
         assert_eq!(OutputFormat::Csv.to_string(), "csv");
     }
 
+    #[test]
+    fn test_output_format_extension() {
+        assert_eq!(OutputFormat::Table.extension(), "txt");
+        assert_eq!(OutputFormat::Json.extension(), "json");
+        assert_eq!(OutputFormat::Markdown.extension(), "md");
+        assert_eq!(OutputFormat::Csv.extension(), "csv");
+    }
+
     #[test]
     fn test_output_format_default() {
         assert_eq!(OutputFormat::default(), OutputFormat::Table);
@@ -269,6 +1240,87 @@ mod tests {
         assert_eq!(parsed, OutputFormat::Markdown);
     }
 
+    #[test]
+    fn test_sort_key_parsing() {
+        assert_eq!("price-asc".parse::<SortKey>().unwrap(), SortKey::PriceAsc);
+        assert_eq!("PRICE-DESC".parse::<SortKey>().unwrap(), SortKey::PriceDesc);
+        assert_eq!("rating".parse::<SortKey>().unwrap(), SortKey::Rating);
+        assert!("cheapest".parse::<SortKey>().unwrap_err().contains("Unknown sort"));
+
+        for key in [
+            SortKey::Featured,
+            SortKey::Relevance,
+            SortKey::PriceAsc,
+            SortKey::Newest,
+            SortKey::Quality,
+        ] {
+            assert_eq!(key.to_string().parse::<SortKey>().unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn test_page_cap_action_parsing() {
+        assert_eq!("warn".parse::<PageCapAction>().unwrap(), PageCapAction::Warn);
+        assert_eq!("ERROR".parse::<PageCapAction>().unwrap(), PageCapAction::Error);
+        assert_eq!("ignore".parse::<PageCapAction>().unwrap(), PageCapAction::Ignore);
+        assert!("panic".parse::<PageCapAction>().is_err());
+        assert_eq!(Config::default().on_page_cap, PageCapAction::Warn);
+    }
+
+    #[test]
+    fn test_dedup_by_parsing() {
+        assert_eq!("asin".parse::<DedupBy>().unwrap(), DedupBy::Asin);
+        assert_eq!("Title".parse::<DedupBy>().unwrap(), DedupBy::Title);
+        assert!("brand".parse::<DedupBy>().is_err());
+        assert_eq!(Config::default().dedup_by, DedupBy::Asin);
+    }
+
+    #[test]
+    fn test_rating_scale() {
+        assert_eq!("10".parse::<RatingScale>().unwrap(), RatingScale::Ten);
+        assert!("7".parse::<RatingScale>().is_err());
+        assert!("ten".parse::<RatingScale>().is_err());
+        assert_eq!(Config::default().rating_scale, RatingScale::Five);
+
+        assert_eq!(RatingScale::Five.format(4.5), "4.5");
+        assert_eq!(RatingScale::Ten.format(4.5), "9.0");
+        assert_eq!(RatingScale::Hundred.format(4.5), "90");
+        assert_eq!(RatingScale::Hundred.rescale(4.3), 86.0);
+
+        let config: Config = toml::from_str("rating_scale = 100").unwrap();
+        assert_eq!(config.rating_scale, RatingScale::Hundred);
+        assert!(toml::from_str::<Config>("rating_scale = 3").is_err());
+    }
+
+    #[test]
+    fn test_keyword_match_mode_parsing() {
+        assert_eq!("all".parse::<KeywordMatchMode>().unwrap(), KeywordMatchMode::All);
+        assert_eq!("ANY".parse::<KeywordMatchMode>().unwrap(), KeywordMatchMode::Any);
+        assert_eq!("or".parse::<KeywordMatchMode>().unwrap(), KeywordMatchMode::Any);
+        assert!("some".parse::<KeywordMatchMode>().is_err());
+        assert_eq!(Config::default().keyword_match_mode, KeywordMatchMode::All);
+    }
+
+    #[test]
+    fn test_decimal_separator_parsing() {
+        assert_eq!("comma".parse::<DecimalSeparator>().unwrap(), DecimalSeparator::Comma);
+        assert_eq!("Period".parse::<DecimalSeparator>().unwrap(), DecimalSeparator::Period);
+        assert!("space".parse::<DecimalSeparator>().is_err());
+    }
+
+    #[test]
+    fn test_sort_key_amazon_param() {
+        assert_eq!(SortKey::Featured.amazon_param(), None);
+        assert_eq!(SortKey::Relevance.amazon_param(), None);
+        assert_eq!(SortKey::PriceAsc.amazon_param(), Some("price-asc-rank"));
+        assert_eq!(SortKey::PriceDesc.amazon_param(), Some("price-desc-rank"));
+        assert_eq!(SortKey::Rating.amazon_param(), Some("review-rank"));
+        assert_eq!(SortKey::Newest.amazon_param(), Some("date-desc-rank"));
+
+        let parsed: SortKey = serde_json::from_str("\"price-asc\"").unwrap();
+        assert_eq!(parsed, SortKey::PriceAsc);
+    }
+
     #[test]
     fn test_config_from_toml() {
         let toml = r#"
@@ -336,6 +1388,16 @@ mod tests {
         assert_eq!(config.delay_ms, 4000);
     }
 
+    #[test]
+    fn test_config_from_file_expands_cache_dir() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"cache_dir = "~/.cache/amz-crawler""#).unwrap();
+
+        let config = Config::from_file(file.path()).unwrap();
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(config.cache_dir, Some(home.join(".cache/amz-crawler")));
+    }
+
     #[test]
     fn test_config_from_file_not_found() {
         let result = Config::from_file("/nonexistent/path/config.toml");
@@ -355,6 +1417,22 @@ mod tests {
         assert!(err.contains("Failed to parse config file"));
     }
 
+    #[test]
+    fn test_config_from_file_unknown_key() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "region = \"de\"\nmax_result = 50").unwrap();
+
+        let err = format!("{:#}", Config::from_file(file.path()).unwrap_err());
+        assert!(err.contains("Failed to parse config file"));
+        assert!(err.contains("unknown field `max_result`"));
+    }
+
+    #[test]
+    fn test_example_config_parses() {
+        let config: Config = toml::from_str(include_str!("../config.example.toml")).unwrap();
+        assert_eq!(config.max_results, 20);
+    }
+
     #[test]
     fn test_config_load_no_file() {
         // When no file exists, should return default config
@@ -441,17 +1519,81 @@ mod tests {
         let config = Config {
             region: Region::Uk,
             proxy: Some("socks5://localhost:1080".to_string()),
+            proxy_user: Some("user".to_string()),
+            proxy_pass: None,
+            proxies: vec!["http://backup:8080".to_string()],
+            retry_captcha_with_proxy: true,
             delay_ms: 3000,
             delay_jitter_ms: 1500,
+            page_delay_ms: Some(6000),
+            asin_delay_ms: Some(10000),
+            rng_seed: Some(42),
+            retry_base_ms: 1000,
+            retry_multiplier: 3.0,
+            retry_max_ms: 20000,
+            retry_jitter_ms: 500,
+            max_retries: 4,
+            cache_dir: Some(PathBuf::from("/tmp/amz-cache")),
+            cache_compress: false,
+            cache_ttl_hours: 6,
+            fingerprint_url: Some("http://localhost:8443/api/all".to_string()),
             max_results: 50,
             format: OutputFormat::Json,
             min_price: Some(10.0),
             max_price: Some(100.0),
             min_rating: Some(4.0),
+            min_reviews: Some(100),
+            quality: Some(Quality::High),
+            min_energy_class: Some('C'),
+            min_subscribe_discount: Some(10),
+            max_stock_remaining: Some(5),
             prime_only: true,
             no_sponsored: true,
             keywords: vec!["test".to_string()],
             exclude_keywords: vec!["exclude".to_string()],
+            keyword_match_mode: KeywordMatchMode::Any,
+            exclude_digital: true,
+            exclude_frequently_returned: true,
+            drop_zero_price: true,
+            include_out_of_stock_price: true,
+            strict_prime: true,
+            strict_parse: true,
+            debug_raw: true,
+            show_url: true,
+            block_phrases: vec!["unusual traffic".to_string()],
+            block_selectors: vec!["#px-captcha".to_string()],
+            first_match: true,
+            dedup_by: DedupBy::Title,
+            fail_fast: true,
+            sort: SortKey::PriceAsc,
+            on_page_cap: PageCapAction::Error,
+            follow_next_link: true,
+            show_total_results: true,
+            popular_query_min: 5000,
+            max_concurrent_regions: 5,
+            batch_concurrency: 4,
+            confirm_threshold: 0,
+            price_decimals: Some(0),
+            csv_bom: true,
+            canonical_urls: true,
+            md_table_images: true,
+            md_tasklist: true,
+            minor_units: true,
+            rating_scale: RatingScale::Hundred,
+            null_value: "NULL".to_string(),
+            trailing_newline: false,
+            table_sep: Some(" | ".to_string()),
+            table_width: Some(80),
+            hyperlinks: true,
+            decimal: Some(DecimalSeparator::Comma),
+            currency: Some("EUR".to_string()),
+            locale_currency: HashMap::from([("UK".to_string(), "GBP".to_string())]),
+            rates_url: Some("https://example.com/rates.json".to_string()),
+            rates_ttl_hours: 6,
+            presets: HashMap::from([(
+                "deals".to_string(),
+                FilterPreset { max_price: Some(50.0), ..Default::default() },
+            )]),
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -459,10 +1601,149 @@ mod tests {
 
         assert_eq!(parsed.region, config.region);
         assert_eq!(parsed.proxy, config.proxy);
+        assert_eq!(parsed.proxy_user, config.proxy_user);
+        assert_eq!(parsed.proxies, config.proxies);
+        assert_eq!(parsed.retry_captcha_with_proxy, config.retry_captcha_with_proxy);
         assert_eq!(parsed.delay_ms, config.delay_ms);
+        assert_eq!(parsed.page_delay_ms, config.page_delay_ms);
+        assert_eq!(parsed.asin_delay_ms, config.asin_delay_ms);
+        assert_eq!(parsed.rng_seed, config.rng_seed);
+        assert_eq!(parsed.retry_base_ms, config.retry_base_ms);
+        assert_eq!(parsed.retry_multiplier, config.retry_multiplier);
+        assert_eq!(parsed.retry_max_ms, config.retry_max_ms);
+        assert_eq!(parsed.retry_jitter_ms, config.retry_jitter_ms);
+        assert_eq!(parsed.max_retries, config.max_retries);
+        assert_eq!(parsed.cache_dir, config.cache_dir);
+        assert_eq!(parsed.cache_compress, config.cache_compress);
+        assert_eq!(parsed.cache_ttl_hours, config.cache_ttl_hours);
+        assert_eq!(parsed.fingerprint_url, config.fingerprint_url);
         assert_eq!(parsed.max_results, config.max_results);
         assert_eq!(parsed.format, config.format);
         assert_eq!(parsed.min_price, config.min_price);
+        assert_eq!(parsed.min_reviews, config.min_reviews);
+        assert_eq!(parsed.quality, config.quality);
         assert_eq!(parsed.prime_only, config.prime_only);
+        assert_eq!(parsed.exclude_digital, config.exclude_digital);
+        assert_eq!(parsed.keyword_match_mode, config.keyword_match_mode);
+        assert_eq!(parsed.exclude_frequently_returned, config.exclude_frequently_returned);
+        assert_eq!(parsed.drop_zero_price, config.drop_zero_price);
+        assert_eq!(parsed.min_energy_class, config.min_energy_class);
+        assert_eq!(parsed.min_subscribe_discount, config.min_subscribe_discount);
+        assert_eq!(parsed.max_stock_remaining, config.max_stock_remaining);
+        assert_eq!(parsed.include_out_of_stock_price, config.include_out_of_stock_price);
+        assert_eq!(parsed.strict_prime, config.strict_prime);
+        assert_eq!(parsed.strict_parse, config.strict_parse);
+        assert_eq!(parsed.debug_raw, config.debug_raw);
+        assert_eq!(parsed.show_url, config.show_url);
+        assert_eq!(parsed.block_phrases, config.block_phrases);
+        assert_eq!(parsed.block_selectors, config.block_selectors);
+        assert_eq!(parsed.fail_fast, config.fail_fast);
+        assert_eq!(parsed.first_match, config.first_match);
+        assert_eq!(parsed.dedup_by, config.dedup_by);
+        assert_eq!(parsed.sort, config.sort);
+        assert_eq!(parsed.on_page_cap, config.on_page_cap);
+        assert_eq!(parsed.follow_next_link, config.follow_next_link);
+        assert_eq!(parsed.show_total_results, config.show_total_results);
+        assert_eq!(parsed.popular_query_min, config.popular_query_min);
+        assert_eq!(parsed.max_concurrent_regions, config.max_concurrent_regions);
+        assert_eq!(parsed.batch_concurrency, config.batch_concurrency);
+        assert_eq!(parsed.confirm_threshold, config.confirm_threshold);
+        assert_eq!(parsed.price_decimals, config.price_decimals);
+        assert_eq!(parsed.csv_bom, config.csv_bom);
+        assert_eq!(parsed.canonical_urls, config.canonical_urls);
+        assert_eq!(parsed.md_table_images, config.md_table_images);
+        assert_eq!(parsed.md_tasklist, config.md_tasklist);
+        assert_eq!(parsed.minor_units, config.minor_units);
+        assert_eq!(parsed.rating_scale, config.rating_scale);
+        assert_eq!(parsed.null_value, config.null_value);
+        assert_eq!(parsed.trailing_newline, config.trailing_newline);
+        assert_eq!(parsed.table_sep, config.table_sep);
+        assert_eq!(parsed.table_width, config.table_width);
+        assert_eq!(parsed.hyperlinks, config.hyperlinks);
+        assert_eq!(parsed.decimal, config.decimal);
+        assert_eq!(parsed.currency, config.currency);
+        assert_eq!(parsed.locale_currency, config.locale_currency);
+        assert_eq!(parsed.rates_url, config.rates_url);
+        assert_eq!(parsed.rates_ttl_hours, config.rates_ttl_hours);
+        assert_eq!(parsed.presets, config.presets);
+    }
+
+    #[test]
+    fn test_apply_preset_with_cli_override() {
+        let mut config: Config = toml::from_str(
+            r#"
+            min_rating = 3.0
+
+            [presets.deals]
+            max_price = 50.0
+            min_rating = 4.0
+            prime_only = true
+            keywords = ["wireless"]
+            "#,
+        )
+        .unwrap();
+
+        config.apply_preset("deals").unwrap();
+        assert_eq!(config.max_price, Some(50.0));
+        assert_eq!(config.min_rating, Some(4.0));
+        assert!(config.prime_only);
+        assert_eq!(config.keywords, vec!["wireless"]);
+
+        // CLI flags are applied after the preset and win
+        config.apply_filters(FilterPreset { max_price: Some(30.0), ..Default::default() });
+        assert_eq!(config.max_price, Some(30.0));
+        assert_eq!(config.min_rating, Some(4.0));
+        assert!(config.prime_only);
+    }
+
+    #[test]
+    fn test_validate_accepts_example_config() {
+        let config: Config = toml::from_str(include_str!("../config.example.toml")).unwrap();
+        assert_eq!(config.validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_inverted_price_range() {
+        let config: Config = toml::from_str(
+            r#"
+            delay_ms = 0
+            min_price = 100.0
+            max_price = 20.0
+
+            [presets.deals]
+            min_rating = 7.0
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.validate(),
+            vec![
+                "delay_ms must be greater than 0 (undelayed requests get blocked)",
+                "min_price (100) is greater than max_price (20)",
+                "presets.deals.min_rating (7) must be between 0 and 5",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quality_parse() {
+        assert_eq!("high".parse::<Quality>().unwrap(), Quality::High);
+        assert_eq!("Medium".parse::<Quality>().unwrap().thresholds(), (4.0, 25));
+        assert!("low".parse::<Quality>().is_err());
+
+        let config: Config = toml::from_str("quality = \"high\"").unwrap();
+        assert_eq!(config.quality, Some(Quality::High));
+    }
+
+    #[test]
+    fn test_apply_unknown_preset() {
+        let mut config: Config = toml::from_str("[presets.deals]\nmax_price = 50.0").unwrap();
+        let err = config.apply_preset("cheap").unwrap_err().to_string();
+        assert!(err.contains("Unknown preset: cheap"));
+        assert!(err.contains("deals"));
+
+        let err = Config::default().apply_preset("deals").unwrap_err().to_string();
+        assert!(err.contains("no [presets]"));
     }
 }
@@ -0,0 +1,121 @@
+//! Confirmation prompt before scrapes that would send many requests.
+
+use crate::amazon::models::RESULTS_PER_PAGE;
+use crate::commands::search::MAX_SEARCH_PAGES;
+use anyhow::{Context, Result};
+use std::io::{BufRead, IsTerminal, Write};
+
+/// Estimated requests for a search collecting `max_results` in each of
+/// `regions` regions (at most `MAX_SEARCH_PAGES` pages per region).
+pub fn search_requests(max_results: usize, regions: usize) -> usize {
+    let pages = max_results.div_ceil(RESULTS_PER_PAGE as usize).clamp(1, MAX_SEARCH_PAGES as usize);
+    pages * regions.max(1)
+}
+
+/// Asks on stderr whether to go ahead with `requests` requests, when `force`
+/// is set or the estimate exceeds `threshold` (0 = never ask unforced).
+///
+/// Returns true without prompting when stdin isn't a terminal, so scripts
+/// and pipelines never block.
+pub fn confirm_scrape(
+    requests: usize,
+    delay_ms: u64,
+    threshold: usize,
+    force: bool,
+) -> Result<bool> {
+    let interactive = std::io::stdin().is_terminal();
+    confirm_scrape_with(
+        requests,
+        delay_ms,
+        threshold,
+        force,
+        interactive,
+        &mut std::io::stdin().lock(),
+        &mut std::io::stderr(),
+    )
+}
+
+/// [`confirm_scrape`] with explicit input/output (for testing).
+pub fn confirm_scrape_with(
+    requests: usize,
+    delay_ms: u64,
+    threshold: usize,
+    force: bool,
+    interactive: bool,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<bool> {
+    let large = threshold > 0 && requests > threshold;
+    if !interactive || !(force || large) {
+        return Ok(true);
+    }
+
+    let seconds = requests as u64 * delay_ms / 1000;
+    write!(
+        output,
+        "About to send ~{} requests (at least {}s at the current delay). Continue? [y/N] ",
+        requests, seconds
+    )?;
+    output.flush()?;
+
+    let mut answer = String::new();
+    input.read_line(&mut answer).context("Failed to read confirmation")?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_requests() {
+        assert_eq!(search_requests(20, 1), 1);
+        assert_eq!(search_requests(100, 1), 3);
+        assert_eq!(search_requests(500, 1), 10);
+        assert_eq!(search_requests(100, 3), 9);
+        assert_eq!(search_requests(0, 0), 1);
+    }
+
+    #[test]
+    fn test_prompt_answers() {
+        let mut output = Vec::new();
+        let ok = confirm_scrape_with(60, 2000, 50, false, true, &mut "y\n".as_bytes(), &mut output)
+            .unwrap();
+        assert!(ok);
+        let prompt = String::from_utf8(output).unwrap();
+        assert_eq!(
+            prompt,
+            "About to send ~60 requests (at least 120s at the current delay). Continue? [y/N] "
+        );
+
+        let no =
+            confirm_scrape_with(60, 2000, 50, false, true, &mut "\n".as_bytes(), &mut Vec::new())
+                .unwrap();
+        assert!(!no);
+
+        // Forced prompt below the threshold
+        let forced =
+            confirm_scrape_with(5, 2000, 50, true, true, &mut "no\n".as_bytes(), &mut Vec::new())
+                .unwrap();
+        assert!(!forced);
+    }
+
+    #[test]
+    fn test_skips_prompt() {
+        // Non-interactive: never prompts, even when forced
+        let mut output = Vec::new();
+        assert!(
+            confirm_scrape_with(500, 0, 50, true, false, &mut "".as_bytes(), &mut output).unwrap()
+        );
+        assert!(output.is_empty());
+
+        // Small scrape, or the automatic prompt disabled
+        assert!(
+            confirm_scrape_with(10, 0, 50, false, true, &mut "".as_bytes(), &mut output).unwrap()
+        );
+        assert!(
+            confirm_scrape_with(500, 0, 0, false, true, &mut "".as_bytes(), &mut output).unwrap()
+        );
+        assert!(output.is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+//! EU energy efficiency class filter.
+
+use super::Filter;
+use crate::amazon::Product;
+
+/// Filters products by minimum EU energy class ('A' is best, 'G' worst).
+pub struct EnergyClassFilter {
+    min_class: char,
+}
+
+impl EnergyClassFilter {
+    /// Creates a new energy class filter; letters outside A-G are clamped.
+    pub fn new(min_class: char) -> Self {
+        Self { min_class: min_class.to_ascii_uppercase().clamp('A', 'G') }
+    }
+}
+
+impl Filter for EnergyClassFilter {
+    fn matches(&self, product: &Product) -> bool {
+        // Products without an energy label pass the filter (don't exclude them)
+        let Some(class) = product.energy_class else {
+            return true;
+        };
+
+        class <= self.min_class
+    }
+
+    fn description(&self) -> String {
+        format!("Energy class: {} or better", self.min_class)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_product(energy_class: Option<char>) -> Product {
+        Product {
+            asin: "TEST".to_string(),
+            title: "Test".to_string(),
+            url: "https://amazon.com/dp/TEST".to_string(),
+            image_url: None,
+            price: None,
+            rating: None,
+            is_sponsored: false,
+            is_prime: false,
+            is_amazon_choice: false,
+            in_stock: true,
+            brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            unit_price: None,
+            raw: None,
+            ships_internationally: None,
+            stock_remaining: None,
+        }
+    }
+
+    #[test]
+    fn test_energy_class_ordering() {
+        let filter = EnergyClassFilter::new('C');
+
+        assert!(filter.matches(&make_product(Some('A'))));
+        assert!(filter.matches(&make_product(Some('C'))));
+        assert!(!filter.matches(&make_product(Some('E'))));
+        assert!(filter.matches(&make_product(None)));
+    }
+
+    #[test]
+    fn test_normalizes_min_class() {
+        assert_eq!(EnergyClassFilter::new('b').min_class, 'B');
+        assert_eq!(EnergyClassFilter::new('Z').min_class, 'G');
+        assert_eq!(EnergyClassFilter::new('c').description(), "Energy class: C or better");
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::Filter;
 use crate::amazon::Product;
+use crate::config::KeywordMatchMode;
 
 /// Filters products by keywords in the title.
 pub struct KeywordFilter {
@@ -9,6 +10,8 @@ pub struct KeywordFilter {
     required: Vec<String>,
     /// Keywords that must NOT appear in the title.
     excluded: Vec<String>,
+    /// Whether all or any of the required keywords must appear.
+    match_mode: KeywordMatchMode,
 }
 
 impl KeywordFilter {
@@ -17,9 +20,16 @@ impl KeywordFilter {
         Self {
             required: required.into_iter().map(|k| k.to_lowercase()).collect(),
             excluded: excluded.into_iter().map(|k| k.to_lowercase()).collect(),
+            match_mode: KeywordMatchMode::All,
         }
     }
 
+    /// Sets whether all (default) or any of the required keywords must appear.
+    pub fn with_match_mode(mut self, match_mode: KeywordMatchMode) -> Self {
+        self.match_mode = match_mode;
+        self
+    }
+
     /// Creates a filter with only required keywords.
     pub fn required(keywords: Vec<String>) -> Self {
         Self::new(keywords, Vec::new())
@@ -35,9 +45,14 @@ impl Filter for KeywordFilter {
     fn matches(&self, product: &Product) -> bool {
         let title = product.title.to_lowercase();
 
-        // Check required keywords (all must be present)
-        for keyword in &self.required {
-            if !title.contains(keyword) {
+        // Check required keywords (all, or at least one, must be present)
+        if !self.required.is_empty() {
+            let found = |keyword: &String| title.contains(keyword.as_str());
+            let matched = match self.match_mode {
+                KeywordMatchMode::All => self.required.iter().all(found),
+                KeywordMatchMode::Any => self.required.iter().any(found),
+            };
+            if !matched {
                 return false;
             }
         }
@@ -56,7 +71,11 @@ impl Filter for KeywordFilter {
         let mut parts = Vec::new();
 
         if !self.required.is_empty() {
-            parts.push(format!("Must contain: {}", self.required.join(", ")));
+            let prefix = match self.match_mode {
+                KeywordMatchMode::All => "Must contain",
+                KeywordMatchMode::Any => "Must contain any of",
+            };
+            parts.push(format!("{}: {}", prefix, self.required.join(", ")));
         }
 
         if !self.excluded.is_empty() {
@@ -88,6 +107,24 @@ mod tests {
             is_amazon_choice: false,
             in_stock: true,
             brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -101,6 +138,19 @@ mod tests {
         assert!(!filter.matches(&make_product("Gaming Mouse"))); // Missing "wireless"
     }
 
+    #[test]
+    fn test_required_keywords_any_mode() {
+        let keywords = vec!["laptop".to_string(), "notebook".to_string(), "ultrabook".to_string()];
+        let all = KeywordFilter::required(keywords.clone());
+        let any = KeywordFilter::required(keywords).with_match_mode(KeywordMatchMode::Any);
+        let product = make_product("Slim 14\" Notebook, 16GB RAM");
+
+        assert!(any.matches(&product));
+        assert!(!all.matches(&product));
+        assert!(!any.matches(&make_product("Wireless Mouse")));
+        assert_eq!(any.description(), "Must contain any of: laptop, notebook, ultrabook");
+    }
+
     #[test]
     fn test_excluded_keywords() {
         let filter = KeywordFilter::excluded(vec!["refurbished".to_string(), "used".to_string()]);
@@ -1,16 +1,25 @@
 //! Product filtering system with composable filters.
 
+pub mod energy;
 pub mod keyword;
 pub mod price;
 pub mod prime;
 pub mod rating;
+pub mod reviews;
+pub mod stock;
+pub mod subscribe;
 
 use crate::amazon::Product;
+use crate::config::{Config, KeywordMatchMode, Quality};
 
+pub use energy::EnergyClassFilter;
 pub use keyword::KeywordFilter;
 pub use price::PriceFilter;
 pub use prime::PrimeFilter;
 pub use rating::RatingFilter;
+pub use reviews::ReviewCountFilter;
+pub use stock::StockRemainingFilter;
+pub use subscribe::SubscribeDiscountFilter;
 
 /// Trait for filtering products.
 pub trait Filter: Send + Sync {
@@ -21,6 +30,20 @@ pub trait Filter: Send + Sync {
     fn description(&self) -> String;
 }
 
+/// Closures act as ad-hoc filters, e.g. `chain.add(|p: &Product| p.is_prime)`.
+impl<F> Filter for F
+where
+    F: Fn(&Product) -> bool + Send + Sync,
+{
+    fn matches(&self, product: &Product) -> bool {
+        self(product)
+    }
+
+    fn description(&self) -> String {
+        "custom filter".to_string()
+    }
+}
+
 /// A chain of filters that must all pass.
 pub struct FilterChain {
     filters: Vec<Box<dyn Filter>>,
@@ -81,6 +104,25 @@ impl FilterChainBuilder {
         Self { chain: FilterChain::new() }
     }
 
+    /// Creates a builder with all filters configured in `config`.
+    pub fn from_config(config: &Config) -> Self {
+        Self::new()
+            .price_range(config.min_price, config.max_price)
+            .min_rating(config.min_rating)
+            .min_reviews(config.min_reviews)
+            .quality(config.quality)
+            .min_energy_class(config.min_energy_class)
+            .min_subscribe_discount(config.min_subscribe_discount)
+            .max_stock_remaining(config.max_stock_remaining)
+            .prime_only(config.prime_only)
+            .no_sponsored(config.no_sponsored)
+            .keywords_matching(config.keywords.clone(), config.keyword_match_mode)
+            .exclude_keywords(config.exclude_keywords.clone())
+            .exclude_digital(config.exclude_digital)
+            .exclude_frequently_returned(config.exclude_frequently_returned)
+            .drop_zero_price(config.drop_zero_price)
+    }
+
     /// Adds a price range filter.
     pub fn price_range(mut self, min: Option<f64>, max: Option<f64>) -> Self {
         if min.is_some() || max.is_some() {
@@ -97,6 +139,46 @@ impl FilterChainBuilder {
         self
     }
 
+    /// Adds a minimum review count filter.
+    pub fn min_reviews(mut self, min: Option<u32>) -> Self {
+        if let Some(min) = min {
+            self.chain.add(ReviewCountFilter::new(min));
+        }
+        self
+    }
+
+    /// Adds the rating and review count filters of a quality level.
+    pub fn quality(self, quality: Option<Quality>) -> Self {
+        match quality.map(|q| q.thresholds()) {
+            Some((stars, reviews)) => self.min_rating(Some(stars)).min_reviews(Some(reviews)),
+            None => self,
+        }
+    }
+
+    /// Adds a minimum EU energy class filter ('A' is best).
+    pub fn min_energy_class(mut self, min: Option<char>) -> Self {
+        if let Some(min) = min {
+            self.chain.add(EnergyClassFilter::new(min));
+        }
+        self
+    }
+
+    /// Adds a minimum Subscribe & Save discount filter (percent).
+    pub fn min_subscribe_discount(mut self, min: Option<u8>) -> Self {
+        if let Some(min) = min {
+            self.chain.add(SubscribeDiscountFilter::new(min));
+        }
+        self
+    }
+
+    /// Adds a low-stock filter keeping products with at most `max` units left.
+    pub fn max_stock_remaining(mut self, max: Option<u32>) -> Self {
+        if let Some(max) = max {
+            self.chain.add(StockRemainingFilter::new(max));
+        }
+        self
+    }
+
     /// Adds a Prime-only filter.
     pub fn prime_only(mut self, enabled: bool) -> Self {
         if enabled {
@@ -113,10 +195,39 @@ impl FilterChainBuilder {
         self
     }
 
-    /// Adds required keywords filter.
-    pub fn keywords(mut self, keywords: Vec<String>) -> Self {
+    /// Adds a digital filter (excludes ebooks, gift cards, etc.).
+    pub fn exclude_digital(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.chain.add(DigitalFilter::new());
+        }
+        self
+    }
+
+    /// Adds a filter excluding products flagged "Frequently returned item".
+    pub fn exclude_frequently_returned(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.chain.add(FrequentlyReturnedFilter::new());
+        }
+        self
+    }
+
+    /// Adds a filter dropping products with a visible 0.00 price.
+    pub fn drop_zero_price(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.chain.add(ZeroPriceFilter::new());
+        }
+        self
+    }
+
+    /// Adds required keywords filter (all must match).
+    pub fn keywords(self, keywords: Vec<String>) -> Self {
+        self.keywords_matching(keywords, KeywordMatchMode::All)
+    }
+
+    /// Adds required keywords filter where all or any must match.
+    pub fn keywords_matching(mut self, keywords: Vec<String>, mode: KeywordMatchMode) -> Self {
         if !keywords.is_empty() {
-            self.chain.add(KeywordFilter::required(keywords));
+            self.chain.add(KeywordFilter::required(keywords).with_match_mode(mode));
         }
         self
     }
@@ -129,6 +240,12 @@ impl FilterChainBuilder {
         self
     }
 
+    /// Adds a user-supplied filter.
+    pub fn custom(mut self, filter: impl Filter + 'static) -> Self {
+        self.chain.add(filter);
+        self
+    }
+
     /// Builds the filter chain.
     pub fn build(self) -> FilterChain {
         self.chain
@@ -166,6 +283,84 @@ impl Filter for SponsoredFilter {
     }
 }
 
+/// Filter that excludes digital products (ebooks, gift cards, codes).
+pub struct DigitalFilter;
+
+impl DigitalFilter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DigitalFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Filter for DigitalFilter {
+    fn matches(&self, product: &Product) -> bool {
+        !product.is_digital
+    }
+
+    fn description(&self) -> String {
+        "Exclude digital".to_string()
+    }
+}
+
+/// Filter that excludes products Amazon flags as frequently returned.
+pub struct FrequentlyReturnedFilter;
+
+impl FrequentlyReturnedFilter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for FrequentlyReturnedFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Filter for FrequentlyReturnedFilter {
+    fn matches(&self, product: &Product) -> bool {
+        !product.is_frequently_returned
+    }
+
+    fn description(&self) -> String {
+        "Exclude frequently returned".to_string()
+    }
+}
+
+/// Filter that drops products whose visible price parsed as 0.00.
+///
+/// Products without a price, or with a hidden "see price in cart" price,
+/// pass: only an explicit zero is suspicious.
+pub struct ZeroPriceFilter;
+
+impl ZeroPriceFilter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ZeroPriceFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Filter for ZeroPriceFilter {
+    fn matches(&self, product: &Product) -> bool {
+        !matches!(&product.price, Some(price) if !price.is_hidden && price.current == 0.0)
+    }
+
+    fn description(&self) -> String {
+        "Drop zero-priced".to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,6 +379,24 @@ mod tests {
             is_amazon_choice: false,
             in_stock: true,
             brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -200,6 +413,24 @@ mod tests {
             is_amazon_choice: false,
             in_stock: true,
             brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -262,6 +493,24 @@ mod tests {
         assert_eq!(filtered.len(), 2);
     }
 
+    #[test]
+    fn test_filter_chain_closure() {
+        let mut chain = FilterChain::new();
+        chain.add(|p: &Product| p.is_prime);
+
+        assert_eq!(chain.descriptions(), vec!["custom filter".to_string()]);
+
+        let products = vec![
+            make_product(25.0, 4.0, true, false),
+            make_product(25.0, 4.0, false, false),
+            make_product(30.0, 4.0, true, true),
+        ];
+
+        let filtered = chain.apply(products);
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().all(|p| p.is_prime));
+    }
+
     #[test]
     fn test_filter_chain_descriptions() {
         let mut chain = FilterChain::new();
@@ -311,6 +560,76 @@ mod tests {
         assert!(chain.is_empty());
     }
 
+    #[test]
+    fn test_filter_chain_builder_from_config() {
+        let config = Config {
+            min_price: Some(10.0),
+            min_rating: Some(4.0),
+            no_sponsored: true,
+            keywords: vec!["gaming".to_string()],
+            ..Config::default()
+        };
+
+        let chain = FilterChainBuilder::from_config(&config).build();
+        assert_eq!(chain.len(), 4);
+
+        let chain = FilterChainBuilder::from_config(&Config::default()).build();
+        assert!(chain.is_empty());
+    }
+
+    #[test]
+    fn test_filter_chain_builder_quality() {
+        let config = Config { quality: Some(Quality::High), ..Config::default() };
+        let chain = FilterChainBuilder::from_config(&config).build();
+        assert_eq!(chain.descriptions(), vec!["Rating: >= 4.5 stars", "Reviews: >= 100"]);
+
+        let rated = |stars: f32, reviews: u32| {
+            let mut product = make_product(25.0, stars, false, false);
+            product.rating = Some(Rating::new(stars, reviews));
+            product
+        };
+        assert!(chain.matches(&rated(4.6, 100)));
+        assert!(!chain.matches(&rated(4.8, 99)));
+        assert!(!chain.matches(&rated(4.4, 5000)));
+
+        let config = Config { quality: Some(Quality::Medium), ..Config::default() };
+        let chain = FilterChainBuilder::from_config(&config).build();
+        assert!(chain.matches(&rated(4.4, 30)));
+    }
+
+    struct FnFilter<F>(F);
+
+    impl<F: Fn(&Product) -> bool + Send + Sync> Filter for FnFilter<F> {
+        fn matches(&self, product: &Product) -> bool {
+            (self.0)(product)
+        }
+
+        fn description(&self) -> String {
+            "Title under 20 chars".to_string()
+        }
+    }
+
+    #[test]
+    fn test_filter_chain_builder_custom() {
+        let chain = FilterChainBuilder::new()
+            .prime_only(true)
+            .custom(FnFilter(|p: &Product| p.title.len() < 20))
+            .build();
+
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain.descriptions()[1], "Title under 20 chars");
+
+        let products = vec![
+            make_product_with_title("Short title", true, false),
+            make_product_with_title("A much longer product title", true, false),
+            make_product_with_title("Short but no Prime", false, false),
+        ];
+
+        let filtered = chain.apply(products);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].title, "Short title");
+    }
+
     #[test]
     fn test_filter_chain_builder_keywords() {
         let chain = FilterChainBuilder::new()
@@ -382,6 +701,86 @@ mod tests {
         assert_eq!(filter.description(), "Exclude sponsored");
     }
 
+    // DigitalFilter tests
+
+    #[test]
+    fn test_digital_filter() {
+        let filter = DigitalFilter::new();
+        assert_eq!(filter.description(), "Exclude digital");
+
+        let physical = make_product_with_title("Paperback Novel", true, false);
+        let mut ebook = make_product_with_title("Novel: Kindle Edition", true, false);
+        ebook.is_digital = true;
+
+        assert!(filter.matches(&physical));
+        assert!(!filter.matches(&ebook));
+    }
+
+    #[test]
+    fn test_filter_chain_builder_exclude_digital() {
+        let config = Config { exclude_digital: true, ..Config::default() };
+        let chain = FilterChainBuilder::from_config(&config).build();
+        assert_eq!(chain.len(), 1);
+
+        let mut gift_card = make_product_with_title("Amazon.com Gift Card", true, false);
+        gift_card.is_digital = true;
+        let products = vec![make_product_with_title("Gaming Mouse", true, false), gift_card];
+
+        let filtered = chain.apply(products);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].title, "Gaming Mouse");
+    }
+
+    // FrequentlyReturnedFilter tests
+
+    #[test]
+    fn test_frequently_returned_filter() {
+        let filter = FrequentlyReturnedFilter::new();
+        assert_eq!(filter.description(), "Exclude frequently returned");
+
+        let mut returned = make_product_with_title("Cheap Earbuds", true, false);
+        returned.is_frequently_returned = true;
+
+        assert!(filter.matches(&make_product_with_title("Good Earbuds", true, false)));
+        assert!(!filter.matches(&returned));
+
+        let config = Config { exclude_frequently_returned: true, ..Config::default() };
+        assert_eq!(FilterChainBuilder::from_config(&config).build().len(), 1);
+    }
+
+    // ZeroPriceFilter tests
+
+    #[test]
+    fn test_zero_price_filter() {
+        let filter = ZeroPriceFilter::new();
+        assert_eq!(filter.description(), "Drop zero-priced");
+
+        let mut hidden = make_product(0.0, 4.0, true, false);
+        hidden.price = Some(Price::hidden("USD"));
+        let mut unpriced = make_product(0.0, 4.0, true, false);
+        unpriced.price = None;
+
+        assert!(!filter.matches(&make_product(0.0, 4.0, true, false)));
+        assert!(filter.matches(&make_product(0.01, 4.0, true, false)));
+        assert!(filter.matches(&hidden));
+        assert!(filter.matches(&unpriced));
+    }
+
+    #[test]
+    fn test_zero_price_kept_unless_enabled() {
+        let products =
+            vec![make_product(0.0, 4.0, true, false), make_product(9.99, 4.0, true, false)];
+
+        let kept =
+            FilterChainBuilder::from_config(&Config::default()).build().apply(products.clone());
+        assert_eq!(kept.len(), 2);
+
+        let config = Config { drop_zero_price: true, ..Config::default() };
+        let filtered = FilterChainBuilder::from_config(&config).build().apply(products);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].current_price(), Some(9.99));
+    }
+
     // Integration test with all filters
 
     #[test]
@@ -0,0 +1,90 @@
+//! Minimum review count filter.
+
+use super::Filter;
+use crate::amazon::Product;
+
+/// Filters products by minimum number of reviews.
+///
+/// Unlike [`RatingFilter`](super::RatingFilter), products without a rating
+/// are excluded: no rating means no reviews.
+pub struct ReviewCountFilter {
+    min_reviews: u32,
+}
+
+impl ReviewCountFilter {
+    /// Creates a new review count filter.
+    pub fn new(min_reviews: u32) -> Self {
+        Self { min_reviews }
+    }
+}
+
+impl Filter for ReviewCountFilter {
+    fn matches(&self, product: &Product) -> bool {
+        let reviews = product.rating.as_ref().map_or(0, |r| r.review_count);
+        reviews >= self.min_reviews
+    }
+
+    fn description(&self) -> String {
+        format!("Reviews: >= {}", self.min_reviews)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amazon::models::Rating;
+
+    fn make_product(rating: Option<Rating>) -> Product {
+        Product {
+            asin: "TEST".to_string(),
+            title: "Test".to_string(),
+            url: "https://amazon.com/dp/TEST".to_string(),
+            image_url: None,
+            price: None,
+            rating,
+            is_sponsored: false,
+            is_prime: false,
+            is_amazon_choice: false,
+            in_stock: true,
+            brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
+        }
+    }
+
+    #[test]
+    fn test_review_count_filter() {
+        let filter = ReviewCountFilter::new(100);
+
+        assert!(!filter.matches(&make_product(Some(Rating::new(4.8, 99)))));
+        assert!(filter.matches(&make_product(Some(Rating::new(4.8, 100)))));
+        assert!(filter.matches(&make_product(Some(Rating::new(3.0, 5000)))));
+    }
+
+    #[test]
+    fn test_no_rating_excluded() {
+        assert!(!ReviewCountFilter::new(1).matches(&make_product(None)));
+        assert!(ReviewCountFilter::new(0).matches(&make_product(None)));
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(ReviewCountFilter::new(100).description(), "Reviews: >= 100");
+    }
+}
@@ -0,0 +1,87 @@
+//! Low-stock ("Only N left") urgency filter.
+
+use super::Filter;
+use crate::amazon::Product;
+
+/// Keeps products showing at most `max_remaining` units left in stock.
+///
+/// Products without a low-stock notice are excluded, since Amazon only
+/// shows a count once stock runs low.
+pub struct StockRemainingFilter {
+    max_remaining: u32,
+}
+
+impl StockRemainingFilter {
+    /// Creates a new stock remaining filter.
+    pub fn new(max_remaining: u32) -> Self {
+        Self { max_remaining }
+    }
+}
+
+impl Filter for StockRemainingFilter {
+    fn matches(&self, product: &Product) -> bool {
+        product.stock_remaining.is_some_and(|left| left <= self.max_remaining)
+    }
+
+    fn description(&self) -> String {
+        format!("Stock remaining: <= {}", self.max_remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_product(stock_remaining: Option<u32>) -> Product {
+        Product {
+            asin: "TEST".to_string(),
+            title: "Test".to_string(),
+            url: "https://amazon.com/dp/TEST".to_string(),
+            image_url: None,
+            price: None,
+            rating: None,
+            is_sponsored: false,
+            is_prime: false,
+            is_amazon_choice: false,
+            in_stock: true,
+            brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining,
+        }
+    }
+
+    #[test]
+    fn test_stock_remaining_filter() {
+        let filter = StockRemainingFilter::new(5);
+
+        assert!(filter.matches(&make_product(Some(1))));
+        assert!(filter.matches(&make_product(Some(5))));
+        assert!(!filter.matches(&make_product(Some(6))));
+    }
+
+    #[test]
+    fn test_no_low_stock_notice_excluded() {
+        assert!(!StockRemainingFilter::new(100).matches(&make_product(None)));
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(StockRemainingFilter::new(5).description(), "Stock remaining: <= 5");
+    }
+}
@@ -0,0 +1,82 @@
+//! Subscribe & Save discount filter.
+
+use super::Filter;
+use crate::amazon::Product;
+
+/// Filters products by minimum Subscribe & Save saving over the one-time price.
+pub struct SubscribeDiscountFilter {
+    min_percent: u8,
+}
+
+impl SubscribeDiscountFilter {
+    /// Creates a new Subscribe & Save discount filter.
+    pub fn new(min_percent: u8) -> Self {
+        Self { min_percent }
+    }
+}
+
+impl Filter for SubscribeDiscountFilter {
+    fn matches(&self, product: &Product) -> bool {
+        // Products without a Subscribe & Save offer are excluded: asking for
+        // an S&S discount means only S&S deals are wanted
+        product.subscribe_discount_percent.is_some_and(|percent| percent >= self.min_percent)
+    }
+
+    fn description(&self) -> String {
+        format!("Subscribe & Save: {}%+ off", self.min_percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_product(subscribe_discount_percent: Option<u8>) -> Product {
+        Product {
+            asin: "TEST".to_string(),
+            title: "Test".to_string(),
+            url: "https://amazon.com/dp/TEST".to_string(),
+            image_url: None,
+            price: None,
+            rating: None,
+            is_sponsored: false,
+            is_prime: false,
+            is_amazon_choice: false,
+            in_stock: true,
+            brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
+        }
+    }
+
+    #[test]
+    fn test_subscribe_discount_threshold() {
+        let filter = SubscribeDiscountFilter::new(10);
+
+        assert!(filter.matches(&make_product(Some(15))));
+        assert!(filter.matches(&make_product(Some(10))));
+        assert!(!filter.matches(&make_product(Some(5))));
+        assert!(!filter.matches(&make_product(None)));
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(SubscribeDiscountFilter::new(15).description(), "Subscribe & Save: 15%+ off");
+    }
+}
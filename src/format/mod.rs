@@ -1,74 +1,458 @@
 //! Output formatting for products (table, JSON, markdown, CSV).
 
-use crate::amazon::Product;
-use crate::config::OutputFormat;
+use crate::amazon::{Product, Region};
+use crate::config::{Config, DecimalSeparator, OutputFormat, RatingScale};
+use std::borrow::Cow;
+
+/// UTF-8 byte-order mark, prepended to CSV output for Excel when requested.
+pub const UTF8_BOM: &str = "\u{feff}";
+
+/// Formats an amount using the region's currency symbol and separators
+/// (e.g. "$1,234.56", "1.234,56 €").
+pub fn format_price(amount: f64, region: Region) -> String {
+    format_price_with_decimals(amount, region, default_decimals(region))
+}
+
+/// Like [`format_price`], but with an explicit number of decimal places.
+pub fn format_price_with_decimals(amount: f64, region: Region, decimals: usize) -> String {
+    format_price_styled(amount, region, decimals, region.uses_comma_decimal())
+}
+
+/// Decimal places a region's currency is normally shown with.
+fn default_decimals(region: Region) -> usize {
+    if region.currency() == "JPY" {
+        0
+    } else {
+        2
+    }
+}
+
+/// Places the region's currency symbol around an amount formatted with the
+/// given decimal separator.
+fn format_price_styled(
+    amount: f64,
+    region: Region,
+    decimals: usize,
+    comma_decimal: bool,
+) -> String {
+    let number = format_number(amount, decimals, comma_decimal);
+
+    let symbol = region.currency_symbol();
+    if region.symbol_after_amount() {
+        format!("{} {}", number, symbol)
+    } else if symbol.chars().any(char::is_alphabetic) {
+        format!("{} {}", symbol, number)
+    } else {
+        format!("{}{}", symbol, number)
+    }
+}
+
+/// Formats a bare amount with thousands grouping, e.g. "1,234.56" or
+/// "1.234,56" when `comma_decimal` is set.
+pub fn format_number(amount: f64, decimals: usize, comma_decimal: bool) -> String {
+    let (thousands, decimal) = if comma_decimal { ('.', ',') } else { (',', '.') };
+
+    let fixed = format!("{:.*}", decimals, amount.abs());
+    let (int_part, frac_part) = fixed.split_once('.').unwrap_or((&fixed, ""));
+
+    let mut number = String::new();
+    if amount < 0.0 {
+        number.push('-');
+    }
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            number.push(thousands);
+        }
+        number.push(c);
+    }
+    if !frac_part.is_empty() {
+        number.push(decimal);
+        number.push_str(frac_part);
+    }
+
+    number
+}
+
+/// Number of decimal places in a currency's smallest unit (ISO 4217 exponent).
+pub fn minor_unit_exponent(currency: &str) -> u32 {
+    match currency {
+        "JPY" | "KRW" | "CLP" | "VND" | "ISK" | "PYG" | "UGX" => 0,
+        "BHD" | "JOD" | "KWD" | "OMR" | "TND" | "LYD" | "IQD" => 3,
+        _ => 2,
+    }
+}
+
+/// Converts an amount to an integer count of the currency's smallest unit,
+/// e.g. 29.98 USD -> 2998, 2999 JPY -> 2999.
+pub fn to_minor_units(amount: f64, currency: &str) -> i64 {
+    (amount * 10f64.powi(minor_unit_exponent(currency) as i32)).round() as i64
+}
+
+/// Separator between table columns unless overridden.
+const DEFAULT_TABLE_SEP: &str = "  ";
+
+/// Title column width when the table width is unknown.
+const DEFAULT_TITLE_WIDTH: usize = 50;
+
+/// Narrowest title column, even if the table then overflows the terminal.
+const MIN_TITLE_WIDTH: usize = 10;
+
+/// Wraps `text` in an OSC 8 escape so terminals render it as a link to `url`.
+fn hyperlink(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Whether any product carries a best seller rank, so lists get a rank column.
+fn has_rank(products: &[Product]) -> bool {
+    products.iter().any(|p| p.rank.is_some())
+}
+
+/// Returns the width of the terminal on stdout, or `None` when it isn't a TTY.
+pub fn terminal_width() -> Option<usize> {
+    terminal_size::terminal_size().map(|(terminal_size::Width(width), _)| width as usize)
+}
 
 /// Formats products for output.
 pub struct Formatter {
     format: OutputFormat,
+    /// Decimal places for rendered prices (None = 2, or the currency's default)
+    price_decimals: Option<usize>,
+    /// Prefix CSV output with a UTF-8 BOM
+    csv_bom: bool,
+    /// Decimal separator override (None = the currency's convention)
+    decimal: Option<DecimalSeparator>,
+    /// Replace product URLs with their canonical `/dp/<asin>` form
+    canonical_urls: bool,
+    /// Add an image thumbnail column to markdown tables
+    md_table_images: bool,
+    /// Render markdown product lists as a task list instead of a table
+    md_tasklist: bool,
+    /// Render CSV/JSON prices as integer minor units
+    minor_units: bool,
+    /// Written in CSV for absent optional fields
+    null_value: String,
+    /// End the output with a newline, in every format
+    trailing_newline: bool,
+    /// Column separator in table product lists
+    table_sep: String,
+    /// Total table width to fit (None = fixed column widths)
+    table_width: Option<usize>,
+    /// Render table titles as OSC 8 hyperlinks to the product URL
+    hyperlinks: bool,
+    /// Scale ratings are rendered on (parsed ratings are 0-5)
+    rating_scale: RatingScale,
 }
 
 impl Formatter {
     /// Creates a new formatter.
     pub fn new(format: OutputFormat) -> Self {
-        Self { format }
+        Self {
+            format,
+            price_decimals: None,
+            csv_bom: false,
+            decimal: None,
+            canonical_urls: false,
+            md_table_images: false,
+            md_tasklist: false,
+            minor_units: false,
+            null_value: String::new(),
+            trailing_newline: false,
+            table_sep: DEFAULT_TABLE_SEP.to_string(),
+            table_width: None,
+            hyperlinks: false,
+            rating_scale: RatingScale::Five,
+        }
+    }
+
+    /// Creates a formatter using the output settings in `config`.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            format: config.format,
+            price_decimals: config.price_decimals,
+            csv_bom: config.csv_bom,
+            decimal: config.decimal,
+            canonical_urls: config.canonical_urls,
+            md_table_images: config.md_table_images,
+            md_tasklist: config.md_tasklist,
+            minor_units: config.minor_units,
+            null_value: config.null_value.clone(),
+            trailing_newline: config.trailing_newline,
+            table_sep: config.table_sep.clone().unwrap_or_else(|| DEFAULT_TABLE_SEP.to_string()),
+            table_width: config.table_width,
+            hyperlinks: config.hyperlinks,
+            rating_scale: config.rating_scale,
+        }
+    }
+
+    /// Sets the column separator used in table product lists.
+    pub fn with_table_sep(mut self, table_sep: impl Into<String>) -> Self {
+        self.table_sep = table_sep.into();
+        self
+    }
+
+    /// Fits table product lists into `width` columns by shrinking the title column.
+    pub fn with_table_width(mut self, width: usize) -> Self {
+        self.table_width = Some(width);
+        self
+    }
+
+    /// Renders titles in table product lists as clickable OSC 8 hyperlinks.
+    pub fn with_hyperlinks(mut self, hyperlinks: bool) -> Self {
+        self.hyperlinks = hyperlinks;
+        self
+    }
+
+    /// Sets the decimal places used for prices in table and markdown output.
+    pub fn with_price_decimals(mut self, decimals: usize) -> Self {
+        self.price_decimals = Some(decimals);
+        self
+    }
+
+    /// Renders prices with the given decimal separator regardless of currency.
+    pub fn with_decimal(mut self, decimal: DecimalSeparator) -> Self {
+        self.decimal = Some(decimal);
+        self
+    }
+
+    /// Rewrites product URLs to `https://www.<domain>/dp/<asin>` in every format.
+    pub fn with_canonical_urls(mut self, canonical_urls: bool) -> Self {
+        self.canonical_urls = canonical_urls;
+        self
+    }
+
+    /// Adds an Image column with thumbnails to the markdown product table.
+    pub fn with_md_table_images(mut self, md_table_images: bool) -> Self {
+        self.md_table_images = md_table_images;
+        self
+    }
+
+    /// Renders markdown product lists as a task list, one `- [ ]` item per product.
+    pub fn with_md_tasklist(mut self, md_tasklist: bool) -> Self {
+        self.md_tasklist = md_tasklist;
+        self
+    }
+
+    /// Renders CSV/JSON prices as integer minor units (cents) of their currency.
+    pub fn with_minor_units(mut self, minor_units: bool) -> Self {
+        self.minor_units = minor_units;
+        self
+    }
+
+    /// Writes `null_value` in CSV for absent price, original price, rating,
+    /// review count and brand (empty by default).
+    pub fn with_null_value(mut self, null_value: impl Into<String>) -> Self {
+        self.null_value = null_value.into();
+        self
+    }
+
+    /// Ends every format's output with a newline (off by default, so the
+    /// output can be embedded; the CLI turns it on unless `--no-trailing-newline`).
+    pub fn with_trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Renders ratings on a 0-10 or 0-100 scale instead of 0-5 stars, in
+    /// every format. JSON ratings then carry the scale as `rating.scale`.
+    pub fn with_rating_scale(mut self, rating_scale: RatingScale) -> Self {
+        self.rating_scale = rating_scale;
+        self
+    }
+
+    /// Prefixes CSV output with a UTF-8 BOM so Excel detects the encoding.
+    pub fn with_csv_bom(mut self, csv_bom: bool) -> Self {
+        self.csv_bom = csv_bom;
+        self
     }
 
     /// Formats a single product.
     pub fn format_product(&self, product: &Product) -> String {
-        match self.format {
+        let products = self.prepare(std::slice::from_ref(product));
+        let product = &products[0];
+
+        let output = match self.format {
             OutputFormat::Json => self.json_single(product),
             OutputFormat::Table => self.table_single(product),
             OutputFormat::Markdown => self.markdown_single(product),
-            OutputFormat::Csv => self.csv_products(std::slice::from_ref(product)),
-        }
+            OutputFormat::Csv => self.csv_output(self.csv_products(std::slice::from_ref(product))),
+        };
+        self.finish(output)
     }
 
     /// Formats multiple products.
     pub fn format_products(&self, products: &[Product]) -> String {
+        let products = &*self.prepare(products);
+
         if products.is_empty() {
-            return match self.format {
+            let output = match self.format {
                 OutputFormat::Json => "[]".to_string(),
-                OutputFormat::Csv => self.csv_header(),
+                OutputFormat::Csv => self.csv_output(self.csv_header()),
                 _ => "No products found.".to_string(),
             };
+            return self.finish(output);
         }
 
-        match self.format {
+        let output = match self.format {
             OutputFormat::Json => self.json_products(products),
             OutputFormat::Table => self.table_products(products),
             OutputFormat::Markdown => self.markdown_products(products),
-            OutputFormat::Csv => self.csv_products(products),
+            OutputFormat::Csv => self.csv_output(self.csv_products(products)),
+        };
+        self.finish(output)
+    }
+
+    /// Appends the trailing newline when enabled.
+    fn finish(&self, mut output: String) -> String {
+        if self.trailing_newline {
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Applies URL rewriting, borrowing the products untouched when it's off.
+    fn prepare<'a>(&self, products: &'a [Product]) -> Cow<'a, [Product]> {
+        if !self.canonical_urls {
+            return Cow::Borrowed(products);
         }
+
+        Cow::Owned(
+            products.iter().map(|p| Product { url: p.canonical_url(), ..p.clone() }).collect(),
+        )
+    }
+
+    /// Formats an amount in the style of the region using `currency`, falling
+    /// back to "CODE 12.34" for currencies without a known region.
+    fn money(&self, amount: f64, currency: &str) -> String {
+        let comma_decimal = self.decimal.map(|d| d == DecimalSeparator::Comma);
+        match Region::from_currency(currency) {
+            Some(region) => format_price_styled(
+                amount,
+                region,
+                self.price_decimals.unwrap_or_else(|| default_decimals(region)),
+                comma_decimal.unwrap_or_else(|| region.uses_comma_decimal()),
+            ),
+            None => match comma_decimal {
+                Some(comma) => {
+                    format!("{} {}", currency, format_number(amount, self.decimals(), comma))
+                }
+                None => format!("{} {:.*}", currency, self.decimals(), amount),
+            },
+        }
+    }
+
+    /// Decimal places for plain (non-localized) prices.
+    fn decimals(&self) -> usize {
+        self.price_decimals.unwrap_or(2)
     }
 
     // JSON formatting
 
     fn json_single(&self, product: &Product) -> String {
+        if self.rewrites_json() {
+            return serde_json::to_string_pretty(&self.json_value(product))
+                .unwrap_or_else(|_| "{}".to_string());
+        }
         serde_json::to_string_pretty(product).unwrap_or_else(|_| "{}".to_string())
     }
 
     fn json_products(&self, products: &[Product]) -> String {
+        if self.rewrites_json() {
+            let values: Vec<_> = products.iter().map(|p| self.json_value(p)).collect();
+            return serde_json::to_string_pretty(&values).unwrap_or_else(|_| "[]".to_string());
+        }
         serde_json::to_string_pretty(products).unwrap_or_else(|_| "[]".to_string())
     }
 
+    /// Whether JSON products need rewriting (minor units or a rating scale).
+    fn rewrites_json(&self) -> bool {
+        self.minor_units || self.rating_scale != RatingScale::Five
+    }
+
+    /// Serializes a product with the minor-unit and rating-scale options applied.
+    fn json_value(&self, product: &Product) -> serde_json::Value {
+        let mut value = if self.minor_units {
+            Self::json_minor_units(product)
+        } else {
+            serde_json::to_value(product).unwrap_or_default()
+        };
+
+        if let (Some(rating), Some(obj)) =
+            (&product.rating, value.get_mut("rating").and_then(|v| v.as_object_mut()))
+        {
+            if self.rating_scale != RatingScale::Five {
+                obj.insert("stars".into(), self.rating_scale.rescale(rating.stars).into());
+                obj.insert("scale".into(), self.rating_scale.max().into());
+            }
+        }
+
+        value
+    }
+
+    /// Serializes a product with every price amount replaced by integer minor units.
+    fn json_minor_units(product: &Product) -> serde_json::Value {
+        let mut value = serde_json::to_value(product).unwrap_or_default();
+        let Some(price) = &product.price else {
+            return value;
+        };
+
+        let minor = |amount: f64| serde_json::Value::from(to_minor_units(amount, &price.currency));
+        let optional = |amount: Option<f64>| amount.map_or(serde_json::Value::Null, minor);
+
+        if let Some(obj) = value.get_mut("price").and_then(|v| v.as_object_mut()) {
+            obj.insert("current".into(), minor(price.current));
+            obj.insert("original".into(), optional(price.original));
+            obj.insert("list_price".into(), optional(price.list_price));
+            if let Some(range) = obj.get_mut("range").and_then(|v| v.as_object_mut()) {
+                if let Some(r) = &price.range {
+                    range.insert("min".into(), minor(r.min));
+                    range.insert("max".into(), optional(r.max));
+                }
+            }
+        }
+
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("subscribe_price".into(), optional(product.subscribe_price));
+            obj.insert("other_sellers_from".into(), optional(product.other_sellers_from));
+            if let Some(unit) = obj.get_mut("unit_price").and_then(|v| v.as_object_mut()) {
+                if let Some(u) = &product.unit_price {
+                    unit.insert("amount".into(), minor(u.amount));
+                }
+            }
+        }
+
+        value
+    }
+
     // Table formatting
 
     fn table_single(&self, product: &Product) -> String {
         let mut lines = Vec::new();
 
         lines.push(format!("ASIN:    {}", product.asin));
+        if let Some(rank) = product.rank {
+            lines.push(format!("Rank:    #{}", rank));
+        }
         lines.push(format!("Title:   {}", product.title));
+        if product.is_frequently_returned {
+            lines.push("Warning: Frequently returned item".to_string());
+        }
+        if product.ships_internationally == Some(false) {
+            lines.push("Warning: Does not ship to your location".to_string());
+        }
         lines.push(format!("URL:     {}", product.url));
 
         if let Some(price) = &product.price {
             if price.is_hidden {
                 lines.push("Price:   See price in cart".to_string());
             } else {
+                let decimals = self.decimals();
                 let price_str = if let Some(orig) = price.original {
-                    format!("{} {:.2} (was {:.2})", price.currency, price.current, orig)
+                    format!(
+                        "{} {:.*} (was {:.*})",
+                        price.currency, decimals, price.current, decimals, orig
+                    )
                 } else {
-                    format!("{} {:.2}", price.currency, price.current)
+                    format!("{} {:.*}", price.currency, decimals, price.current)
                 };
                 lines.push(format!("Price:   {}", price_str));
             }
@@ -76,8 +460,38 @@ impl Formatter {
             lines.push("Price:   N/A".to_string());
         }
 
+        if let Some(from) = product.other_sellers_from {
+            let currency = product.price.as_ref().map_or("", |p| p.currency.as_str());
+            lines.push(format!("Others:  from {} {:.*}", currency, self.decimals(), from));
+        }
+
+        if let Some(unit_price) = &product.unit_price {
+            let currency = product.price.as_ref().map_or("", |p| p.currency.as_str());
+            lines.push(format!(
+                "Unit:    {} {:.*}/{}",
+                currency,
+                self.decimals(),
+                unit_price.amount,
+                unit_price.unit
+            ));
+        }
+
+        if let Some(subscribe) = product.subscribe_price {
+            let currency = product.price.as_ref().map_or("", |p| p.currency.as_str());
+            let mut line = format!("S&S:     {} {:.*}", currency, self.decimals(), subscribe);
+            if let Some(percent) = product.subscribe_discount_percent {
+                line.push_str(&format!(" (-{}%)", percent));
+            }
+            lines.push(line);
+        }
+
         if let Some(rating) = &product.rating {
-            lines.push(format!("Rating:  {:.1}/5 ({} reviews)", rating.stars, rating.review_count));
+            lines.push(format!(
+                "Rating:  {}/{} ({} reviews)",
+                self.rating_scale.format(rating.stars),
+                self.rating_scale.max(),
+                rating.review_count
+            ));
         } else {
             lines.push("Rating:  N/A".to_string());
         }
@@ -100,10 +514,31 @@ impl Formatter {
             lines.push(format!("Brand:   {}", brand));
         }
 
-        lines.push(format!(
-            "Stock:   {}",
-            if product.in_stock { "In Stock" } else { "Out of Stock" }
-        ));
+        if let Some(promotion) = &product.promotion {
+            lines.push(format!("Promo:   {}", promotion));
+        }
+
+        if let Some(code) = &product.coupon_code {
+            lines.push(format!("Code:    {}", code));
+        }
+
+        if let Some(class) = product.energy_class {
+            lines.push(format!("Energy:  {}", class));
+        }
+
+        if let Some(dimensions) = &product.dimensions {
+            lines.push(format!("Size:    {}", dimensions));
+        }
+
+        if let Some(weight) = &product.weight {
+            lines.push(format!("Weight:  {}", weight));
+        }
+
+        let stock = if product.in_stock { "In Stock" } else { "Out of Stock" };
+        match product.stock_remaining {
+            Some(left) => lines.push(format!("Stock:   {} (only {} left)", stock, left)),
+            None => lines.push(format!("Stock:   {}", stock)),
+        }
 
         lines.join("\n")
     }
@@ -114,44 +549,69 @@ impl Formatter {
         let price_width = 12;
         let rating_width = 8;
         let prime_width = 5;
-        let title_width = 50;
+        let rank_width = 4;
+        let sep = self.table_sep.as_str();
+        // Best seller lists get a leading rank column
+        let ranked = has_rank(products);
+        let rank_cell = |rank: &str, fill: bool| match (ranked, fill) {
+            (false, _) => String::new(),
+            (true, false) => format!("{:>rank_width$}{sep}", rank),
+            (true, true) => format!("{:->rank_width$}{sep}", rank),
+        };
+        // The title takes whatever the fixed columns and separators leave over
+        let title_width = match self.table_width {
+            Some(width) => {
+                let fixed = asin_width
+                    + price_width
+                    + rating_width
+                    + prime_width
+                    + 4 * sep.chars().count()
+                    + rank_cell("", false).chars().count();
+                width.saturating_sub(fixed).max(MIN_TITLE_WIDTH)
+            }
+            None => DEFAULT_TITLE_WIDTH,
+        };
 
         let mut lines = Vec::new();
 
         // Header
         lines.push(format!(
-            "{:<asin_width$}  {:<price_width$}  {:<rating_width$}  {:<prime_width$}  {}",
-            "ASIN", "Price", "Rating", "Prime", "Title"
+            "{}{:<asin_width$}{sep}{:<price_width$}{sep}{:<rating_width$}{sep}{:<prime_width$}{sep}{}",
+            rank_cell("#", false), "ASIN", "Price", "Rating", "Prime", "Title"
         ));
         lines.push(format!(
-            "{:-<asin_width$}  {:-<price_width$}  {:-<rating_width$}  {:-<prime_width$}  {:-<title_width$}",
-            "", "", "", "", ""
+            "{}{:-<asin_width$}{sep}{:-<price_width$}{sep}{:-<rating_width$}{sep}{:-<prime_width$}{sep}{:-<title_width$}",
+            rank_cell("", true), "", "", "", "", ""
         ));
 
         // Rows
         for product in products {
             let price_str = match &product.price {
-                Some(p) if !p.is_hidden => format!("{:.2}", p.current),
+                Some(p) if !p.is_hidden => format!("{:.*}", self.decimals(), p.current),
                 Some(_) => "In cart".to_string(),
                 None => "N/A".to_string(),
             };
 
             let rating_str = match &product.rating {
-                Some(r) => format!("{:.1}", r.stars),
+                Some(r) => self.rating_scale.format(r.stars),
                 None => "N/A".to_string(),
             };
 
             let prime_str = if product.is_prime { "Yes" } else { "No" };
 
-            let title = if product.title.len() > title_width {
-                format!("{}...", &product.title[..title_width - 3])
+            let title = if product.title.chars().count() > title_width {
+                let kept: String = product.title.chars().take(title_width - 3).collect();
+                format!("{}...", kept)
             } else {
                 product.title.clone()
             };
+            // Wrap after truncating so the escape bytes don't count toward the width
+            let title = if self.hyperlinks { hyperlink(&product.url, &title) } else { title };
 
+            let rank = product.rank.map(|r| r.to_string()).unwrap_or_default();
             lines.push(format!(
-                "{:<asin_width$}  {:>price_width$}  {:>rating_width$}  {:<prime_width$}  {}",
-                product.asin, price_str, rating_str, prime_str, title
+                "{}{:<asin_width$}{sep}{:>price_width$}{sep}{:>rating_width$}{sep}{:<prime_width$}{sep}{}",
+                rank_cell(&rank, false), product.asin, price_str, rating_str, prime_str, title
             ));
         }
 
@@ -169,7 +629,19 @@ impl Formatter {
         lines.push(format!("## {}", product.title));
         lines.push(String::new());
 
+        if product.is_frequently_returned {
+            lines.push("> ⚠️ **Frequently returned item**".to_string());
+            lines.push(String::new());
+        }
+        if product.ships_internationally == Some(false) {
+            lines.push("> ⚠️ **Does not ship to your location**".to_string());
+            lines.push(String::new());
+        }
+
         lines.push(format!("- **ASIN:** {}", product.asin));
+        if let Some(rank) = product.rank {
+            lines.push(format!("- **Best seller rank:** #{}", rank));
+        }
         lines.push(format!("- **URL:** [View on Amazon]({})", product.url));
 
         if let Some(price) = &product.price {
@@ -177,18 +649,44 @@ impl Formatter {
                 lines.push("- **Price:** See price in cart".to_string());
             } else if let Some(orig) = price.original {
                 lines.push(format!(
-                    "- **Price:** {} {:.2} ~~{:.2}~~",
-                    price.currency, price.current, orig
+                    "- **Price:** {} ~~{}~~",
+                    self.money(price.current, &price.currency),
+                    self.money(orig, &price.currency)
                 ));
             } else {
-                lines.push(format!("- **Price:** {} {:.2}", price.currency, price.current));
+                lines.push(format!("- **Price:** {}", self.money(price.current, &price.currency)));
+            }
+        }
+
+        if let Some(from) = product.other_sellers_from {
+            let currency = product.price.as_ref().map_or("", |p| p.currency.as_str());
+            lines.push(format!("- **Other sellers:** from {}", self.money(from, currency)));
+        }
+
+        if let Some(unit_price) = &product.unit_price {
+            let currency = product.price.as_ref().map_or("", |p| p.currency.as_str());
+            lines.push(format!(
+                "- **Unit price:** {}/{}",
+                self.money(unit_price.amount, currency),
+                unit_price.unit
+            ));
+        }
+
+        if let Some(subscribe) = product.subscribe_price {
+            let currency = product.price.as_ref().map_or("", |p| p.currency.as_str());
+            let mut line = format!("- **Subscribe & Save:** {}", self.money(subscribe, currency));
+            if let Some(percent) = product.subscribe_discount_percent {
+                line.push_str(&format!(" (-{}%)", percent));
             }
+            lines.push(line);
         }
 
         if let Some(rating) = &product.rating {
             lines.push(format!(
-                "- **Rating:** {:.1}/5 ({} reviews)",
-                rating.stars, rating.review_count
+                "- **Rating:** {}/{} ({} reviews)",
+                self.rating_scale.format(rating.stars),
+                self.rating_scale.max(),
+                rating.review_count
             ));
         }
 
@@ -196,6 +694,30 @@ impl Formatter {
             lines.push(format!("- **Brand:** {}", brand));
         }
 
+        if let Some(left) = product.stock_remaining {
+            lines.push(format!("- **Stock:** only {} left", left));
+        }
+
+        if let Some(promotion) = &product.promotion {
+            lines.push(format!("- **Promotion:** {}", promotion));
+        }
+
+        if let Some(code) = &product.coupon_code {
+            lines.push(format!("- **Coupon code:** `{}`", code));
+        }
+
+        if let Some(class) = product.energy_class {
+            lines.push(format!("- **Energy class:** {}", class));
+        }
+
+        if let Some(dimensions) = &product.dimensions {
+            lines.push(format!("- **Dimensions:** {}", dimensions));
+        }
+
+        if let Some(weight) = &product.weight {
+            lines.push(format!("- **Weight:** {}", weight));
+        }
+
         let mut badges = Vec::new();
         if product.is_prime {
             badges.push("✓ Prime");
@@ -211,20 +733,44 @@ impl Formatter {
     }
 
     fn markdown_products(&self, products: &[Product]) -> String {
+        if self.md_tasklist {
+            return self.markdown_tasklist(products);
+        }
+
         let mut lines = Vec::new();
+        let ranked = has_rank(products);
 
-        lines.push("| ASIN | Price | Rating | Prime | Title |".to_string());
-        lines.push("|------|-------|--------|-------|-------|".to_string());
+        let (mut header, mut rule) = if ranked {
+            ("| # ".to_string(), "|---".to_string())
+        } else {
+            (String::new(), String::new())
+        };
+        if self.md_table_images {
+            header.push_str("| Image | ASIN | Price | Rating | Prime | Title |");
+            rule.push_str("|-------|------|-------|--------|-------|-------|");
+        } else {
+            header.push_str("| ASIN | Price | Rating | Prime | Title |");
+            rule.push_str("|------|-------|--------|-------|-------|");
+        }
+        lines.push(header);
+        lines.push(rule);
 
         for product in products {
             let price_str = match &product.price {
-                Some(p) if !p.is_hidden => format!("{:.2}", p.current),
+                Some(p) if !p.is_hidden => match p.original {
+                    Some(orig) => format!(
+                        "{} ~~{}~~",
+                        self.money(p.current, &p.currency),
+                        self.money(orig, &p.currency)
+                    ),
+                    None => self.money(p.current, &p.currency),
+                },
                 Some(_) => "In cart".to_string(),
                 None => "N/A".to_string(),
             };
 
             let rating_str = match &product.rating {
-                Some(r) => format!("{:.1}", r.stars),
+                Some(r) => self.rating_scale.format(r.stars),
                 None => "N/A".to_string(),
             };
 
@@ -236,9 +782,29 @@ impl Formatter {
                 product.title.clone()
             };
 
+            let rank_cell = match product.rank {
+                Some(rank) => format!("| {} ", rank),
+                None if ranked => "| ".to_string(),
+                None => String::new(),
+            };
+
+            let image_cell = if self.md_table_images {
+                let image = product.image_url.as_ref().map(|url| format!("![]({})", url));
+                format!("| {} ", image.unwrap_or_default())
+            } else {
+                String::new()
+            };
+
             lines.push(format!(
-                "| {} | {} | {} | {} | [{}]({}) |",
-                product.asin, price_str, rating_str, prime_str, title, product.url
+                "{}{}| {} | {} | {} | {} | [{}]({}) |",
+                rank_cell,
+                image_cell,
+                product.asin,
+                price_str,
+                rating_str,
+                prime_str,
+                title,
+                product.url
             ));
         }
 
@@ -248,42 +814,103 @@ impl Formatter {
         lines.join("\n")
     }
 
+    /// Renders `- [ ] [Title](url) — $29.99 (4.5★)`, omitting a missing or
+    /// hidden price and a missing rating.
+    fn markdown_tasklist(&self, products: &[Product]) -> String {
+        products
+            .iter()
+            .map(|product| {
+                let mut line = format!("- [ ] [{}]({})", product.title, product.url);
+                if let Some(p) = product.price.as_ref().filter(|p| !p.is_hidden) {
+                    line.push_str(&format!(" — {}", self.money(p.current, &p.currency)));
+                }
+                match &product.rating {
+                    Some(r) if self.rating_scale == RatingScale::Five => {
+                        line.push_str(&format!(" ({:.1}★)", r.stars));
+                    }
+                    Some(r) => line.push_str(&format!(
+                        " ({}/{})",
+                        self.rating_scale.format(r.stars),
+                        self.rating_scale.max()
+                    )),
+                    None => {}
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     // CSV formatting
 
+    fn csv_output(&self, csv: String) -> String {
+        if self.csv_bom {
+            format!("{}{}", UTF8_BOM, csv)
+        } else {
+            csv
+        }
+    }
+
     fn csv_header(&self) -> String {
         "asin,title,price,original_price,currency,rating,reviews,prime,sponsored,amazon_choice,in_stock,brand,url"
             .to_string()
     }
 
+    /// Renders a CSV amount as a decimal, or as integer minor units when enabled.
+    fn csv_amount(&self, amount: f64, currency: &str) -> String {
+        if self.minor_units {
+            to_minor_units(amount, currency).to_string()
+        } else {
+            amount.to_string()
+        }
+    }
+
     fn csv_products(&self, products: &[Product]) -> String {
+        let ranked = has_rank(products);
         let mut lines = Vec::new();
-        lines.push(self.csv_header());
+        lines.push(if ranked { format!("rank,{}", self.csv_header()) } else { self.csv_header() });
 
         for product in products {
+            let null = || Self::csv_escape(&self.null_value);
+            let rank_cell = match product.rank {
+                Some(rank) => format!("{},", rank),
+                None if ranked => format!("{},", null()),
+                None => String::new(),
+            };
+
             let price = product
                 .price
                 .as_ref()
-                .map(|p| if p.is_hidden { String::new() } else { p.current.to_string() })
-                .unwrap_or_default();
+                .filter(|p| !p.is_hidden)
+                .map(|p| self.csv_amount(p.current, &p.currency))
+                .unwrap_or_else(null);
 
             let original = product
                 .price
                 .as_ref()
-                .and_then(|p| p.original.map(|o| o.to_string()))
-                .unwrap_or_default();
+                .and_then(|p| p.original.map(|o| self.csv_amount(o, &p.currency)))
+                .unwrap_or_else(null);
 
             let currency = product.price.as_ref().map(|p| p.currency.clone()).unwrap_or_default();
 
-            let rating = product.rating.as_ref().map(|r| r.stars.to_string()).unwrap_or_default();
+            let rating = product
+                .rating
+                .as_ref()
+                .map(|r| match self.rating_scale {
+                    RatingScale::Five => r.stars.to_string(),
+                    scale => scale.rescale(r.stars).to_string(),
+                })
+                .unwrap_or_else(null);
 
             let reviews =
-                product.rating.as_ref().map(|r| r.review_count.to_string()).unwrap_or_default();
+                product.rating.as_ref().map(|r| r.review_count.to_string()).unwrap_or_else(null);
 
             let title = Self::csv_escape(&product.title);
-            let brand = product.brand.as_ref().map(|b| Self::csv_escape(b)).unwrap_or_default();
+            let brand = product.brand.as_ref().map(|b| Self::csv_escape(b)).unwrap_or_else(null);
 
             lines.push(format!(
-                "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                "{}{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                rank_cell,
                 product.asin,
                 title,
                 price,
@@ -315,7 +942,7 @@ impl Formatter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::amazon::models::{Price, Rating};
+    use crate::amazon::models::{Price, Rating, UnitPrice};
 
     fn make_product() -> Product {
         Product {
@@ -330,6 +957,24 @@ mod tests {
             is_amazon_choice: true,
             in_stock: true,
             brand: Some("TestBrand".to_string()),
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -346,6 +991,24 @@ mod tests {
             is_amazon_choice: false,
             in_stock: false,
             brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -362,6 +1025,24 @@ mod tests {
             is_amazon_choice: false,
             in_stock: true,
             brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -378,6 +1059,24 @@ mod tests {
             is_amazon_choice: false,
             in_stock: true,
             brand: None,
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -394,6 +1093,24 @@ mod tests {
             is_amazon_choice: false,
             in_stock: true,
             brand: Some("LongBrand".to_string()),
+            related_asins: Vec::new(),
+            currency_mismatch: false,
+            is_digital: false,
+            is_frequently_returned: false,
+            promotion: None,
+            energy_class: None,
+            dimensions: None,
+            weight: None,
+            latest_review_date: None,
+            other_sellers_from: None,
+            rank: None,
+            coupon_code: None,
+            subscribe_price: None,
+            subscribe_discount_percent: None,
+            raw: None,
+            unit_price: None,
+            ships_internationally: None,
+            stock_remaining: None,
         }
     }
 
@@ -465,6 +1182,50 @@ mod tests {
         assert!(output.contains("Stock:   Out of Stock"));
     }
 
+    #[test]
+    fn test_rating_scale() {
+        let product = make_product();
+        let formatter = |format, scale| Formatter::new(format).with_rating_scale(scale);
+
+        let table = formatter(OutputFormat::Table, RatingScale::Ten).format_product(&product);
+        assert!(table.contains("Rating:  9.0/10 (1234 reviews)"));
+        let list = formatter(OutputFormat::Table, RatingScale::Hundred)
+            .format_products(std::slice::from_ref(&product));
+        assert!(list.contains(" 90 "));
+
+        let markdown =
+            formatter(OutputFormat::Markdown, RatingScale::Hundred).format_product(&product);
+        assert!(markdown.contains("- **Rating:** 90/100 (1234 reviews)"));
+
+        let csv = formatter(OutputFormat::Csv, RatingScale::Ten).format_product(&product);
+        assert!(csv.lines().nth(1).unwrap().contains(",9,1234,"));
+
+        let json = formatter(OutputFormat::Json, RatingScale::Hundred).format_product(&product);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["rating"]["stars"], 90.0);
+        assert_eq!(value["rating"]["scale"], 100);
+
+        // The default 5-star scale leaves JSON untouched
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"stars\": 4.5"));
+        assert!(!json.contains("\"scale\""));
+    }
+
+    #[test]
+    fn test_single_stock_remaining() {
+        let mut product = make_product();
+        product.stock_remaining = Some(3);
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Stock:   In Stock (only 3 left)"));
+
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Stock:** only 3 left"));
+
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"stock_remaining\": 3"));
+    }
+
     #[test]
     fn test_table_single_hidden_price() {
         let formatter = Formatter::new(OutputFormat::Table);
@@ -521,6 +1282,46 @@ mod tests {
         assert!(output.contains("..."));
     }
 
+    #[test]
+    fn test_table_fits_narrow_width() {
+        let products = vec![make_long_title_product(), make_product()];
+        let output =
+            Formatter::new(OutputFormat::Table).with_table_width(60).format_products(&products);
+
+        let rows: Vec<&str> = output.lines().take(4).collect();
+        assert!(rows.iter().all(|row| row.chars().count() <= 60), "{:?}", rows);
+        assert!(rows[2].ends_with("..."));
+        assert!(rows[2].contains("This is a ver"));
+
+        // Too narrow to fit: the title keeps a minimum width
+        let output =
+            Formatter::new(OutputFormat::Table).with_table_width(20).format_products(&products);
+        assert!(output.lines().nth(2).unwrap().ends_with("This is..."));
+    }
+
+    #[test]
+    fn test_table_sep() {
+        let output = Formatter::new(OutputFormat::Table)
+            .with_table_sep(" | ")
+            .format_products(&[make_product()]);
+
+        assert!(output.starts_with("ASIN       | Price        | Rating   | Prime | Title"));
+        assert!(output.lines().nth(2).unwrap().starts_with("B08N5WRWNW |"));
+    }
+
+    #[test]
+    fn test_table_hyperlinks() {
+        let product = make_product();
+        let products = std::slice::from_ref(&product);
+        let plain = Formatter::new(OutputFormat::Table).format_products(products);
+        assert!(!plain.contains('\x1b'));
+
+        let linked =
+            Formatter::new(OutputFormat::Table).with_hyperlinks(true).format_products(products);
+        let expected = format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", product.url, product.title);
+        assert!(linked.lines().nth(2).unwrap().ends_with(&expected));
+    }
+
     #[test]
     fn test_table_hidden_price_in_list() {
         let formatter = Formatter::new(OutputFormat::Table);
@@ -548,7 +1349,7 @@ mod tests {
         assert!(output.contains("## Test Product Title"));
         assert!(output.contains("- **ASIN:** B08N5WRWNW"));
         assert!(output.contains("- **URL:** [View on Amazon](https://amazon.com/dp/B08N5WRWNW)"));
-        assert!(output.contains("- **Price:** USD 29.99 ~~39.99~~"));
+        assert!(output.contains("- **Price:** $29.99 ~~$39.99~~"));
         assert!(output.contains("- **Rating:** 4.5/5 (1234 reviews)"));
         assert!(output.contains("- **Brand:** TestBrand"));
         assert!(output.contains("✓ Prime"));
@@ -584,10 +1385,308 @@ mod tests {
         let product = make_sponsored_product();
         let output = formatter.format_product(&product);
 
-        assert!(output.contains("- **Price:** USD 19.99"));
+        assert!(output.contains("- **Price:** $19.99"));
         assert!(!output.contains("~~")); // No strikethrough for non-discounted
     }
 
+    #[test]
+    fn test_markdown_single_eu_discount() {
+        let formatter = Formatter::new(OutputFormat::Markdown);
+        let mut product = make_product();
+        product.price = Some(Price::with_discount(1234.5, 1499.99, "EUR"));
+        let output = formatter.format_product(&product);
+
+        assert!(output.contains("- **Price:** 1.234,50 € ~~1.499,99 €~~"));
+    }
+
+    #[test]
+    fn test_markdown_list_eu_discount() {
+        let formatter = Formatter::new(OutputFormat::Markdown);
+        let mut product = make_product();
+        product.price = Some(Price::with_discount(29.99, 39.99, "EUR"));
+        let output = formatter.format_products(&[product]);
+
+        assert!(output.contains("| 29,99 € ~~39,99 €~~ |"));
+    }
+
+    #[test]
+    fn test_format_price_regions() {
+        assert_eq!(format_price(29.99, Region::Us), "$29.99");
+        assert_eq!(format_price(1234.56, Region::Us), "$1,234.56");
+        assert_eq!(format_price(1234.56, Region::De), "1.234,56 €");
+        assert_eq!(format_price(1234567.0, Region::Fr), "1.234.567,00 €");
+        assert_eq!(format_price(19.5, Region::Uk), "£19.50");
+        assert_eq!(format_price(2999.0, Region::Jp), "¥2,999");
+        assert_eq!(format_price(99.9, Region::Br), "R$ 99,90");
+        assert_eq!(format_price(0.99, Region::Se), "0,99 kr");
+    }
+
+    #[test]
+    fn test_format_price_turkey() {
+        assert_eq!(format_price(1234.56, Region::Tr), "1.234,56 ₺");
+        assert_eq!(format_price(89.9, Region::Tr), "89,90 ₺");
+
+        let formatter = Formatter::new(OutputFormat::Markdown);
+        let mut product = make_product();
+        product.price = Some(Price::with_discount(1234.56, 1499.0, "TRY"));
+        let output = formatter.format_product(&product);
+        assert!(output.contains("- **Price:** 1.234,56 ₺ ~~1.499,00 ₺~~"));
+    }
+
+    #[test]
+    fn test_canonical_urls_all_formats() {
+        let mut product = make_product();
+        product.url =
+            "https://www.amazon.com/Some-Title/dp/B08N5WRWNW/ref=sr_1_3?qid=1&sr=8-3".into();
+        let canonical = "https://www.amazon.com/dp/B08N5WRWNW";
+
+        for format in
+            [OutputFormat::Table, OutputFormat::Json, OutputFormat::Markdown, OutputFormat::Csv]
+        {
+            let formatter = Formatter::new(format).with_canonical_urls(true);
+            let single = formatter.format_product(&product);
+            let list = formatter.format_products(std::slice::from_ref(&product));
+            assert!(single.contains(canonical) && !single.contains("ref=sr_1_3"), "{}", format);
+            assert!(!list.contains("ref=sr_1_3"), "{}", format);
+
+            let raw = Formatter::new(format).format_product(&product);
+            assert!(raw.contains("ref=sr_1_3"), "{}", format);
+        }
+    }
+
+    #[test]
+    fn test_promotion_in_single_views() {
+        let mut product = make_product();
+        product.promotion = Some("Save 5% when you buy 2".to_string());
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Promo:   Save 5% when you buy 2"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Promotion:** Save 5% when you buy 2"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"promotion\": \"Save 5% when you buy 2\""));
+    }
+
+    #[test]
+    fn test_coupon_code_in_single_views() {
+        let mut product = make_product();
+        product.coupon_code = Some("SPRING15".to_string());
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Code:    SPRING15"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Coupon code:** `SPRING15`"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"coupon_code\": \"SPRING15\""));
+    }
+
+    #[test]
+    fn test_unit_price_in_single_views() {
+        let mut product = make_product();
+        product.unit_price = Some(UnitPrice { amount: 25.0, unit: "kg".to_string() });
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Unit:    USD 25.00/kg"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Unit price:** $25.00/kg"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"unit\": \"kg\""));
+    }
+
+    #[test]
+    fn test_subscribe_and_save_in_single_views() {
+        let mut product = make_product();
+        product.subscribe_price = Some(17.0);
+        product.subscribe_discount_percent = Some(15);
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("S&S:     USD 17.00 (-15%)"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Subscribe & Save:** "));
+        assert!(markdown.contains("(-15%)"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"subscribe_discount_percent\": 15"));
+    }
+
+    #[test]
+    fn test_energy_class_in_single_views() {
+        let mut product = make_product();
+        product.energy_class = Some('B');
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Energy:  B"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Energy class:** B"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"energy_class\": \"B\""));
+    }
+
+    #[test]
+    fn test_dimensions_and_weight_in_single_views() {
+        let mut product = make_product();
+        product.dimensions = Some("20 x 10 x 5 cm".to_string());
+        product.weight = Some("450 g".to_string());
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Size:    20 x 10 x 5 cm"));
+        assert!(table.contains("Weight:  450 g"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Dimensions:** 20 x 10 x 5 cm"));
+        assert!(markdown.contains("- **Weight:** 450 g"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"dimensions\": \"20 x 10 x 5 cm\""));
+        assert!(json.contains("\"weight\": \"450 g\""));
+    }
+
+    #[test]
+    fn test_other_sellers_in_single_views() {
+        let mut product = make_product();
+        product.other_sellers_from = Some(18.5);
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Others:  from USD 18.50"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Other sellers:** from $18.50"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"other_sellers_from\": 18.5"));
+    }
+
+    #[test]
+    fn test_rank_column_in_lists() {
+        let mut first = make_product();
+        first.rank = Some(1);
+        let mut second = make_product();
+        second.rank = Some(2);
+        let products = vec![first, second];
+
+        let table = Formatter::new(OutputFormat::Table).format_products(&products);
+        let lines: Vec<_> = table.lines().collect();
+        assert!(lines[0].starts_with("   #  ASIN"));
+        assert!(lines[2].starts_with("   1  B08N5WRWNW"));
+        assert!(lines[3].starts_with("   2  B08N5WRWNW"));
+
+        let markdown = Formatter::new(OutputFormat::Markdown).format_products(&products);
+        assert!(markdown.starts_with("| # | ASIN | Price |"));
+        assert!(markdown.contains("\n| 2 | B08N5WRWNW |"));
+
+        let csv = Formatter::new(OutputFormat::Csv).format_products(&products);
+        assert!(csv.starts_with("rank,asin,title,"));
+        assert!(csv.contains("\n1,B08N5WRWNW,"));
+
+        // Search results have no rank, so no column
+        let unranked = vec![make_product()];
+        let table = Formatter::new(OutputFormat::Table).format_products(&unranked);
+        assert!(table.starts_with("ASIN"));
+        let csv = Formatter::new(OutputFormat::Csv).format_products(&unranked);
+        assert!(csv.starts_with("asin,"));
+    }
+
+    #[test]
+    fn test_rank_in_single_views() {
+        let mut product = make_product();
+        product.rank = Some(3);
+
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Rank:    #3"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("- **Best seller rank:** #3"));
+        assert!(!Formatter::new(OutputFormat::Table)
+            .format_product(&make_product())
+            .contains("Rank:"));
+    }
+
+    #[test]
+    fn test_ships_internationally_warning() {
+        let mut product = make_product();
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(!table.contains("Does not ship"));
+
+        product.ships_internationally = Some(false);
+        let table = Formatter::new(OutputFormat::Table).format_product(&product);
+        assert!(table.contains("Warning: Does not ship to your location"));
+        let markdown = Formatter::new(OutputFormat::Markdown).format_product(&product);
+        assert!(markdown.contains("> ⚠️ **Does not ship to your location**"));
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"ships_internationally\": false"));
+    }
+
+    #[test]
+    fn test_frequently_returned_warning() {
+        let mut product = make_product();
+        let table = Formatter::new(OutputFormat::Table);
+        let markdown = Formatter::new(OutputFormat::Markdown);
+        assert!(!table.format_product(&product).contains("Frequently returned"));
+
+        product.is_frequently_returned = true;
+        let lines: Vec<String> = table.format_product(&product).lines().map(String::from).collect();
+        assert_eq!(lines[2], "Warning: Frequently returned item");
+        assert!(markdown.format_product(&product).contains("> ⚠️ **Frequently returned item**"));
+
+        let json = Formatter::new(OutputFormat::Json).format_product(&product);
+        assert!(json.contains("\"is_frequently_returned\": true"));
+    }
+
+    #[test]
+    fn test_decimal_override() {
+        let mut product = make_product();
+        product.price = Some(Price::simple(1234.5, "USD"));
+
+        let markdown = Formatter::new(OutputFormat::Markdown).with_decimal(DecimalSeparator::Comma);
+        assert!(markdown.format_product(&product).contains("- **Price:** $1.234,50"));
+
+        product.price = Some(Price::simple(1234.5, "XYZ"));
+        assert!(markdown.format_product(&product).contains("- **Price:** XYZ 1.234,50"));
+
+        let markdown = Formatter::new(OutputFormat::Markdown);
+        assert!(markdown.format_product(&product).contains("- **Price:** XYZ 1234.50"));
+    }
+
+    #[test]
+    fn test_price_decimals_zero() {
+        let mut product = make_product();
+        product.price = Some(Price::with_discount(29.4567, 1234.5, "USD"));
+
+        let table = Formatter::new(OutputFormat::Table).with_price_decimals(0);
+        assert!(table.format_product(&product).contains("Price:   USD 29 (was 1234)"));
+        assert!(table.format_products(std::slice::from_ref(&product)).contains(" 29  "));
+
+        let markdown = Formatter::new(OutputFormat::Markdown).with_price_decimals(0);
+        assert!(markdown.format_product(&product).contains("- **Price:** $29 ~~$1,234~~"));
+    }
+
+    #[test]
+    fn test_price_decimals_three() {
+        let mut product = make_product();
+        product.price = Some(Price::simple(29.4567, "EUR"));
+
+        let table = Formatter::new(OutputFormat::Table).with_price_decimals(3);
+        assert!(table.format_product(&product).contains("Price:   EUR 29.457"));
+
+        let markdown = Formatter::new(OutputFormat::Markdown).with_price_decimals(3);
+        assert!(markdown.format_product(&product).contains("- **Price:** 29,457 €"));
+
+        // JSON and CSV keep the raw value
+        let csv = Formatter::new(OutputFormat::Csv).with_price_decimals(3);
+        assert!(csv.format_product(&product).contains(",29.4567,"));
+    }
+
+    #[test]
+    fn test_formatter_from_config() {
+        let config = Config { price_decimals: Some(1), ..Config::default() };
+        let output = Formatter::from_config(&config).format_product(&make_product());
+        assert!(output.contains("Price:   USD 30.0 (was 40.0)"));
+    }
+
+    #[test]
+    fn test_markdown_unknown_currency_fallback() {
+        let formatter = Formatter::new(OutputFormat::Markdown);
+        let mut product = make_product();
+        product.price = Some(Price::simple(10.0, "XYZ"));
+        let output = formatter.format_product(&product);
+
+        assert!(output.contains("- **Price:** XYZ 10.00"));
+    }
+
     #[test]
     fn test_markdown_multiple_products() {
         let formatter = Formatter::new(OutputFormat::Markdown);
@@ -610,6 +1709,45 @@ mod tests {
         assert!(output.contains("*2 products found*"));
     }
 
+    #[test]
+    fn test_markdown_table_images() {
+        let products = vec![make_product(), make_minimal_product()];
+
+        let plain = Formatter::new(OutputFormat::Markdown).format_products(&products);
+        assert!(!plain.contains("Image"));
+        assert!(!plain.contains("![]("));
+
+        let formatter = Formatter::new(OutputFormat::Markdown).with_md_table_images(true);
+        let output = formatter.format_products(&products);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "| Image | ASIN | Price | Rating | Prime | Title |");
+        assert!(lines[2].starts_with("| ![](https://images.amazon.com/test.jpg) | B08N5WRWNW |"));
+        // Missing image leaves the cell empty
+        assert!(lines[3].starts_with("|  | MINIMAL123 |"));
+    }
+
+    #[test]
+    fn test_markdown_tasklist() {
+        let mut unrated = make_product();
+        unrated.rating = None;
+        let products =
+            vec![make_product(), make_minimal_product(), make_hidden_price_product(), unrated];
+
+        let formatter = Formatter::new(OutputFormat::Markdown).with_md_tasklist(true);
+        let output = formatter.format_products(&products);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines.iter().all(|l| l.starts_with("- [ ] [")));
+        assert!(lines[0].ends_with(" — $29.99 (4.5★)"));
+        assert!(lines[1].ends_with(")"));
+        assert!(!lines[1].contains(" — ") && !lines[1].contains("★"));
+        assert!(!lines[2].contains(" — "));
+        assert!(lines[3].ends_with(" — $29.99"));
+        assert!(!output.contains("| ASIN |"));
+    }
+
     #[test]
     fn test_markdown_long_title_truncation() {
         let formatter = Formatter::new(OutputFormat::Markdown);
@@ -636,6 +1774,60 @@ mod tests {
         assert!(output.contains("In cart"));
     }
 
+    // Minor units tests
+
+    #[test]
+    fn test_to_minor_units() {
+        assert_eq!(to_minor_units(29.98, "USD"), 2998);
+        assert_eq!(to_minor_units(2999.0, "JPY"), 2999);
+        assert_eq!(to_minor_units(1.234, "KWD"), 1234);
+        assert_eq!(minor_unit_exponent("EUR"), 2);
+    }
+
+    #[test]
+    fn test_minor_units_csv_and_json() {
+        let mut usd = make_product();
+        usd.price = Some(Price::with_discount(29.98, 39.99, "USD"));
+        let mut jpy = make_product();
+        jpy.asin = "B0JPY00001".to_string();
+        jpy.price = Some(Price::simple(2999.0, "JPY"));
+        let products = vec![usd, jpy];
+
+        let csv = Formatter::new(OutputFormat::Csv).with_minor_units(true);
+        let output = csv.format_products(&products);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[1].starts_with("B08N5WRWNW,Test Product Title,2998,3999,USD,"));
+        assert!(lines[2].starts_with("B0JPY00001,Test Product Title,2999,,JPY,"));
+
+        let json = Formatter::new(OutputFormat::Json).with_minor_units(true);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json.format_products(&products)).unwrap();
+        assert_eq!(parsed[0]["price"]["current"], 2998);
+        assert_eq!(parsed[0]["price"]["original"], 3999);
+        assert_eq!(parsed[0]["price"]["currency"], "USD");
+        assert_eq!(parsed[1]["price"]["current"], 2999);
+        assert!(parsed[0]["subscribe_price"].is_null());
+
+        let single: serde_json::Value =
+            serde_json::from_str(&json.format_product(&products[0])).unwrap();
+        assert_eq!(single["price"]["current"], 2998);
+
+        let mut subscribed = products[0].clone();
+        subscribed.subscribe_price = Some(25.48);
+        subscribed.other_sellers_from = Some(18.5);
+        subscribed.unit_price = Some(UnitPrice { amount: 12.5, unit: "kg".to_string() });
+        let single: serde_json::Value =
+            serde_json::from_str(&json.format_product(&subscribed)).unwrap();
+        assert_eq!(single["subscribe_price"], 2548);
+        assert_eq!(single["other_sellers_from"], 1850);
+        assert_eq!(single["unit_price"]["amount"], 1250);
+        assert_eq!(single["unit_price"]["unit"], "kg");
+
+        // Off by default
+        let plain = Formatter::new(OutputFormat::Csv).format_products(&products);
+        assert!(plain.contains(",29.98,39.99,USD,"));
+    }
+
     // CSV format tests
 
     #[test]
@@ -682,6 +1874,42 @@ mod tests {
         assert!(lines[3].contains("SPONSORED1"));
     }
 
+    #[test]
+    fn test_csv_currency_per_product() {
+        let mut euro = make_product();
+        euro.asin = "B0EURO0001".to_string();
+        euro.price = Some(Price::simple(24.99, "EUR"));
+        let products = vec![make_product(), euro];
+
+        for formatter in [
+            Formatter::new(OutputFormat::Csv),
+            Formatter::new(OutputFormat::Csv).with_minor_units(true),
+        ] {
+            let output = formatter.format_products(&products);
+            let currencies: Vec<&str> =
+                output.lines().skip(1).map(|line| line.split(',').nth(4).unwrap()).collect();
+            assert_eq!(currencies, ["USD", "EUR"]);
+        }
+    }
+
+    #[test]
+    fn test_csv_bom() {
+        let products = vec![make_product()];
+
+        let with_bom = Formatter::new(OutputFormat::Csv).with_csv_bom(true);
+        let output = with_bom.format_products(&products);
+        assert!(output.as_bytes().starts_with(&[0xEF, 0xBB, 0xBF]));
+        assert!(output.trim_start_matches(UTF8_BOM).starts_with("asin,"));
+        assert!(with_bom.format_products(&[]).starts_with(UTF8_BOM));
+
+        let without_bom = Formatter::new(OutputFormat::Csv).format_products(&products);
+        assert!(without_bom.starts_with("asin,"));
+
+        // JSON output is never BOM-prefixed
+        let json = Formatter::new(OutputFormat::Json).with_csv_bom(true);
+        assert!(json.format_products(&products).starts_with('['));
+    }
+
     #[test]
     fn test_csv_hidden_price() {
         let formatter = Formatter::new(OutputFormat::Csv);
@@ -693,6 +1921,44 @@ mod tests {
         assert!(lines[1].contains("HIDDEN1234"));
     }
 
+    #[test]
+    fn test_csv_null_value() {
+        let formatter = Formatter::new(OutputFormat::Csv).with_null_value("NULL");
+        let output = formatter.format_products(&[make_minimal_product(), make_product()]);
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines[1],
+            "MINIMAL123,Minimal Product,NULL,NULL,,NULL,NULL,false,false,false,false,NULL,https://amazon.com/dp/MINIMAL123"
+        );
+        assert!(!lines[2].contains("NULL"));
+
+        // Empty by default
+        let output = Formatter::new(OutputFormat::Csv).format_product(&make_minimal_product());
+        assert!(output.contains("MINIMAL123,Minimal Product,,,,,,false"));
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        let products = [make_product(), make_minimal_product()];
+
+        let csv = Formatter::new(OutputFormat::Csv).format_products(&products);
+        assert!(!csv.ends_with('\n'));
+
+        let formatter = Formatter::new(OutputFormat::Csv).with_trailing_newline(true);
+        let csv = formatter.format_products(&products);
+        assert!(csv.ends_with("MINIMAL123\n"));
+        assert!(formatter.format_products(&[]).ends_with(",url\n"));
+
+        // Same for every format, single and list output
+        for format in [OutputFormat::Json, OutputFormat::Table, OutputFormat::Markdown] {
+            let formatter = Formatter::new(format).with_trailing_newline(true);
+            assert!(formatter.format_products(&products).ends_with('\n'));
+            assert!(!formatter.format_products(&products).ends_with("\n\n"));
+            assert!(formatter.format_product(&products[0]).ends_with('\n'));
+        }
+    }
+
     #[test]
     fn test_csv_empty() {
         let formatter = Formatter::new(OutputFormat::Csv);
@@ -6,8 +6,10 @@
 pub mod amazon;
 pub mod commands;
 pub mod config;
+pub mod confirm;
 pub mod filters;
 pub mod format;
+pub mod progress;
 
 #[cfg(feature = "tropical")]
 pub mod tropical;
@@ -3,12 +3,20 @@
 //! A Rust implementation with TLS fingerprint emulation for reliable scraping.
 
 use amz_crawler::amazon::regions::Region;
-use amz_crawler::commands::{ProductCommand, SearchCommand};
-use amz_crawler::config::{Config, OutputFormat};
+use amz_crawler::commands::{
+    BestsellersCommand, DiffCommand, FingerprintCommand, ProductCommand, ReformatCommand,
+    SchemaCommand, SchemaKind, SearchCommand, SelftestCommand, ValidateConfigCommand,
+};
+use amz_crawler::config::{
+    Config, DecimalSeparator, DedupBy, FilterPreset, KeywordMatchMode, OutputFormat, PageCapAction,
+    Quality, RatingScale, SortKey,
+};
+use amz_crawler::{confirm, progress};
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
+use std::io::IsTerminal;
 use std::path::PathBuf;
-use tracing::Level;
+use tracing::{warn, Level};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser)]
@@ -27,10 +35,22 @@ struct Cli {
     #[arg(long, global = true, env = "AMZ_PROXY")]
     proxy: Option<String>,
 
+    /// On a CAPTCHA, retry through the next proxy in the pool (see `proxies` in config)
+    #[arg(long, global = true)]
+    retry_captcha_with_proxy: bool,
+
     /// Delay between requests in milliseconds
     #[arg(long, default_value = "2000", global = true, env = "AMZ_DELAY")]
     delay: u64,
 
+    /// Delay before each search page after the first, in milliseconds (default: --delay)
+    #[arg(long, global = true, value_name = "MS")]
+    delay_between_pages: Option<u64>,
+
+    /// Extra delay between product lookups in a batch, in milliseconds
+    #[arg(long, global = true, value_name = "MS")]
+    between_asins_delay: Option<u64>,
+
     /// Path to config file
     #[arg(short, long, global = true)]
     config: Option<PathBuf>,
@@ -39,10 +59,98 @@ struct Cli {
     #[arg(short, long, default_value = "table", global = true)]
     format: OutputFormat,
 
+    /// Decimal places for prices in table/markdown/compare output
+    #[arg(long, value_name = "N", global = true)]
+    price_decimals: Option<usize>,
+
+    /// Prefix CSV output with a UTF-8 byte-order mark (for Excel)
+    #[arg(long, global = true)]
+    csv_bom: bool,
+
+    /// Rewrite product URLs to https://www.<domain>/dp/<ASIN> (drops ref/tracking params)
+    #[arg(long, global = true)]
+    canonical_urls: bool,
+
+    /// Add an Image thumbnail column to markdown tables
+    #[arg(long, global = true)]
+    md_table_images: bool,
+
+    /// Render markdown product lists as a "- [ ]" task list
+    #[arg(long, global = true)]
+    md_tasklist: bool,
+
+    /// Seed the request jitter RNG for reproducible delays
+    #[arg(long, global = true, value_name = "SEED")]
+    rng_seed: Option<u64>,
+
+    /// Random jitter added to each rate-limit retry wait, in ms
+    #[arg(long, global = true, value_name = "MS")]
+    between_retries_jitter: Option<u64>,
+
+    /// Emit CSV/JSON prices as integer minor units (e.g. cents)
+    #[arg(long, global = true)]
+    minor_units: bool,
+
+    /// Show ratings on a 5 (stars, default), 10 or 100 point scale
+    #[arg(long, global = true, value_name = "5|10|100")]
+    rating_scale: Option<RatingScale>,
+
+    /// Value written in CSV for absent price, rating, reviews and brand (default: empty)
+    #[arg(long, global = true, value_name = "STR")]
+    null_value: Option<String>,
+
+    /// Don't end search/product/format output with a newline
+    #[arg(long, global = true)]
+    no_trailing_newline: bool,
+
+    /// Column separator for table product lists (e.g. "|")
+    #[arg(long, global = true, value_name = "STR")]
+    table_sep: Option<String>,
+
+    /// Don't emit terminal escape sequences (hyperlinks in table output)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Keep the last-known price on out-of-stock listings
+    #[arg(long, global = true)]
+    include_out_of_stock_price: bool,
+
+    /// Only trust a Prime badge in the product page buy box, not elsewhere on the page
+    #[arg(long, global = true)]
+    strict_prime: bool,
+
+    /// Fail when a product is missing its title or an expected price
+    #[arg(long, global = true)]
+    strict_parse: bool,
+
+    /// Include the raw title/price/rating text per product (`_raw` in JSON output)
+    #[arg(long, global = true)]
+    debug_raw: bool,
+
+    /// Print each Amazon URL to stderr before requesting it
+    #[arg(long, global = true)]
+    show_url: bool,
+
+    /// Decimal separator override for prices (comma, period), e.g. for a custom base URL
+    #[arg(long, value_name = "SEP", global = true)]
+    decimal: Option<DecimalSeparator>,
+
+    /// Currency code override for prices (e.g. EUR)
+    #[arg(long, value_name = "CODE", global = true)]
+    currency: Option<String>,
+
     /// Enable verbose logging
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Ask before searching or looking up products, whatever the request count
+    #[arg(long, global = true)]
+    confirm: bool,
+
+    /// Show progress on stderr even when it isn't a terminal
+    #[arg(long, global = true)]
+    progress: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -59,33 +167,44 @@ enum Commands {
         #[arg(short, long, default_value = "20")]
         max: usize,
 
-        /// Minimum price filter
+        /// Stop at the first product that passes all filters
         #[arg(long)]
-        min_price: Option<f64>,
+        first_match: bool,
 
-        /// Maximum price filter
-        #[arg(long)]
-        max_price: Option<f64>,
+        /// Sort order: featured, relevance, price-asc, price-desc, rating, newest, quality
+        #[arg(long, default_value = "featured")]
+        sort: SortKey,
 
-        /// Minimum rating filter (1.0-5.0)
-        #[arg(long)]
-        min_rating: Option<f32>,
+        /// Drop repeated results by: asin (default), title (collapses variants)
+        #[arg(long, value_name = "KEY")]
+        dedup_by: Option<DedupBy>,
 
-        /// Only show Prime-eligible products
+        /// Paginate by following Amazon's "Next" link instead of page numbers
         #[arg(long)]
-        prime_only: bool,
+        follow_next: bool,
 
-        /// Exclude sponsored products
-        #[arg(long)]
-        no_sponsored: bool,
+        /// When the page limit stops the search short of --max: warn, error, ignore
+        #[arg(long, value_name = "ACTION")]
+        on_page_cap: Option<PageCapAction>,
 
-        /// Required keywords in title (comma-separated)
-        #[arg(long, value_delimiter = ',')]
-        keywords: Option<Vec<String>>,
+        /// Search several regions concurrently (comma-separated); results keep this order
+        #[arg(long, value_delimiter = ',', value_name = "REGIONS")]
+        regions: Vec<Region>,
 
-        /// Excluded keywords from title (comma-separated)
-        #[arg(long, value_delimiter = ',')]
-        exclude: Option<Vec<String>>,
+        /// Most --regions searched at once (default: 3)
+        #[arg(long, value_name = "N")]
+        max_concurrent_regions: Option<usize>,
+
+        /// Print how often each ASIN appeared across fetched pages, most frequent first
+        #[arg(long, conflicts_with = "regions")]
+        asin_report: bool,
+
+        /// Report Amazon's total result count ("About 10,000 results"; JSON gets a wrapping object)
+        #[arg(long, conflicts_with_all = ["regions", "asin_report"])]
+        total_results: bool,
+
+        #[command(flatten)]
+        filters: FilterArgs,
     },
 
     /// Look up a product by ASIN
@@ -94,17 +213,143 @@ enum Commands {
         /// ASIN(s) to look up
         #[arg(required = true)]
         asins: Vec<String>,
+
+        /// Write each product to <DIR>/<ASIN>.<ext> instead of stdout
+        #[arg(long, value_name = "DIR")]
+        output_dir: Option<PathBuf>,
+
+        /// Most lookups in flight at once with --output-dir (default: 1)
+        #[arg(long, value_name = "N", requires = "output_dir")]
+        concurrency: Option<usize>,
+
+        /// Abort the whole batch on the first CAPTCHA or block instead of continuing
+        #[arg(long)]
+        fail_fast: bool,
+
+        /// Append failed or skipped ASINs to this file as `ASIN<TAB>reason` lines
+        #[arg(long, value_name = "PATH")]
+        error_log: Option<PathBuf>,
+
+        #[command(flatten)]
+        filters: FilterArgs,
+
+        #[command(flatten)]
+        page_filters: ProductPageFilterArgs,
+    },
+
+    /// List a category's best sellers, ranked
+    Bestsellers {
+        /// Category slug from /gp/bestsellers/<category> (e.g. "electronics")
+        category: String,
+
+        /// Maximum number of results (a list page holds up to 50)
+        #[arg(short, long, default_value = "50")]
+        max: usize,
+
+        #[command(flatten)]
+        filters: FilterArgs,
+    },
+
+    /// Re-run the lookups listed in a product --error-log, rewriting it with what still fails
+    Retry {
+        /// Error log written by `product --error-log`
+        error_log: PathBuf,
+    },
+
+    /// Re-format previously saved JSON output without fetching
+    Format {
+        /// JSON file with a product or an array of products
+        #[arg(long = "input-json", value_name = "FILE")]
+        input_json: PathBuf,
+
+        #[command(flatten)]
+        filters: FilterArgs,
+
+        #[command(flatten)]
+        page_filters: ProductPageFilterArgs,
+    },
+
+    /// Show added/removed products and price/rating changes between two JSON scrapes
+    Diff {
+        /// Older JSON scrape
+        old_json: PathBuf,
+
+        /// Newer JSON scrape
+        new_json: PathBuf,
+    },
+
+    /// Print the JSON Schema of `--format json` output
+    Schema {
+        /// Shape to describe (product, or comparison with the tropical feature)
+        #[arg(default_value = "product")]
+        kind: SchemaKind,
     },
 
     /// List supported regions
-    Regions,
+    Regions {
+        /// Print only the region codes, one per line
+        #[arg(long)]
+        list_region_codes: bool,
+    },
+
+    /// List supported output formats
+    Formats {
+        /// Print only the format names and aliases, one per line
+        #[arg(long)]
+        list_format_names: bool,
+    },
+
+    /// Check that selectors still extract fields from the bundled HTML fixtures
+    SelftestSelectors,
+
+    /// Check that a config file parses and has sane values, without fetching
+    ValidateConfig {
+        /// Config file to check
+        path: PathBuf,
+    },
+
+    /// Report the TLS/HTTP2 fingerprint a JA3/JA4 endpoint sees from this client
+    FingerprintTest {
+        /// Fingerprint-reporting endpoint (default: `fingerprint_url`, then tls.peet.ws)
+        #[arg(long)]
+        url: Option<String>,
+    },
 
     /// Compare prices across EU Amazon stores (TropicalPrice)
     #[cfg(feature = "tropical")]
     #[command(alias = "c")]
     Compare {
-        /// ASIN to compare
-        asin: String,
+        /// ASIN(s) to compare
+        #[arg(required = true)]
+        asins: Vec<String>,
+
+        /// Only show ASINs whose max savings is at least this many euros
+        #[arg(long, value_name = "EUR")]
+        min_savings: Option<f64>,
+
+        /// Only show ASINs whose max savings is at least this percentage
+        #[arg(long, value_name = "PERCENT")]
+        min_savings_percent: Option<f64>,
+
+        /// Omit the store links block from a single-ASIN comparison
+        #[arg(long, conflicts_with_all = ["links_only", "min_savings", "min_savings_percent"])]
+        no_links: bool,
+
+        /// Print only the store links block of a single-ASIN comparison
+        #[arg(long, conflicts_with_all = ["min_savings", "min_savings_percent"])]
+        links_only: bool,
+
+        /// Print a price matrix: one row per ASIN, one column per country
+        #[arg(long, conflicts_with_all = ["no_links", "links_only"])]
+        matrix: bool,
+
+        /// Print one shareable line per ASIN (cheapest store, price and max savings)
+        #[arg(long, conflicts_with_all = ["no_links", "links_only", "matrix"])]
+        summary: bool,
+
+        /// Convert EUR prices to this currency using online exchange rates (e.g. GBP)
+        #[arg(long, value_name = "CURRENCY")]
+        convert: Option<String>,
     },
 
     /// Search TropicalPrice for EU products
@@ -119,6 +364,139 @@ enum Commands {
     },
 }
 
+/// Product filters shared by commands that output product lists.
+#[derive(Args)]
+struct FilterArgs {
+    /// Apply a named filter set from `[presets.<name>]` in the config file
+    #[arg(long, value_name = "NAME")]
+    preset: Option<String>,
+
+    /// Minimum price filter
+    #[arg(long)]
+    min_price: Option<f64>,
+
+    /// Maximum price filter
+    #[arg(long)]
+    max_price: Option<f64>,
+
+    /// Minimum rating filter (1.0-5.0)
+    #[arg(long)]
+    min_rating: Option<f32>,
+
+    /// Minimum number of reviews (excludes unrated products)
+    #[arg(long, value_name = "N")]
+    min_reviews: Option<u32>,
+
+    /// Rating + review count gate: high (4.5+ stars, 100+ reviews), medium (4.0+, 25+)
+    #[arg(long, value_name = "LEVEL")]
+    quality: Option<Quality>,
+
+    /// Minimum EU energy efficiency class (A best, G worst)
+    #[arg(long)]
+    min_energy_class: Option<char>,
+
+    /// Only show products with at most N units left ("Only N left in stock")
+    #[arg(long, value_name = "N")]
+    max_stock_remaining: Option<u32>,
+
+    /// Only show Prime-eligible products
+    #[arg(long)]
+    prime_only: bool,
+
+    /// Exclude sponsored products
+    #[arg(long)]
+    no_sponsored: bool,
+
+    /// Required keywords in title (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    keywords: Option<Vec<String>>,
+
+    /// Whether all or any of --keywords must appear: all, any
+    #[arg(long, value_name = "MODE")]
+    keyword_match: Option<KeywordMatchMode>,
+
+    /// Excluded keywords from title (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    exclude: Option<Vec<String>>,
+
+    /// Exclude digital products (Kindle ebooks, audiobooks, gift cards)
+    #[arg(long)]
+    exclude_digital: bool,
+
+    /// Drop products whose price parsed as 0.00 (usually a parse glitch)
+    #[arg(long)]
+    drop_zero_price: bool,
+}
+
+impl FilterArgs {
+    /// Applies the preset, if any, then the filter flags on top of the loaded config.
+    fn apply(self, config: &mut Config) -> Result<()> {
+        if let Some(name) = &self.preset {
+            config.apply_preset(name)?;
+        }
+
+        config.apply_filters(FilterPreset {
+            min_price: self.min_price,
+            max_price: self.max_price,
+            min_rating: self.min_rating,
+            min_reviews: self.min_reviews,
+            quality: self.quality,
+            min_energy_class: self.min_energy_class,
+            min_subscribe_discount: None,
+            max_stock_remaining: self.max_stock_remaining,
+            prime_only: self.prime_only,
+            no_sponsored: self.no_sponsored,
+            keywords: self.keywords,
+            keyword_match_mode: self.keyword_match,
+            exclude_keywords: self.exclude,
+            exclude_digital: self.exclude_digital,
+            exclude_frequently_returned: false,
+            drop_zero_price: self.drop_zero_price,
+        });
+        Ok(())
+    }
+}
+
+/// Filters on product page data, which search and best seller cards don't
+/// carry, so they're only offered where product pages are read.
+#[derive(Args)]
+struct ProductPageFilterArgs {
+    /// Minimum Subscribe & Save discount in percent (excludes products without S&S)
+    #[arg(long, value_name = "PERCENT")]
+    min_subscribe_discount: Option<u8>,
+
+    /// Exclude products Amazon flags as "Frequently returned item"
+    #[arg(long)]
+    exclude_frequently_returned: bool,
+}
+
+impl ProductPageFilterArgs {
+    /// Applies the flags on top of the loaded config (and any preset).
+    fn apply(self, config: &mut Config) {
+        if self.min_subscribe_discount.is_some() {
+            config.min_subscribe_discount = self.min_subscribe_discount;
+        }
+        config.exclude_frequently_returned |= self.exclude_frequently_returned;
+    }
+}
+
+/// Warns about product page filters set in the config file or a preset, which
+/// search and best seller cards can't satisfy.
+fn warn_product_page_filters(config: &Config) {
+    if config.min_subscribe_discount.is_some() {
+        warn!(
+            "min_subscribe_discount only applies to product lookups; \
+             list results don't show Subscribe & Save prices, so none will pass"
+        );
+    }
+    if config.exclude_frequently_returned {
+        warn!(
+            "exclude_frequently_returned only applies to product lookups; \
+             list results don't show return warnings, so nothing is excluded"
+        );
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -132,13 +510,73 @@ async fn main() -> Result<()> {
 
     tracing_subscriber::fmt().with_env_filter(filter).with_target(false).init();
 
+    // Validate before loading, so a broken default config can still be checked
+    if let Commands::ValidateConfig { path } = &cli.command {
+        let (output, passed) = ValidateConfigCommand::new(path).execute();
+        println!("{}", output);
+        if !passed {
+            anyhow::bail!("Config validation failed");
+        }
+        return Ok(());
+    }
+
     // Load config with layered overrides
     let mut config = Config::load(cli.config.as_deref())?.with_env();
 
     // Apply CLI overrides
     config.region = cli.region;
     config.format = cli.format;
+    if let Some(decimals) = cli.price_decimals {
+        config.price_decimals = Some(decimals);
+    }
+    config.csv_bom |= cli.csv_bom;
+    config.canonical_urls |= cli.canonical_urls;
+    config.md_table_images |= cli.md_table_images;
+    config.md_tasklist |= cli.md_tasklist;
+    if let Some(seed) = cli.rng_seed {
+        config.rng_seed = Some(seed);
+    }
+    if let Some(jitter) = cli.between_retries_jitter {
+        config.retry_jitter_ms = jitter;
+    }
+    config.minor_units |= cli.minor_units;
+    if let Some(scale) = cli.rating_scale {
+        config.rating_scale = scale;
+    }
+    if let Some(null_value) = cli.null_value {
+        config.null_value = null_value;
+    }
+    if cli.no_trailing_newline {
+        config.trailing_newline = false;
+    }
+    if let Some(table_sep) = cli.table_sep {
+        config.table_sep = Some(table_sep);
+    }
+    if config.table_width.is_none() {
+        // Not a TTY (piped or redirected): keep the fixed column widths
+        config.table_width = amz_crawler::format::terminal_width();
+    }
+    // Clickable titles only where a terminal will interpret them
+    config.hyperlinks = (config.hyperlinks || std::io::stdout().is_terminal()) && !cli.no_color;
+    config.include_out_of_stock_price |= cli.include_out_of_stock_price;
+    config.strict_prime |= cli.strict_prime;
+    config.strict_parse |= cli.strict_parse;
+    config.debug_raw |= cli.debug_raw;
+    config.show_url |= cli.show_url;
+    if let Some(decimal) = cli.decimal {
+        config.decimal = Some(decimal);
+    }
+    if let Some(currency) = cli.currency {
+        config.currency = Some(currency.to_uppercase());
+    }
     config.delay_ms = cli.delay;
+    if let Some(page_delay) = cli.delay_between_pages {
+        config.page_delay_ms = Some(page_delay);
+    }
+    if let Some(asin_delay) = cli.between_asins_delay {
+        config.asin_delay_ms = Some(asin_delay);
+    }
+    config.retry_captcha_with_proxy |= cli.retry_captcha_with_proxy;
 
     if let Some(proxy) = cli.proxy {
         config.proxy = Some(proxy);
@@ -148,47 +586,145 @@ async fn main() -> Result<()> {
         Commands::Search {
             query,
             max,
-            min_price,
-            max_price,
-            min_rating,
-            prime_only,
-            no_sponsored,
-            keywords,
-            exclude,
+            first_match,
+            sort,
+            dedup_by,
+            follow_next,
+            on_page_cap,
+            regions,
+            max_concurrent_regions,
+            asin_report,
+            total_results,
+            filters,
         } => {
             // Apply search-specific config
             config.max_results = max;
-            config.min_price = min_price;
-            config.max_price = max_price;
-            config.min_rating = min_rating;
-            config.prime_only = prime_only;
-            config.no_sponsored = no_sponsored;
-
-            if let Some(kw) = keywords {
-                config.keywords = kw;
+            config.first_match = first_match;
+            config.sort = sort;
+            config.follow_next_link = follow_next;
+            config.show_total_results |= total_results;
+            if let Some(dedup_by) = dedup_by {
+                config.dedup_by = dedup_by;
+            }
+            if let Some(action) = on_page_cap {
+                config.on_page_cap = action;
+            }
+            if let Some(limit) = max_concurrent_regions {
+                config.max_concurrent_regions = limit;
             }
-            if let Some(ex) = exclude {
-                config.exclude_keywords = ex;
+            filters.apply(&mut config)?;
+            warn_product_page_filters(&config);
+
+            let requests = confirm::search_requests(max, regions.len());
+            if !confirm::confirm_scrape(
+                requests,
+                config.delay_ms,
+                config.confirm_threshold,
+                cli.confirm,
+            )? {
+                eprintln!("Aborted.");
+                return Ok(());
             }
 
-            let cmd = SearchCommand::new(config);
-            let output = cmd.execute(&query).await?;
-            println!("{}", output);
+            if !regions.is_empty() {
+                let output = SearchCommand::new(config).execute_regions(&regions, &query).await?;
+                print!("{}", output);
+            } else {
+                let cmd =
+                    SearchCommand::new(config).with_progress(progress::default_sink(cli.progress));
+                if asin_report {
+                    println!("{}", cmd.execute_asin_report(&query).await?);
+                } else {
+                    print!("{}", cmd.execute(&query).await?);
+                }
+            }
         }
 
-        Commands::Product { asins } => {
-            let cmd = ProductCommand::new(config);
+        Commands::Product {
+            asins,
+            output_dir,
+            concurrency,
+            fail_fast,
+            error_log,
+            filters,
+            page_filters,
+        } => {
+            config.fail_fast |= fail_fast;
+            filters.apply(&mut config)?;
+            page_filters.apply(&mut config);
+            if let Some(concurrency) = concurrency {
+                config.batch_concurrency = concurrency;
+            }
+            if !confirm::confirm_scrape(
+                asins.len(),
+                config.delay_ms,
+                config.confirm_threshold,
+                cli.confirm,
+            )? {
+                eprintln!("Aborted.");
+                return Ok(());
+            }
+            // A lone ASIN still goes through the batch path when failures are logged
+            let single = asins.len() == 1 && error_log.is_none();
+            let mut cmd =
+                ProductCommand::new(config).with_progress(progress::default_sink(cli.progress));
+            if let Some(path) = error_log {
+                cmd = cmd.with_error_log(path);
+            }
 
-            let output = if asins.len() == 1 {
-                cmd.execute(&asins[0]).await?
+            if let Some(dir) = output_dir {
+                let written = cmd.execute_batch_to_dir(&asins, &dir).await?;
+                eprintln!("Wrote {} file(s) to {}", written.len(), dir.display());
             } else {
-                cmd.execute_batch(&asins).await?
-            };
+                let output = if single {
+                    cmd.execute(&asins[0]).await?
+                } else {
+                    cmd.execute_batch(&asins).await?
+                };
+
+                print!("{}", output);
+            }
+        }
+
+        Commands::Retry { error_log } => {
+            let cmd =
+                ProductCommand::new(config).with_progress(progress::default_sink(cli.progress));
+            print!("{}", cmd.execute_retry(&error_log).await?);
+        }
+
+        Commands::Format { input_json, filters, page_filters } => {
+            filters.apply(&mut config)?;
+            page_filters.apply(&mut config);
+
+            let cmd = ReformatCommand::new(config);
+            let output = cmd.execute(&input_json)?;
+            print!("{}", output);
+        }
 
+        Commands::Bestsellers { category, max, filters } => {
+            config.max_results = max;
+            filters.apply(&mut config)?;
+            warn_product_page_filters(&config);
+
+            let output = BestsellersCommand::new(config).execute(&category).await?;
+            print!("{}", output);
+        }
+
+        Commands::Diff { old_json, new_json } => {
+            let cmd = DiffCommand::new(config);
+            let output = cmd.execute(&old_json, &new_json)?;
             println!("{}", output);
         }
 
-        Commands::Regions => {
+        Commands::Schema { kind } => {
+            println!("{}", SchemaCommand::new(kind).execute()?);
+        }
+
+        Commands::Regions { list_region_codes: true } => {
+            println!("{}", Region::codes());
+        }
+
+        Commands::Regions { list_region_codes: false } => {
             println!("Supported Amazon regions:\n");
             println!("{:<6} {:<20} {:<10}", "Code", "Domain", "Currency");
             println!("{:-<6} {:-<20} {:-<10}", "", "", "");
@@ -203,10 +739,109 @@ async fn main() -> Result<()> {
             }
         }
 
+        Commands::Formats { list_format_names: true } => {
+            println!("{}", OutputFormat::names());
+        }
+
+        Commands::Formats { list_format_names: false } => {
+            println!("Supported output formats:\n");
+            println!("{:<10} {:<10} {:<10}", "Name", "Aliases", "Extension");
+            println!("{:-<10} {:-<10} {:-<10}", "", "", "");
+
+            for format in OutputFormat::all() {
+                println!(
+                    "{:<10} {:<10} {:<10}",
+                    format.name(),
+                    format.aliases().join(", "),
+                    format.extension()
+                );
+            }
+        }
+
+        Commands::FingerprintTest { url } => {
+            let cmd = FingerprintCommand::new(config);
+            println!("{}", cmd.execute(url.as_deref()).await?);
+        }
+
+        Commands::ValidateConfig { .. } => unreachable!("handled before config loading"),
+
+        Commands::SelftestSelectors => {
+            let (output, passed) = SelftestCommand::new().execute();
+            println!("{}", output);
+
+            if !passed {
+                anyhow::bail!("Selector self-test failed");
+            }
+        }
+
         #[cfg(feature = "tropical")]
-        Commands::Compare { asin } => {
-            use amz_crawler::commands::compare;
-            let output = compare::compare_prices(&asin, config.format).await?;
+        Commands::Compare {
+            asins,
+            min_savings,
+            min_savings_percent,
+            no_links,
+            links_only,
+            matrix,
+            summary,
+            convert,
+        } => {
+            use amz_crawler::commands::compare::{self, LinksMode, SavingsThreshold};
+            let decimals = config.price_decimals.unwrap_or(2);
+            let threshold = SavingsThreshold { min_savings, min_savings_percent };
+            let links = match (no_links, links_only) {
+                (true, _) => LinksMode::Hide,
+                (_, true) => LinksMode::Only,
+                _ => LinksMode::Show,
+            };
+            // Batch tables have no links block to show or hide
+            if links != LinksMode::Show && asins.len() > 1 {
+                anyhow::bail!("--no-links and --links-only only apply to a single-ASIN compare");
+            }
+            let conversion = match &convert {
+                Some(currency) => Some(compare::load_conversion(&config, currency).await?),
+                None => None,
+            };
+            let conversion = conversion.as_ref();
+            let output = if matrix {
+                compare::compare_matrix(
+                    &asins,
+                    threshold,
+                    config.format,
+                    decimals,
+                    &config.locale_currency,
+                    conversion,
+                )
+                .await?
+            } else if summary {
+                compare::compare_summary(
+                    &asins,
+                    threshold,
+                    decimals,
+                    &config.locale_currency,
+                    conversion,
+                )
+                .await?
+            } else if asins.len() == 1 && threshold.is_empty() {
+                compare::compare_prices(
+                    &asins[0],
+                    config.format,
+                    decimals,
+                    links,
+                    &config.locale_currency,
+                    conversion,
+                )
+                .await?
+            } else {
+                compare::compare_batch(
+                    &asins,
+                    threshold,
+                    config.format,
+                    decimals,
+                    &config.locale_currency,
+                    conversion,
+                )
+                .await?
+            };
             println!("{}", output);
         }
 
@@ -0,0 +1,77 @@
+//! Progress reporting for multi-page searches and batch lookups.
+
+use std::fmt;
+use std::io::IsTerminal;
+use std::sync::Arc;
+
+/// A progress update emitted from a command loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Progress {
+    /// A search results page was fetched.
+    Page { page: u32, max_pages: u32, collected: usize },
+    /// An ASIN in a batch lookup was processed.
+    Asin { done: usize, total: usize },
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Progress::Page { page, max_pages, collected } => {
+                write!(f, "page {}/{}, {} products collected", page, max_pages, collected)
+            }
+            Progress::Asin { done, total } => write!(f, "{}/{} ASINs", done, total),
+        }
+    }
+}
+
+/// Receives progress updates - enables capturing them in tests.
+pub trait ProgressSink: Send + Sync {
+    /// Called after each page or ASIN is processed.
+    fn report(&self, progress: &Progress);
+
+    /// Called once the loop is done.
+    fn finish(&self) {}
+}
+
+/// Discards all progress updates.
+pub struct NoProgress;
+
+impl ProgressSink for NoProgress {
+    fn report(&self, _progress: &Progress) {}
+}
+
+/// Prints progress updates to stderr, rewriting a single line.
+pub struct StderrProgress;
+
+impl ProgressSink for StderrProgress {
+    fn report(&self, progress: &Progress) {
+        eprint!("\r{}\x1b[K", progress);
+    }
+
+    fn finish(&self) {
+        eprintln!();
+    }
+}
+
+/// Picks stderr progress when forced or when stderr is a terminal.
+pub fn default_sink(force: bool) -> Arc<dyn ProgressSink> {
+    if force || std::io::stderr().is_terminal() {
+        Arc::new(StderrProgress)
+    } else {
+        Arc::new(NoProgress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_display() {
+        let page = Progress::Page { page: 3, max_pages: 10, collected: 48 };
+        assert_eq!(page.to_string(), "page 3/10, 48 products collected");
+
+        let asin = Progress::Asin { done: 5, total: 30 };
+        assert_eq!(asin.to_string(), "5/30 ASINs");
+    }
+}
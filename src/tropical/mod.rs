@@ -6,6 +6,10 @@
 mod client;
 mod models;
 mod parser;
+mod rates;
 
 pub use client::{TropicalClient, TropicalSearch};
-pub use models::{CountryPrice, PriceComparison, TropicalProduct};
+pub use models::{CountryPrice, MatrixRow, PriceComparison, PriceMatrix, TropicalProduct};
+pub use rates::{
+    Conversion, ExchangeRates, HttpRateProvider, RateCache, RateProvider, DEFAULT_RATES_URL,
+};
@@ -1,5 +1,6 @@
 //! Data models for TropicalPrice responses.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Country code to flag emoji mapping.
@@ -35,7 +36,7 @@ pub struct TropicalProduct {
 }
 
 /// Price for a specific country/Amazon store.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CountryPrice {
     /// Country code (DE, ES, FR, IT, etc.)
     pub country: String,
@@ -57,7 +58,7 @@ impl CountryPrice {
 }
 
 /// Price comparison across EU Amazon stores for a single ASIN.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PriceComparison {
     /// Amazon ASIN
     pub asin: String,
@@ -99,6 +100,82 @@ impl PriceComparison {
     }
 }
 
+/// Prices of several ASINs laid out as a grid: one row per ASIN, one
+/// column per country.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceMatrix {
+    /// Country codes of the columns, sorted
+    pub countries: Vec<String>,
+    /// Currency of the prices ("EUR" unless converted); "EUR" as well when
+    /// the columns are labeled with different currencies
+    pub currency: String,
+    /// Currency label per column of `countries` (see `locale_currency`)
+    #[serde(default)]
+    pub currencies: Vec<String>,
+    /// One row per ASIN, in input order
+    pub rows: Vec<MatrixRow>,
+}
+
+/// A row of a [`PriceMatrix`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatrixRow {
+    /// Amazon ASIN
+    pub asin: String,
+    /// Product title
+    pub title: String,
+    /// Price per column of `countries`; None where the store has no price
+    pub prices: Vec<Option<f64>>,
+}
+
+impl PriceMatrix {
+    /// Builds the grid from comparisons; the columns are every country that
+    /// has a price for at least one ASIN.
+    pub fn from_comparisons(comparisons: &[PriceComparison]) -> Self {
+        let mut countries: Vec<String> = comparisons
+            .iter()
+            .flat_map(|c| c.prices.iter().map(|p| p.country.to_uppercase()))
+            .collect();
+        countries.sort();
+        countries.dedup();
+
+        let rows = comparisons
+            .iter()
+            .map(|comparison| MatrixRow {
+                asin: comparison.asin.clone(),
+                title: comparison.title.clone(),
+                prices: countries
+                    .iter()
+                    .map(|country| {
+                        comparison
+                            .prices
+                            .iter()
+                            .find(|p| p.country.eq_ignore_ascii_case(country))
+                            .map(|p| p.price)
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let currencies: Vec<String> = countries
+            .iter()
+            .map(|country| {
+                comparisons
+                    .iter()
+                    .flat_map(|c| &c.prices)
+                    .find(|p| p.country.eq_ignore_ascii_case(country))
+                    .map_or_else(|| "EUR".to_string(), |p| p.currency.clone())
+            })
+            .collect();
+
+        let currency = match currencies.first() {
+            Some(first) if currencies.iter().all(|c| c == first) => first.clone(),
+            _ => "EUR".to_string(),
+        };
+
+        Self { countries, currency, currencies, rows }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +190,46 @@ mod tests {
         }
     }
 
+    // Price matrix tests
+
+    #[test]
+    fn test_price_matrix_cells() {
+        let first = PriceComparison {
+            asin: "B000000001".to_string(),
+            title: "First".to_string(),
+            prices: vec![make_country_price("DE", 10.0), make_country_price("ES", 12.0)],
+            total_stores: 2,
+        };
+        let second = PriceComparison {
+            asin: "B000000002".to_string(),
+            title: "Second".to_string(),
+            prices: vec![make_country_price("it", 20.0), make_country_price("DE", 22.0)],
+            total_stores: 2,
+        };
+        let third = PriceComparison {
+            asin: "B000000003".to_string(),
+            title: "Third".to_string(),
+            prices: vec![make_country_price("FR", 30.0)],
+            total_stores: 1,
+        };
+
+        let matrix = PriceMatrix::from_comparisons(&[first, second, third]);
+        assert_eq!(matrix.countries, vec!["DE", "ES", "FR", "IT"]);
+        assert_eq!(matrix.rows[0].asin, "B000000001");
+        assert_eq!(matrix.rows[0].prices, vec![Some(10.0), Some(12.0), None, None]);
+        assert_eq!(matrix.rows[1].prices, vec![Some(22.0), None, None, Some(20.0)]);
+        assert_eq!(matrix.rows[2].prices, vec![None, None, Some(30.0), None]);
+        assert_eq!(matrix.currencies, vec!["EUR"; 4]);
+        assert_eq!(matrix.currency, "EUR");
+    }
+
+    #[test]
+    fn test_price_matrix_empty() {
+        let matrix = PriceMatrix::from_comparisons(&[]);
+        assert!(matrix.countries.is_empty());
+        assert!(matrix.rows.is_empty());
+    }
+
     // Country flag tests
 
     #[test]
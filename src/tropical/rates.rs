@@ -0,0 +1,308 @@
+//! Exchange rates for converting compare prices out of EUR.
+
+use super::models::PriceComparison;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tracing::{debug, info, warn};
+use wreq::Client;
+
+/// ECB daily reference rates (EUR base).
+pub const DEFAULT_RATES_URL: &str = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml";
+
+/// Units of each currency per one unit of `base`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRates {
+    /// Base currency code (e.g. "EUR")
+    pub base: String,
+    /// Rate per currency code, relative to `base`
+    pub rates: HashMap<String, f64>,
+}
+
+impl ExchangeRates {
+    /// Parses an ECB-style XML feed (`<Cube currency="USD" rate="1.08"/>`,
+    /// EUR base) or JSON of the form `{"base": "EUR", "rates": {"USD": 1.08}}`.
+    pub fn parse(body: &str) -> Result<Self> {
+        let rates = if body.trim_start().starts_with('<') {
+            let cube = Selector::parse("cube[currency][rate]").unwrap();
+            let rates = Html::parse_document(body)
+                .select(&cube)
+                .filter_map(|e| {
+                    let currency = e.value().attr("currency")?.to_ascii_uppercase();
+                    let rate = e.value().attr("rate")?.parse().ok()?;
+                    Some((currency, rate))
+                })
+                .collect();
+            Self { base: "EUR".to_string(), rates }
+        } else {
+            serde_json::from_str(body).context("Invalid exchange rate JSON")?
+        };
+
+        if rates.rates.is_empty() {
+            anyhow::bail!("No exchange rates found in response");
+        }
+        Ok(rates)
+    }
+
+    /// Rate of `currency` relative to the base (1.0 for the base itself).
+    pub fn rate(&self, currency: &str) -> Option<f64> {
+        if currency.eq_ignore_ascii_case(&self.base) {
+            return Some(1.0);
+        }
+        self.rates
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(currency))
+            .map(|(_, rate)| *rate)
+            .filter(|rate| *rate > 0.0)
+    }
+
+    /// Converts `amount` between two currencies through the base.
+    pub fn convert(&self, amount: f64, from: &str, to: &str) -> Option<f64> {
+        Some(amount / self.rate(from)? * self.rate(to)?)
+    }
+}
+
+/// Source of exchange rates - enables mocking for tests.
+#[async_trait]
+pub trait RateProvider: Send + Sync {
+    /// Fetches the current rates.
+    async fn fetch_rates(&self) -> Result<ExchangeRates>;
+}
+
+/// Fetches rates from a URL serving ECB XML or rate JSON.
+pub struct HttpRateProvider {
+    client: Client,
+    url: String,
+}
+
+impl HttpRateProvider {
+    /// Creates a provider for `url`.
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(15))
+            .connect_timeout(Duration::from_secs(10))
+            .build()?;
+
+        Ok(Self { client, url: url.into() })
+    }
+}
+
+#[async_trait]
+impl RateProvider for HttpRateProvider {
+    async fn fetch_rates(&self) -> Result<ExchangeRates> {
+        info!("Fetching exchange rates: {}", self.url);
+
+        let response =
+            self.client.get(&self.url).send().await.context("Failed to fetch exchange rates")?;
+        if !response.status().is_success() {
+            anyhow::bail!("Exchange rate provider returned status: {}", response.status());
+        }
+
+        let body = response.text().await.context("Failed to read exchange rates")?;
+        ExchangeRates::parse(&body)
+    }
+}
+
+/// Keeps the last fetched rates on disk and reuses them until `ttl` expires.
+pub struct RateCache {
+    path: PathBuf,
+    ttl: Duration,
+}
+
+impl RateCache {
+    /// Creates a cache stored in the JSON file at `path`.
+    pub fn new(path: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self { path: path.into(), ttl }
+    }
+
+    /// Returns the cached rates if still fresh, otherwise fetches and
+    /// caches new ones. Cache read/write problems only log a warning.
+    pub async fn rates(&self, provider: &impl RateProvider) -> Result<ExchangeRates> {
+        match self.load_fresh() {
+            Ok(Some(rates)) => {
+                debug!("Using cached exchange rates: {}", self.path.display());
+                return Ok(rates);
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Ignoring exchange rate cache: {:#}", e),
+        }
+
+        let rates = provider.fetch_rates().await?;
+        if let Err(e) = self.store(&rates) {
+            warn!("Failed to cache exchange rates: {:#}", e);
+        }
+        Ok(rates)
+    }
+
+    fn load_fresh(&self) -> Result<Option<ExchangeRates>> {
+        let Ok(metadata) = std::fs::metadata(&self.path) else {
+            return Ok(None);
+        };
+        let age = SystemTime::now().duration_since(metadata.modified()?).unwrap_or(Duration::ZERO);
+        if age > self.ttl {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&self.path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    fn store(&self, rates: &ExchangeRates) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string(rates)?)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// Location of the rate cache file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Converts TropicalPrice prices (always EUR) into one target currency.
+#[derive(Debug, Clone)]
+pub struct Conversion {
+    currency: String,
+    rate: f64,
+}
+
+impl Conversion {
+    /// Creates a conversion to `currency`, failing if the rates lack it.
+    pub fn new(rates: &ExchangeRates, currency: &str) -> Result<Self> {
+        let currency = currency.to_ascii_uppercase();
+        let rate = rates.convert(1.0, "EUR", &currency).with_context(|| {
+            let mut known: Vec<&str> = rates.rates.keys().map(String::as_str).collect();
+            known.sort_unstable();
+            format!("No exchange rate for {}. Available: {}", currency, known.join(", "))
+        })?;
+        Ok(Self { currency, rate })
+    }
+
+    /// Target currency code.
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Converts every store price of `comparison` and relabels its currency.
+    pub fn apply(&self, comparison: &mut PriceComparison) {
+        for price in &mut comparison.prices {
+            price.price *= self.rate;
+            price.currency = self.currency.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tropical::CountryPrice;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Mock provider that counts fetches.
+    struct MockRateProvider {
+        rates: ExchangeRates,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RateProvider for MockRateProvider {
+        async fn fetch_rates(&self) -> Result<ExchangeRates> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.rates.clone())
+        }
+    }
+
+    fn make_rates() -> ExchangeRates {
+        ExchangeRates {
+            base: "EUR".to_string(),
+            rates: HashMap::from([("GBP".to_string(), 0.85), ("USD".to_string(), 1.1)]),
+        }
+    }
+
+    fn make_comparison() -> PriceComparison {
+        let price = |country: &str, price: f64| CountryPrice {
+            country: country.to_string(),
+            price,
+            currency: "EUR".to_string(),
+            is_marketplace: false,
+            amazon_url: format!("https://amazon.{}/dp/B08N5WRWNW", country.to_lowercase()),
+        };
+        PriceComparison {
+            asin: "B08N5WRWNW".to_string(),
+            title: "Test Product".to_string(),
+            prices: vec![price("DE", 100.0), price("FR", 120.0)],
+            total_stores: 2,
+        }
+    }
+
+    #[test]
+    fn test_parse_ecb_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <gesmes:Envelope>
+                <Cube><Cube time="2026-10-14">
+                    <Cube currency="USD" rate="1.0823"/>
+                    <Cube currency="GBP" rate="0.8561"/>
+                </Cube></Cube>
+            </gesmes:Envelope>"#;
+        let rates = ExchangeRates::parse(xml).unwrap();
+
+        assert_eq!(rates.base, "EUR");
+        assert_eq!(rates.rate("usd"), Some(1.0823));
+        assert_eq!(rates.rate("EUR"), Some(1.0));
+        assert_eq!(rates.rate("JPY"), None);
+    }
+
+    #[test]
+    fn test_parse_json_and_cross_convert() {
+        let rates =
+            ExchangeRates::parse(r#"{"base": "USD", "rates": {"EUR": 0.5, "GBP": 0.25}}"#).unwrap();
+        assert_eq!(rates.convert(10.0, "EUR", "GBP"), Some(5.0));
+        assert_eq!(rates.convert(10.0, "EUR", "USD"), Some(20.0));
+
+        assert!(ExchangeRates::parse(r#"{"base": "EUR", "rates": {}}"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cached_conversion() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RateCache::new(dir.path().join("rates.json"), Duration::from_secs(3600));
+        let provider = MockRateProvider { rates: make_rates(), calls: AtomicUsize::new(0) };
+
+        let rates = cache.rates(&provider).await.unwrap();
+        assert!(cache.path().exists());
+        let cached: ExchangeRates =
+            serde_json::from_str(&std::fs::read_to_string(cache.path()).unwrap()).unwrap();
+        assert_eq!(cached, rates);
+
+        // Fresh cache: no second fetch
+        cache.rates(&provider).await.unwrap();
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+
+        let mut comparison = make_comparison();
+        Conversion::new(&rates, "gbp").unwrap().apply(&mut comparison);
+        assert_eq!(comparison.prices[0].currency, "GBP");
+        assert!((comparison.prices[0].price - 85.0).abs() < 1e-9);
+        assert!((comparison.prices[1].price - 102.0).abs() < 1e-9);
+
+        let err = Conversion::new(&rates, "JPY").unwrap_err().to_string();
+        assert!(err.contains("No exchange rate for JPY"));
+    }
+
+    #[tokio::test]
+    async fn test_expired_cache_refetches() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RateCache::new(dir.path().join("rates.json"), Duration::ZERO);
+        let provider = MockRateProvider { rates: make_rates(), calls: AtomicUsize::new(0) };
+
+        cache.rates(&provider).await.unwrap();
+        std::thread::sleep(Duration::from_millis(10));
+        cache.rates(&provider).await.unwrap();
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -4,6 +4,7 @@ use amz_crawler::amazon::parser::Parser;
 use amz_crawler::amazon::regions::Region;
 
 const SEARCH_FIXTURE: &str = include_str!("fixtures/search_result.html");
+const PRODUCT_RELATED_FIXTURE: &str = include_str!("fixtures/product_related.html");
 
 #[test]
 fn test_parse_search_results() {
@@ -13,6 +14,10 @@ fn test_parse_search_results() {
     // Should have parsed 2 products (third one has empty ASIN)
     assert_eq!(results.count(), 2);
     assert!(results.has_more);
+    assert_eq!(
+        results.next_url.as_deref(),
+        Some("/s?k=wireless+mouse&page=2&qid=1700000000&ref=sr_pg_1")
+    );
 
     // Check first product
     let product = &results.products[0];
@@ -74,3 +79,15 @@ fn test_filter_integration() {
     assert_eq!(filtered.len(), 1);
     assert_eq!(filtered[0].asin, "B08N5WRWNW");
 }
+
+#[test]
+fn test_parse_product_related_asins() {
+    let parser = Parser::new(Region::Us);
+    let product = parser.parse_product_page(PRODUCT_RELATED_FIXTURE, "B08N5WRWNW").unwrap();
+
+    // Duplicates, the page's own ASIN, and empty placeholders are dropped
+    assert_eq!(product.related_asins, vec!["B09HMZ6S1Y", "B07W6JN8V8", "B0BKVQK3LT"]);
+
+    let json = serde_json::to_string(&product).unwrap();
+    assert!(json.contains("\"related_asins\":[\"B09HMZ6S1Y\""));
+}